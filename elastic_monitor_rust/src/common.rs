@@ -1,5 +1,5 @@
 pub use std::{
-    collections::{HashMap, HashSet},
+    collections::{BTreeMap, HashMap, HashSet},
     fmt::Display,
     future::Future,
     io::Write,
@@ -7,7 +7,6 @@ pub use std::{
     result::Result,
     str::{FromStr, Lines},
     sync::Arc,
-    thread::sleep as std_sleep,
 };
 
 pub use tokio::{
@@ -15,7 +14,7 @@ pub use tokio::{
     time::{sleep, sleep_until, Duration, Instant},
 };
 
-pub use log::{error, info, warn};
+pub use log::{debug, error, info, warn};
 
 pub use flexi_logger::{Age, Cleanup, Criterion, FileSpec, Logger, Naming, Record};
 
@@ -35,15 +34,20 @@ pub use futures::{
 pub use elasticsearch::{
     auth::Credentials as EsCredentials,
     cat::{CatIndicesParts, CatShardsParts, CatThreadPoolParts},
-    cluster::ClusterHealthParts,
+    cluster::{ClusterHealthParts, ClusterStatsParts},
+    http::request::JsonBody,
     http::response::Response,
     http::transport::{MultiNodeConnectionPool, Transport as EsTransport, TransportBuilder},
     http::Url,
     indices::IndicesStatsParts,
-    nodes::NodesStatsParts,
-    CountParts, Elasticsearch, IndexParts, SearchParts,
+    nodes::{NodesHotThreadsParts, NodesStatsParts},
+    params::Bytes,
+    tasks::TasksCancelParts,
+    BulkParts, CountParts, Elasticsearch, IndexParts, SearchParts,
 };
 
+pub use tokio_util::sync::CancellationToken;
+
 pub use tiberius::Row;
 
 pub use rand::{prelude::IndexedRandom, prelude::ThreadRng, Rng};
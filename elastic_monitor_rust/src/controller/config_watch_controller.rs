@@ -0,0 +1,362 @@
+use crate::common::*;
+
+use crate::controller::{main_controller::*, task_supervisor::*};
+
+use crate::service::{
+    chart_service::*, metrics_service::*, mon_es_service::*, monitoring_service::*,
+    notification_service::*, report_service::*,
+};
+
+use crate::repository::es_repository::*;
+
+use crate::traits::service::notification_service_trait::*;
+
+use crate::model::{
+    cluster_dto::{cluster_config::*, cluster_info::*},
+    configs::config::*,
+};
+
+use crate::env_configuration::env_config::*;
+
+use crate::utils_modules::io_utils::*;
+
+/* Interval at which the modification time of the configuration files is checked */
+const CONFIG_WATCH_INTERVAL_SEC: u64 = 10;
+
+type ClusterMonitoringService = MonitoringServiceImpl<
+    MetricServiceImpl<EsRepositoryImpl>,
+    NotificationServiceImpl,
+    MonEsServiceImpl<EsRepositoryImpl>,
+>;
+
+type ClusterReportService = ReportServiceImpl<
+    NotificationServiceImpl,
+    ChartServiceImpl,
+    MonEsServiceImpl<EsRepositoryImpl>,
+>;
+
+#[doc = "Kinds of configuration files that can be reloaded without a restart"]
+#[derive(Debug, Clone, Copy)]
+enum ConfigFileKind {
+    System,
+    ClusterList,
+    Receiver,
+}
+
+#[doc = "Running tasks of a cluster and the services they share"]
+#[derive(Debug)]
+struct ClusterTask {
+    cluster_info: ClusterInfo,
+    cancel_token: CancellationToken, /* Stops every task of the cluster */
+    metric_service: Arc<MetricServiceImpl<EsRepositoryImpl>>,
+    monitoring_service: Arc<ClusterMonitoringService>,
+    report_service: Arc<ClusterReportService>,
+}
+
+#[derive(Debug, new)]
+pub struct ConfigWatchController {
+    supervisor: Arc<TaskSupervisor>,
+    notification_service: Arc<NotificationServiceImpl>,
+    chart_service: Arc<ChartServiceImpl>,
+    mon_es_service: Arc<MonEsServiceImpl<EsRepositoryImpl>>,
+    /* cluster name -> running tasks of the cluster */
+    #[new(default)]
+    cluster_tasks: RwLock<HashMap<String, ClusterTask>>,
+}
+
+impl ConfigWatchController {
+    #[doc = "Function that injects the dependencies of a cluster and registers its tasks to the supervisor"]
+    /// # Arguments
+    /// * `cluster_info`    - Configuration of the cluster to be monitored
+    /// * `es_repository`   - Connection of the cluster to be monitored
+    pub async fn start_cluster(
+        &self,
+        cluster_info: ClusterInfo,
+        es_repository: EsRepositoryImpl,
+    ) -> anyhow::Result<()> {
+        let cluster_name: String = cluster_info.cluster_name.clone();
+
+        /*
+            Handler Dependency Injection(DI)
+            Since multiple clusters can be monitored simultaneously,
+            dependency injection is performed for each cluster.
+        */
+        let metric_service: Arc<MetricServiceImpl<EsRepositoryImpl>> =
+            Arc::new(MetricServiceImpl::new(Arc::new(RwLock::new(es_repository))));
+
+        let monitoring_service: Arc<ClusterMonitoringService> =
+            Arc::new(MonitoringServiceImpl::new(
+                Arc::clone(&metric_service),
+                Arc::clone(&self.notification_service),
+                Arc::clone(&self.mon_es_service),
+                RwLock::new(cluster_info.clone()),
+            ));
+
+        let report_service: Arc<ClusterReportService> = Arc::new(ReportServiceImpl::new(
+            Arc::clone(&self.notification_service),
+            Arc::clone(&self.chart_service),
+            Arc::clone(&self.mon_es_service),
+            RwLock::new(cluster_info.clone()),
+        ));
+
+        let controller: MainController<ClusterMonitoringService, ClusterReportService> =
+            MainController::new(Arc::clone(&monitoring_service), Arc::clone(&report_service));
+
+        /* Child token -> the cluster can be stopped alone, and is also stopped on shutdown. */
+        let cancel_token: CancellationToken = self.supervisor.cancel_token().child_token();
+
+        controller
+            .main_task(&self.supervisor, cancel_token.clone())
+            .await
+            .map_err(|e| anyhow!("[ConfigWatchController::start_cluster] {:?}", e))?;
+
+        info!(
+            "[ConfigWatchController::start_cluster] Monitoring of '{}' started.",
+            cluster_name
+        );
+
+        self.cluster_tasks.write().await.insert(
+            cluster_name,
+            ClusterTask {
+                cluster_info,
+                cancel_token,
+                metric_service,
+                monitoring_service,
+                report_service,
+            },
+        );
+
+        Ok(())
+    }
+
+    #[doc = "Function that applies a changed configuration to the running tasks of a cluster.
+             The tasks are not restarted, so the alert state, the samples and the baselines of the cluster are kept."]
+    /// # Arguments
+    /// * `cluster_info`    - Reloaded configuration of the cluster
+    /// * `es_repository`   - New connection -> only if the connection settings have changed
+    async fn update_cluster(
+        &self,
+        cluster_info: ClusterInfo,
+        es_repository: Option<EsRepositoryImpl>,
+    ) {
+        let mut cluster_tasks = self.cluster_tasks.write().await;
+
+        let cluster_task: &mut ClusterTask = match cluster_tasks.get_mut(&cluster_info.cluster_name)
+        {
+            Some(cluster_task) => cluster_task,
+            None => return,
+        };
+
+        if let Some(es_repository) = es_repository {
+            cluster_task
+                .metric_service
+                .replace_es_repository(es_repository)
+                .await;
+        }
+
+        cluster_task
+            .monitoring_service
+            .update_cluster_info(cluster_info.clone())
+            .await;
+
+        cluster_task
+            .report_service
+            .update_cluster_info(cluster_info.clone())
+            .await;
+
+        info!(
+            "[ConfigWatchController::update_cluster] Configuration of '{}' updated.",
+            cluster_info.cluster_name
+        );
+
+        cluster_task.cluster_info = cluster_info;
+    }
+
+    #[doc = "Function that stops every task of a cluster"]
+    async fn stop_cluster(&self, cluster_name: &str) {
+        if let Some(cluster_task) = self.cluster_tasks.write().await.remove(cluster_name) {
+            cluster_task.cancel_token.cancel();
+
+            info!(
+                "[ConfigWatchController::stop_cluster] Monitoring of '{}' stopped.",
+                cluster_name
+            );
+        }
+    }
+
+    #[doc = "Function that reloads the cluster list.
+             Removed clusters are stopped, added clusters are started and changed clusters are updated in place.
+             Nothing is applied unless every cluster of the new list is valid."]
+    async fn reload_cluster_infos(&self) -> anyhow::Result<()> {
+        let cluster_config: ClusterConfig =
+            read_toml_from_file::<ClusterConfig>(&ELASTIC_INFO_PATH)
+                .map_err(|e| anyhow!("[ConfigWatchController::reload_cluster_infos] {:?}", e))?;
+
+        cluster_config.validate()?;
+
+        let listed_cluster_names: HashSet<String> = cluster_config
+            .clusters
+            .iter()
+            .map(|cluster_info| cluster_info.cluster_name.clone())
+            .collect();
+
+        let running_clusters: HashMap<String, ClusterInfo> = self
+            .cluster_tasks
+            .read()
+            .await
+            .iter()
+            .map(|(cluster_name, cluster_task)| {
+                (cluster_name.clone(), cluster_task.cluster_info.clone())
+            })
+            .collect();
+
+        /*
+            1. Connections of the added clusters and of the clusters whose connection settings changed
+               are created first (validation).
+        */
+        let mut start_clusters: Vec<(ClusterInfo, EsRepositoryImpl)> = Vec::new();
+        let mut update_clusters: Vec<(ClusterInfo, Option<EsRepositoryImpl>)> = Vec::new();
+
+        for cluster_info in cluster_config.clusters {
+            let running_cluster_info: Option<&ClusterInfo> =
+                running_clusters.get(&cluster_info.cluster_name);
+
+            if running_cluster_info == Some(&cluster_info) {
+                continue;
+            }
+
+            /* Rule-only changes keep the running connection */
+            if running_cluster_info.is_some_and(|running_cluster_info| {
+                running_cluster_info.is_same_connection(&cluster_info)
+            }) {
+                update_clusters.push((cluster_info, None));
+                continue;
+            }
+
+            let es_repository: EsRepositoryImpl =
+                create_es_repository(&cluster_info).map_err(|e| {
+                    anyhow!(
+                        "[ConfigWatchController::reload_cluster_infos] Invalid cluster '{}': {:?}",
+                        cluster_info.cluster_name,
+                        e
+                    )
+                })?;
+
+            if running_cluster_info.is_some() {
+                update_clusters.push((cluster_info, Some(es_repository)));
+            } else {
+                start_clusters.push((cluster_info, es_repository));
+            }
+        }
+
+        /* 2. Clusters which were removed are stopped. */
+        for cluster_name in running_clusters.keys() {
+            if !listed_cluster_names.contains(cluster_name) {
+                self.stop_cluster(cluster_name).await;
+            }
+        }
+
+        /* 3. Clusters which were changed are updated without restarting their tasks. */
+        for (cluster_info, es_repository) in update_clusters {
+            self.update_cluster(cluster_info, es_repository).await;
+        }
+
+        /* 4. Clusters which were added are started. */
+        for (cluster_info, es_repository) in start_clusters {
+            self.start_cluster(cluster_info, es_repository).await?;
+        }
+
+        Ok(())
+    }
+
+    #[doc = "Function that reloads a configuration file"]
+    async fn reload_config_file(&self, config_file_kind: ConfigFileKind) -> anyhow::Result<()> {
+        match config_file_kind {
+            ConfigFileKind::System => reload_server_config(),
+            ConfigFileKind::ClusterList => self.reload_cluster_infos().await,
+            ConfigFileKind::Receiver => self.notification_service.reload_receiver_infos().await,
+        }
+    }
+
+    #[doc = "Function that notifies the administrator that a changed configuration file was rejected"]
+    async fn send_reload_failure_info(&self, file_path: &str, err: &anyhow::Error) {
+        let email_subject: String = String::from("[Elasticsearch] Config reload rejected");
+        let html_content: String = format!(
+            "<h3>The changed configuration file was rejected. The running configuration is kept.</h3>
+            <p><b>File</b>: {}</p>
+            <p><b>Reason</b>: {:?}</p>",
+            file_path, err
+        );
+
+        if let Err(e) = self
+            .notification_service
+            .send_alert_infos_to_admin(&email_subject, &html_content)
+            .await
+        {
+            error!("[ConfigWatchController::send_reload_failure_info] {:?}", e);
+        }
+    }
+
+    #[doc = "Function that watches the configuration files and reloads them when they are modified"]
+    /// # Arguments
+    /// * `cancel_token` - Token that stops the watcher
+    pub async fn config_watch_loop(&self, cancel_token: CancellationToken) -> anyhow::Result<()> {
+        let watch_files: Vec<(ConfigFileKind, &str)> = vec![
+            (ConfigFileKind::System, SYSTEM_CONFIG_PATH.as_str()),
+            (ConfigFileKind::ClusterList, ELASTIC_INFO_PATH.as_str()),
+            (ConfigFileKind::Receiver, EMAIL_RECEIVER_PATH.as_str()),
+        ];
+
+        /* The files have already been loaded at startup -> only later modifications are reloaded. */
+        let mut modified_times: HashMap<&str, std::time::SystemTime> = watch_files
+            .iter()
+            .filter_map(|(_, file_path)| {
+                get_file_modified_time(file_path)
+                    .ok()
+                    .map(|modified_time| (*file_path, modified_time))
+            })
+            .collect();
+
+        loop {
+            tokio::select! {
+                _ = cancel_token.cancelled() => return Ok(()),
+                _ = sleep(Duration::from_secs(CONFIG_WATCH_INTERVAL_SEC)) => {}
+            }
+
+            for (config_file_kind, file_path) in &watch_files {
+                let modified_time: std::time::SystemTime = match get_file_modified_time(file_path) {
+                    Ok(modified_time) => modified_time,
+                    Err(e) => {
+                        error!("[ConfigWatchController::config_watch_loop] {:?}", e);
+                        continue;
+                    }
+                };
+
+                if modified_times.get(file_path) == Some(&modified_time) {
+                    continue;
+                }
+
+                modified_times.insert(file_path, modified_time);
+
+                info!(
+                    "[ConfigWatchController::config_watch_loop] '{}' has been modified. Reloading.",
+                    file_path
+                );
+
+                match self.reload_config_file(*config_file_kind).await {
+                    Ok(_) => info!(
+                        "[ConfigWatchController::config_watch_loop] '{}' has been reloaded.",
+                        file_path
+                    ),
+                    Err(e) => {
+                        error!(
+                            "[ConfigWatchController::config_watch_loop] '{}' was rejected: {:?}",
+                            file_path, e
+                        );
+                        self.send_reload_failure_info(file_path, &e).await;
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,5 +1,7 @@
 use crate::common::*;
 
+use crate::controller::task_supervisor::*;
+
 use crate::traits::service::{monitoring_service_trait::*, report_service_trait::*};
 
 use crate::model::configs::config::*;
@@ -19,111 +21,205 @@ where
     M: MonitoringService + Send + Sync + 'static,
     R: ReportService + Send + Sync + 'static,
 {
-    #[doc = "Function that handles both the monitoring system and the reporting system."]
-    pub async fn main_task(&self) -> anyhow::Result<()> {
+    #[doc = "Function that registers both the monitoring system and the reporting system to the task supervisor."]
+    /// # Arguments
+    /// * `supervisor`      - Supervisor that restarts failed tasks and stops them on shutdown
+    /// * `cancel_token`    - Token that stops every task of the cluster (e.g. removed from the cluster list)
+    pub async fn main_task(
+        &self,
+        supervisor: &TaskSupervisor,
+        cancel_token: CancellationToken,
+    ) -> anyhow::Result<()> {
         /* Monitoring tasks and reporting tasks are executed in parallel. */
         /* Cluster name to be monitored */
         let cluster_name: String = self.monitoring_service.get_cluster_name().await;
 
         /* 1. Monitoring task */
-        let monitoring_handle: tokio::task::JoinHandle<()> =
-            Self::spawn_monitoring_task(Arc::clone(&self.monitoring_service), &cluster_name);
-
-        /* 2. Report Tasks list */
-        let daily_enabled: bool = get_daily_report_config_info().enabled;
-        let weekly_enabled: bool = get_weekly_report_config_info().enabled;
-        let monthly_enabled: bool = get_monthly_report_config_info().enabled;
-        let yearly_enabled: bool = get_yearly_report_config_info().enabled;
+        Self::spawn_monitoring_task(
+            supervisor,
+            cancel_token.clone(),
+            Arc::clone(&self.monitoring_service),
+            &cluster_name,
+        )
+        .await;
+
+        /* Connection pool recovery task -> re-admits nodes that answer again */
+        Self::spawn_pool_recovery_task(
+            supervisor,
+            cancel_token.clone(),
+            Arc::clone(&self.monitoring_service),
+            &cluster_name,
+        )
+        .await;
+
+        /* Index watchlist task -> waits for a reloaded cluster list while the watchlist is empty */
+        Self::spawn_index_watch_task(
+            supervisor,
+            cancel_token.clone(),
+            Arc::clone(&self.monitoring_service),
+            &cluster_name,
+        )
+        .await;
+
+        /* Disk forecast task -> waits for a reloaded cluster list while the forecast is disabled */
+        Self::spawn_disk_forecast_task(
+            supervisor,
+            cancel_token.clone(),
+            Arc::clone(&self.monitoring_service),
+            &cluster_name,
+        )
+        .await;
 
+        /*
+            2. Report Tasks list
+            Report tasks are always registered -> a disabled report waits until it is enabled by a config reload.
+        */
         /* 1. Daily report task */
-        let daily_report_handle = Self::spawn_report_task(
+        Self::spawn_report_task(
+            supervisor,
+            cancel_token.clone(),
             Arc::clone(&self.report_service),
             ReportType::Day,
             "daily_report_task",
-            daily_enabled,
             &cluster_name,
-        );
+        )
+        .await;
 
         /* 2. Weekly report task */
-        let weekly_report_handle = Self::spawn_report_task(
+        Self::spawn_report_task(
+            supervisor,
+            cancel_token.clone(),
             Arc::clone(&self.report_service),
             ReportType::Week,
             "weekly_report_task",
-            weekly_enabled,
             &cluster_name,
-        );
+        )
+        .await;
 
         /* 3. Monthly report task */
-        let monthly_report_handle = Self::spawn_report_task(
+        Self::spawn_report_task(
+            supervisor,
+            cancel_token.clone(),
             Arc::clone(&self.report_service),
             ReportType::Month,
             "monthly_report_task",
-            monthly_enabled,
             &cluster_name,
-        );
+        )
+        .await;
 
         /* 4. Yearly report task */
-        let yearly_report_handle = Self::spawn_report_task(
+        Self::spawn_report_task(
+            supervisor,
+            cancel_token.clone(),
             Arc::clone(&self.report_service),
             ReportType::Year,
             "yearly_report_task",
-            yearly_enabled,
             &cluster_name,
-        );
-
-        /* Run all tasks in parallel and wait for termination */
-        let _ = tokio::join!(
-            monitoring_handle,
-            daily_report_handle,
-            weekly_report_handle,
-            monthly_report_handle,
-            yearly_report_handle
-        );
+        )
+        .await;
 
         Ok(())
     }
 
-    #[doc = "Spawn monitoring task as a separate tokio task"]
-    fn spawn_monitoring_task(service: Arc<M>, cluster_name: &str) -> tokio::task::JoinHandle<()>
-    where
+    #[doc = "Spawn monitoring task as a supervised tokio task"]
+    async fn spawn_monitoring_task(
+        supervisor: &TaskSupervisor,
+        cancel_token: CancellationToken,
+        service: Arc<M>,
+        cluster_name: &str,
+    ) where
         M: MonitoringService,
     {
         let task_name: String = format!("monitoring_task_{}", cluster_name);
 
-        tokio::spawn(async move {
-            match service.monitoring_loop().await {
-                Ok(_) => info!(
-                    "[spawn_monitoring_task->{}] Completed successfully",
-                    task_name
-                ),
-                Err(e) => error!("[{}] Failed with error: {:?}", task_name, e),
-            }
-        })
+        supervisor
+            .spawn_supervised(&task_name, cancel_token, move |cancel_token| {
+                let service: Arc<M> = Arc::clone(&service);
+                async move { service.monitoring_loop(cancel_token).await }
+            })
+            .await;
     }
 
-    #[doc = "Spawn report task as a separate tokio task"]
-    fn spawn_report_task(
+    #[doc = "Spawn connection pool recovery task as a supervised tokio task"]
+    async fn spawn_pool_recovery_task(
+        supervisor: &TaskSupervisor,
+        cancel_token: CancellationToken,
+        service: Arc<M>,
+        cluster_name: &str,
+    ) where
+        M: MonitoringService,
+    {
+        let task_name: String = format!("pool_recovery_task_{}", cluster_name);
+
+        supervisor
+            .spawn_supervised(&task_name, cancel_token, move |cancel_token| {
+                let service: Arc<M> = Arc::clone(&service);
+                async move { service.pool_recovery_loop(cancel_token).await }
+            })
+            .await;
+    }
+
+    #[doc = "Spawn index watchlist task as a supervised tokio task"]
+    async fn spawn_index_watch_task(
+        supervisor: &TaskSupervisor,
+        cancel_token: CancellationToken,
+        service: Arc<M>,
+        cluster_name: &str,
+    ) where
+        M: MonitoringService,
+    {
+        let task_name: String = format!("index_watch_task_{}", cluster_name);
+
+        supervisor
+            .spawn_supervised(&task_name, cancel_token, move |cancel_token| {
+                let service: Arc<M> = Arc::clone(&service);
+                async move { service.index_watch_loop(cancel_token).await }
+            })
+            .await;
+    }
+
+    #[doc = "Spawn disk forecast task as a supervised tokio task"]
+    async fn spawn_disk_forecast_task(
+        supervisor: &TaskSupervisor,
+        cancel_token: CancellationToken,
+        service: Arc<M>,
+        cluster_name: &str,
+    ) where
+        M: MonitoringService,
+    {
+        let task_name: String = format!("disk_forecast_task_{}", cluster_name);
+
+        supervisor
+            .spawn_supervised(&task_name, cancel_token, move |cancel_token| {
+                let service: Arc<M> = Arc::clone(&service);
+                async move { service.disk_forecast_loop(cancel_token).await }
+            })
+            .await;
+    }
+
+    #[doc = "Spawn report task as a supervised tokio task"]
+    async fn spawn_report_task(
+        supervisor: &TaskSupervisor,
+        cancel_token: CancellationToken,
         service: Arc<R>,
         report_type: ReportType,
         task_name: &str,
-        enabled: bool,
         cluster_name: &str,
-    ) -> tokio::task::JoinHandle<()>
-    where
+    ) where
         R: ReportService,
     {
-        let task_name: String = task_name.to_string();
+        let task_name: String = format!("{}_{}", task_name, cluster_name);
         let cluster_name_cloned: String = cluster_name.to_string();
 
-        let img_path: &str = match report_type {
-            ReportType::Day => get_daily_report_config_info().img_path().as_str(),
-            ReportType::Week => get_weekly_report_config_info().img_path().as_str(),
-            ReportType::Month => get_monthly_report_config_info().img_path().as_str(),
-            ReportType::Year => get_yearly_report_config_info().img_path().as_str(),
+        let img_path: String = match report_type {
+            ReportType::Day => get_daily_report_config_info().img_path,
+            ReportType::Week => get_weekly_report_config_info().img_path,
+            ReportType::Month => get_monthly_report_config_info().img_path,
+            ReportType::Year => get_yearly_report_config_info().img_path,
         };
-        
+
         /* It deletes all image files related to the report. */
-        match delete_all_files_in_directory(img_path) {
+        match delete_all_files_in_directory(&img_path) {
             Ok(_) => {
                 info!(
                     "The images within the `{}` directory have been deleted.",
@@ -135,23 +231,16 @@ where
             }
         }
 
-        if !enabled {
-            return tokio::spawn(async move {
-                info!("[{}] Disabled. Skipping.", task_name);
-            });
-        }
-
-        tokio::spawn(async move {
-            match service
-                .report_loop(report_type, cluster_name_cloned.as_str())
-                .await
-            {
-                Ok(_) => info!("[spawn_report_task->{}] Completed successfully", task_name),
-                Err(e) => error!(
-                    "[spawn_report_task->{}] Failed with error [cluster name: {}]: {:?}",
-                    task_name, cluster_name_cloned, e
-                ),
-            }
-        })
+        supervisor
+            .spawn_supervised(&task_name, cancel_token, move |cancel_token| {
+                let service: Arc<R> = Arc::clone(&service);
+                let cluster_name: String = cluster_name_cloned.clone();
+                async move {
+                    service
+                        .report_loop(report_type, cluster_name.as_str(), cancel_token)
+                        .await
+                }
+            })
+            .await;
     }
-}
\ No newline at end of file
+}
@@ -1 +1,3 @@
+pub mod config_watch_controller;
 pub mod main_controller;
+pub mod task_supervisor;
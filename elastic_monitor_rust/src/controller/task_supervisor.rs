@@ -0,0 +1,216 @@
+use crate::common::*;
+
+use crate::enums::task_state::*;
+
+use crate::model::task_dto::task_status::*;
+
+/* Backoff applied before a failed task is restarted */
+const RESTART_BACKOFF_MIN_SEC: u64 = 5;
+const RESTART_BACKOFF_MAX_SEC: u64 = 300;
+
+#[derive(Debug)]
+pub struct TaskSupervisor {
+    cancel_token: CancellationToken,
+    task_statuses: Arc<RwLock<HashMap<String, TaskStatus>>>,
+    task_handles: RwLock<Vec<(String, tokio::task::JoinHandle<()>)>>,
+}
+
+impl TaskSupervisor {
+    pub fn new() -> Self {
+        TaskSupervisor {
+            cancel_token: CancellationToken::new(),
+            task_statuses: Arc::new(RwLock::new(HashMap::new())),
+            task_handles: RwLock::new(Vec::new()),
+        }
+    }
+
+    #[doc = "Function that returns the root cancellation token - cancelled when the program shuts down."]
+    pub fn cancel_token(&self) -> CancellationToken {
+        self.cancel_token.clone()
+    }
+
+    #[doc = "Function that updates the status of a supervised task"]
+    async fn set_task_status(
+        task_statuses: &RwLock<HashMap<String, TaskStatus>>,
+        task_name: &str,
+        state: TaskState,
+        last_error: Option<String>,
+    ) {
+        let mut statuses = task_statuses.write().await;
+
+        let status: &mut TaskStatus = statuses
+            .entry(task_name.to_string())
+            .or_insert_with(|| TaskStatus::new(state, 0, None));
+
+        if state == TaskState::Restarting {
+            status.restart_count += 1;
+        }
+
+        status.state = state;
+
+        if last_error.is_some() {
+            status.last_error = last_error;
+        }
+    }
+
+    #[doc = "Function that spawns a task supervised by the supervisor.
+             If the task fails or panics, it is restarted with an exponential backoff until it is cancelled."]
+    /// # Arguments
+    /// * `task_name`       - Name of the task (must be unique)
+    /// * `cancel_token`    - Token that stops the task (root token or one of its children)
+    /// * `task_factory`    - Function that creates the task future on every (re)start
+    pub async fn spawn_supervised<F, Fut>(
+        &self,
+        task_name: &str,
+        cancel_token: CancellationToken,
+        task_factory: F,
+    ) where
+        F: Fn(CancellationToken) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = anyhow::Result<()>> + Send + 'static,
+    {
+        let task_name_cloned: String = task_name.to_string();
+        let task_statuses: Arc<RwLock<HashMap<String, TaskStatus>>> =
+            Arc::clone(&self.task_statuses);
+
+        Self::set_task_status(&task_statuses, task_name, TaskState::Running, None).await;
+
+        let handle: tokio::task::JoinHandle<()> = tokio::spawn(async move {
+            let task_name: String = task_name_cloned;
+            let mut backoff_sec: u64 = RESTART_BACKOFF_MIN_SEC;
+
+            loop {
+                let started_at: Instant = Instant::now();
+
+                /* The task runs in its own tokio task so that a panic can be caught as a JoinError. */
+                let result: Result<anyhow::Result<()>, tokio::task::JoinError> =
+                    tokio::spawn(task_factory(cancel_token.clone())).await;
+
+                let err_msg: String = match result {
+                    Ok(Ok(_)) => {
+                        let state: TaskState = if cancel_token.is_cancelled() {
+                            TaskState::Cancelled
+                        } else {
+                            TaskState::Completed
+                        };
+
+                        info!(
+                            "[TaskSupervisor->{}] Stopped: {}",
+                            task_name,
+                            state.get_name()
+                        );
+                        Self::set_task_status(&task_statuses, &task_name, state, None).await;
+                        break;
+                    }
+                    Ok(Err(e)) => format!("{:?}", e),
+                    Err(join_err) => format!("panicked: {:?}", join_err),
+                };
+
+                error!(
+                    "[TaskSupervisor->{}] Failed with error: {}",
+                    task_name, err_msg
+                );
+
+                if cancel_token.is_cancelled() {
+                    Self::set_task_status(
+                        &task_statuses,
+                        &task_name,
+                        TaskState::Cancelled,
+                        Some(err_msg),
+                    )
+                    .await;
+                    break;
+                }
+
+                /* A task that has been running for a long time is considered healthy again. */
+                if started_at.elapsed() > Duration::from_secs(RESTART_BACKOFF_MAX_SEC) {
+                    backoff_sec = RESTART_BACKOFF_MIN_SEC;
+                }
+
+                Self::set_task_status(
+                    &task_statuses,
+                    &task_name,
+                    TaskState::Restarting,
+                    Some(err_msg),
+                )
+                .await;
+
+                warn!(
+                    "[TaskSupervisor->{}] Restarting in {} seconds.",
+                    task_name, backoff_sec
+                );
+
+                tokio::select! {
+                    _ = cancel_token.cancelled() => {
+                        Self::set_task_status(&task_statuses, &task_name, TaskState::Cancelled, None).await;
+                        break;
+                    }
+                    _ = sleep(Duration::from_secs(backoff_sec)) => {}
+                }
+
+                backoff_sec = (backoff_sec * 2).min(RESTART_BACKOFF_MAX_SEC);
+
+                Self::set_task_status(&task_statuses, &task_name, TaskState::Running, None).await;
+            }
+        });
+
+        let mut task_handles = self.task_handles.write().await;
+
+        /* Handles of the tasks which already stopped (e.g. a cluster removed from the cluster list) are dropped. */
+        task_handles.retain(|(_, handle)| !handle.is_finished());
+        task_handles.push((task_name.to_string(), handle));
+    }
+
+    #[doc = "Function that cancels every supervised task and waits for them to finish.
+             In-flight work (e.g. notifications) is drained until the grace period expires,
+             after which the remaining tasks are aborted."]
+    /// # Arguments
+    /// * `grace_period` - Maximum time to wait for the tasks to finish
+    pub async fn shutdown(&self, grace_period: Duration) {
+        self.cancel_token.cancel();
+
+        let handles: Vec<(String, tokio::task::JoinHandle<()>)> =
+            std::mem::take(&mut *self.task_handles.write().await);
+
+        let deadline: Instant = Instant::now() + grace_period;
+
+        for (task_name, mut handle) in handles {
+            match tokio::time::timeout_at(deadline, &mut handle).await {
+                Ok(_) => (),
+                Err(_) => {
+                    warn!(
+                        "[TaskSupervisor::shutdown] '{}' did not finish within {:?}. Aborting.",
+                        task_name, grace_period
+                    );
+                    handle.abort();
+                    Self::set_task_status(
+                        &self.task_statuses,
+                        &task_name,
+                        TaskState::Aborted,
+                        None,
+                    )
+                    .await;
+                }
+            }
+        }
+    }
+
+    #[doc = "Function that logs the status of every supervised task"]
+    pub async fn log_task_statuses(&self) {
+        let statuses = self.task_statuses.read().await;
+
+        let mut task_names: Vec<&String> = statuses.keys().collect();
+        task_names.sort();
+
+        for task_name in task_names {
+            let status: &TaskStatus = &statuses[task_name];
+
+            info!(
+                "[TaskSupervisor] task: {}, state: {}, restarts: {}, last error: {}",
+                task_name,
+                status.state().get_name(),
+                status.restart_count(),
+                status.last_error().as_deref().unwrap_or("-")
+            );
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use crate::common::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AlertCheckType {
+    NodeConnection,
+    ClusterHealth,
+    UrgentMetric,
+    PendingTasks,
+    HotThreads,
+    DiskForecast,
+    LongGc,
+    LongSearchTask,
+    ShardSkew,
+    ThreadPoolRejection,
+    NodeMembership,
+    EsMetric,
+}
+
+impl AlertCheckType {
+    pub fn get_name(&self) -> String {
+        match self {
+            AlertCheckType::NodeConnection => "node_connection",
+            AlertCheckType::ClusterHealth => "cluster_health",
+            AlertCheckType::UrgentMetric => "urgent_metric",
+            AlertCheckType::PendingTasks => "pending_tasks",
+            AlertCheckType::HotThreads => "hot_threads",
+            AlertCheckType::DiskForecast => "disk_forecast",
+            AlertCheckType::LongGc => "long_gc",
+            AlertCheckType::LongSearchTask => "long_search_task",
+            AlertCheckType::ShardSkew => "shard_skew",
+            AlertCheckType::ThreadPoolRejection => "thread_pool_rejection",
+            AlertCheckType::NodeMembership => "node_membership",
+            AlertCheckType::EsMetric => "es_metric",
+        }
+        .to_string()
+    }
+}
@@ -0,0 +1,25 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlertTransitionType {
+    Firing,
+    Reminder,
+    Resolved,
+}
+
+impl AlertTransitionType {
+    pub fn get_name(&self) -> String {
+        match self {
+            AlertTransitionType::Firing => "FIRING",
+            AlertTransitionType::Reminder => "REMINDER",
+            AlertTransitionType::Resolved => "RESOLVED",
+        }
+        .to_string()
+    }
+
+    #[doc = "Whether the transition requires an error alarm to be sent"]
+    pub fn is_alarm(&self) -> bool {
+        matches!(
+            self,
+            AlertTransitionType::Firing | AlertTransitionType::Reminder
+        )
+    }
+}
@@ -0,0 +1,31 @@
+use crate::common::*;
+
+#[doc = "Side of the baseline on which a sample is considered an anomaly"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AnomalyDirection {
+    #[default]
+    Above,
+    Below,
+    Both,
+}
+
+impl AnomalyDirection {
+    pub fn get_name(&self) -> String {
+        match self {
+            AnomalyDirection::Above => "above",
+            AnomalyDirection::Below => "below",
+            AnomalyDirection::Both => "both",
+        }
+        .to_string()
+    }
+
+    #[doc = "Function that checks whether the z-score of a sample is beyond `sigma` on this side of the baseline"]
+    pub fn is_anomaly(&self, z_score: f64, sigma: f64) -> bool {
+        match self {
+            AnomalyDirection::Above => z_score > sigma,
+            AnomalyDirection::Below => z_score < -sigma,
+            AnomalyDirection::Both => z_score.abs() > sigma,
+        }
+    }
+}
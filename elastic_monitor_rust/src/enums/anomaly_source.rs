@@ -0,0 +1,39 @@
+use crate::common::*;
+
+#[doc = "Metrics evaluated by an anomaly rule"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AnomalySource {
+    /* Elasticsearch node metrics (`MetricInfo`) collected by this monitor -> baseline per node name */
+    EsMetric,
+    /* Documents of the urgent index shipped by the collection agent -> baseline per host */
+    Urgent,
+}
+
+impl AnomalySource {
+    pub fn get_name(&self) -> String {
+        match self {
+            AnomalySource::EsMetric => "es_metric",
+            AnomalySource::Urgent => "urgent",
+        }
+        .to_string()
+    }
+
+    #[doc = "Field of the stored documents identifying the baseline target (node name or host)"]
+    pub fn get_target_field(&self) -> String {
+        match self {
+            AnomalySource::EsMetric => "name.keyword",
+            AnomalySource::Urgent => "host",
+        }
+        .to_string()
+    }
+
+    #[doc = "Field of the stored documents holding the host -> used to restrict the history to the cluster"]
+    pub fn get_host_field(&self) -> String {
+        match self {
+            AnomalySource::EsMetric => "host.keyword",
+            AnomalySource::Urgent => "host",
+        }
+        .to_string()
+    }
+}
@@ -0,0 +1,31 @@
+use crate::common::*;
+
+#[doc = "Health status of an Elasticsearch cluster - ordered by severity"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum ClusterHealthStatus {
+    Green,
+    Yellow,
+    Red,
+}
+
+impl ClusterHealthStatus {
+    pub fn get_name(&self) -> String {
+        match self {
+            ClusterHealthStatus::Green => "GREEN",
+            ClusterHealthStatus::Yellow => "YELLOW",
+            ClusterHealthStatus::Red => "RED",
+        }
+        .to_string()
+    }
+
+    #[doc = "Function that converts the `status` of `_cluster/health` -> green, yellow, red"]
+    pub fn from_status_str(status: &str) -> Option<Self> {
+        match status.to_uppercase().as_str() {
+            "GREEN" => Some(ClusterHealthStatus::Green),
+            "YELLOW" => Some(ClusterHealthStatus::Yellow),
+            "RED" => Some(ClusterHealthStatus::Red),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use crate::common::*;
+
+#[doc = "Comparison operator of an urgent rule -> `metric <operator> limit`"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ComparisonOperator {
+    #[default]
+    #[serde(rename = ">")]
+    Gt,
+    #[serde(rename = ">=")]
+    Gte,
+    #[serde(rename = "<")]
+    Lt,
+    #[serde(rename = "<=")]
+    Lte,
+    #[serde(rename = "==")]
+    Eq,
+    #[serde(rename = "!=")]
+    Ne,
+}
+
+impl ComparisonOperator {
+    pub fn get_symbol(&self) -> String {
+        match self {
+            ComparisonOperator::Gt => ">",
+            ComparisonOperator::Gte => ">=",
+            ComparisonOperator::Lt => "<",
+            ComparisonOperator::Lte => "<=",
+            ComparisonOperator::Eq => "==",
+            ComparisonOperator::Ne => "!=",
+        }
+        .to_string()
+    }
+
+    #[doc = "Function that checks whether `value <operator> limit` holds"]
+    pub fn matches(&self, value: f64, limit: f64) -> bool {
+        match self {
+            ComparisonOperator::Gt => value > limit,
+            ComparisonOperator::Gte => value >= limit,
+            ComparisonOperator::Lt => value < limit,
+            ComparisonOperator::Lte => value <= limit,
+            ComparisonOperator::Eq => value == limit,
+            ComparisonOperator::Ne => value != limit,
+        }
+    }
+}
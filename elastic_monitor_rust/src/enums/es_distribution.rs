@@ -0,0 +1,18 @@
+use crate::common::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EsDistribution {
+    Elasticsearch,
+    OpenSearch,
+}
+
+impl EsDistribution {
+    pub fn get_name(&self) -> String {
+        match self {
+            EsDistribution::Elasticsearch => "elasticsearch",
+            EsDistribution::OpenSearch => "opensearch",
+        }
+        .to_string()
+    }
+}
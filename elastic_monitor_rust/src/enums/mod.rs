@@ -1,2 +1,12 @@
+pub mod alert_check_type;
+pub mod alert_transition_type;
+pub mod anomaly_direction;
+pub mod anomaly_source;
+pub mod cluster_health_status;
+pub mod comparison_operator;
+pub mod es_distribution;
 pub mod img_file_type;
+pub mod node_membership_change_type;
 pub mod report_type;
+pub mod task_state;
+pub mod urgent_severity;
@@ -0,0 +1,19 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NodeMembershipChangeType {
+    Joined,
+    Left,
+    Restarted,
+    MasterChanged,
+}
+
+impl NodeMembershipChangeType {
+    pub fn get_name(&self) -> String {
+        match self {
+            NodeMembershipChangeType::Joined => "JOINED",
+            NodeMembershipChangeType::Left => "LEFT",
+            NodeMembershipChangeType::Restarted => "RESTARTED",
+            NodeMembershipChangeType::MasterChanged => "MASTER_CHANGED",
+        }
+        .to_string()
+    }
+}
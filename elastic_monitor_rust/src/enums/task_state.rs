@@ -0,0 +1,21 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaskState {
+    Running,
+    Restarting,
+    Completed,
+    Cancelled,
+    Aborted,
+}
+
+impl TaskState {
+    pub fn get_name(&self) -> String {
+        match self {
+            TaskState::Running => "running",
+            TaskState::Restarting => "restarting",
+            TaskState::Completed => "completed",
+            TaskState::Cancelled => "cancelled",
+            TaskState::Aborted => "aborted",
+        }
+        .to_string()
+    }
+}
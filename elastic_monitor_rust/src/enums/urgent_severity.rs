@@ -0,0 +1,24 @@
+use crate::common::*;
+
+#[doc = "Severity of an urgent rule - ordered by severity"]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum UrgentSeverity {
+    Info,
+    Warning,
+    #[default]
+    Critical,
+}
+
+impl UrgentSeverity {
+    pub fn get_name(&self) -> String {
+        match self {
+            UrgentSeverity::Info => "INFO",
+            UrgentSeverity::Warning => "WARNING",
+            UrgentSeverity::Critical => "CRITICAL",
+        }
+        .to_string()
+    }
+}
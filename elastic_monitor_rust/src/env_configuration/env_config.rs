@@ -51,3 +51,11 @@ pub static MON_ELASTIC_INFO_PATH: once_lazy<String> =
 #[doc = "Function to globally initialize the 'REPORT_HTML_TEMPLATE_PATH' variable"]
 pub static REPORT_HTML_TEMPLATE_PATH: once_lazy<String> =
     once_lazy::new(|| get_env_or_panic("REPORT_HTML_TEMPLATE_PATH"));
+
+#[doc = "Function to globally initialize the 'SILENCE_CONFIG_PATH' variable (optional -> no silences if not set)"]
+pub static SILENCE_CONFIG_PATH: once_lazy<Option<String>> =
+    once_lazy::new(|| std::env::var("SILENCE_CONFIG_PATH").ok());
+
+#[doc = "Function to globally initialize the 'ANOMALY_BASELINE_PATH' variable (optional -> baselines are kept in memory only if not set)"]
+pub static ANOMALY_BASELINE_PATH: once_lazy<Option<String>> =
+    once_lazy::new(|| std::env::var("ANOMALY_BASELINE_PATH").ok());
@@ -43,18 +43,16 @@ mod common;
 use common::*;
 
 mod controller;
-use controller::main_controller::*;
+use controller::{config_watch_controller::*, task_supervisor::*};
 
 mod utils_modules;
 use utils_modules::logger_utils::*;
 
 mod service;
-use service::{
-    chart_service::*, metrics_service::*, mon_es_service::*, monitoring_service::*,
-    notification_service::*, report_service::*,
-};
+use service::{chart_service::*, mon_es_service::*, notification_service::*};
 
 mod model;
+use model::cluster_dto::cluster_info::*;
 
 mod repository;
 use repository::es_repository::*;
@@ -65,6 +63,37 @@ mod traits;
 
 mod enums;
 
+/* Maximum time to wait for in-flight cycles and notifications when shutting down */
+const SHUTDOWN_GRACE_PERIOD_SEC: u64 = 120;
+
+#[doc = "Function that waits until SIGTERM or SIGINT(Ctrl+C) is received"]
+async fn wait_for_shutdown_signal() {
+    #[cfg(unix)]
+    {
+        use tokio::signal::unix::{signal, SignalKind};
+
+        match signal(SignalKind::terminate()) {
+            Ok(mut sigterm) => {
+                tokio::select! {
+                    _ = sigterm.recv() => info!("[main] SIGTERM received."),
+                    res = tokio::signal::ctrl_c() => match res {
+                        Ok(_) => info!("[main] SIGINT received."),
+                        Err(e) => error!("[main] Failed to listen for Ctrl+C signal: {:?}", e),
+                    },
+                }
+                return;
+            }
+            Err(e) => {
+                error!("[main] Failed to listen for SIGTERM signal: {:?}", e);
+            }
+        }
+    }
+
+    if let Err(e) = tokio::signal::ctrl_c().await {
+        error!("[main] Failed to listen for Ctrl+C signal: {:?}", e);
+    }
+}
+
 #[tokio::main]
 async fn main() {
     /* config 설정 전역 적용 */
@@ -76,16 +105,17 @@ async fn main() {
     info!("Start Elasticsearch Monitoring Program");
 
     /* List of Elasticsearch DB connection information for ***monitoring targets*** */
-    let es_infos_vec: Vec<EsRepositoryImpl> = initialize_db_clients().unwrap_or_else(|e| {
-        error!(
-            "[main()] Unable to retrieve 'Elasticsearch' connection information.: {:?}",
-            e
-        );
-        panic!(
-            "[main()] Unable to retrieve 'Elasticsearch' connection information.: {:?}",
-            e
-        )
-    });
+    let es_infos_vec: Vec<(ClusterInfo, EsRepositoryImpl)> = initialize_db_clients()
+        .unwrap_or_else(|e| {
+            error!(
+                "[main()] Unable to retrieve 'Elasticsearch' connection information.: {:?}",
+                e
+            );
+            panic!(
+                "[main()] Unable to retrieve 'Elasticsearch' connection information.: {:?}",
+                e
+            )
+        });
 
     let mon_es_infos: EsRepositoryImpl = initialize_mon_db_client().unwrap_or_else(|e| {
         error!(
@@ -108,62 +138,52 @@ async fn main() {
     let mon_es_service: Arc<MonEsServiceImpl<EsRepositoryImpl>> =
         Arc::new(MonEsServiceImpl::new(Arc::new(mon_es_infos)));
 
-    /*
-        Handler Dependency Injection(DI)
-        Since multiple clusters can be monitored simultaneously,
-        dependency injection is performed for each cluster.
-    */
-    for cluster in es_infos_vec {
-        let metric_service: Arc<MetricServiceImpl<EsRepositoryImpl>> =
-            Arc::new(MetricServiceImpl::new(Arc::new(RwLock::new(cluster))));
-
-        let monitoring_service: Arc<
-            MonitoringServiceImpl<
-                MetricServiceImpl<EsRepositoryImpl>,
-                NotificationServiceImpl,
-                MonEsServiceImpl<EsRepositoryImpl>,
-            >,
-        > = Arc::new(MonitoringServiceImpl::new(
-            Arc::clone(&metric_service),
-            Arc::clone(&notification_service),
-            Arc::clone(&mon_es_service),
-        ));
-
-        let report_service: Arc<
-            ReportServiceImpl<
-                NotificationServiceImpl,
-                ChartServiceImpl,
-                MonEsServiceImpl<EsRepositoryImpl>,
-            >,
-        > = Arc::new(ReportServiceImpl::new(
-            Arc::clone(&notification_service),
-            Arc::clone(&chart_service),
-            Arc::clone(&mon_es_service),
-        ));
-
-        let controller: MainController<
-            MonitoringServiceImpl<
-                MetricServiceImpl<EsRepositoryImpl>,
-                NotificationServiceImpl,
-                MonEsServiceImpl<EsRepositoryImpl>,
-            >,
-            ReportServiceImpl<
-                NotificationServiceImpl,
-                ChartServiceImpl,
-                MonEsServiceImpl<EsRepositoryImpl>,
-            >,
-        > = MainController::new(monitoring_service, report_service);
-
-        tokio::spawn(async move {
-            if let Err(e) = controller.main_task().await {
-                error!("[main] controller error: {:?}", e);
-            }
-        });
+    /* Supervisor that restarts failed tasks and stops every task on shutdown */
+    let supervisor: Arc<TaskSupervisor> = Arc::new(TaskSupervisor::new());
+
+    /* Registers the tasks of each cluster and reloads the configuration files when they change */
+    let config_watch_controller: Arc<ConfigWatchController> = Arc::new(ConfigWatchController::new(
+        Arc::clone(&supervisor),
+        notification_service,
+        chart_service,
+        mon_es_service,
+    ));
+
+    for (cluster_info, cluster) in es_infos_vec {
+        if let Err(e) = config_watch_controller
+            .start_cluster(cluster_info, cluster)
+            .await
+        {
+            error!("[main] controller error: {:?}", e);
+        }
     }
 
-    if let Err(e) = tokio::signal::ctrl_c().await {
-        error!("[main] Failed to listen for Ctrl+C signal: {:?}", e);
-    }
+    supervisor
+        .spawn_supervised(
+            "config_watch_task",
+            supervisor.cancel_token(),
+            move |cancel_token| {
+                let config_watch_controller: Arc<ConfigWatchController> =
+                    Arc::clone(&config_watch_controller);
+                async move {
+                    config_watch_controller
+                        .config_watch_loop(cancel_token)
+                        .await
+                }
+            },
+        )
+        .await;
+
+    wait_for_shutdown_signal().await;
 
     info!("Shutting down...");
+
+    /* In-flight monitoring cycles and notifications are drained before the program exits. */
+    supervisor
+        .shutdown(Duration::from_secs(SHUTDOWN_GRACE_PERIOD_SEC))
+        .await;
+
+    supervisor.log_task_statuses().await;
+
+    info!("Elasticsearch Monitoring Program stopped.");
 }
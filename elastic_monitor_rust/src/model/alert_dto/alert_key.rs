@@ -0,0 +1,25 @@
+use crate::common::*;
+
+use crate::enums::alert_check_type::*;
+
+#[doc = "Identifies a single alert -> (cluster, check, host/index/metric)"]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Getters, new)]
+#[getset(get = "pub")]
+pub struct AlertKey {
+    pub cluster_name: String,
+    pub check_type: AlertCheckType,
+    pub host: String,
+    pub target: String,
+}
+
+impl AlertKey {
+    #[doc = "Human readable name of the alert target"]
+    pub fn get_target_name(&self) -> String {
+        match (self.host.is_empty(), self.target.is_empty()) {
+            (false, false) => format!("{} ({})", self.host, self.target),
+            (false, true) => self.host.clone(),
+            (true, false) => self.target.clone(),
+            (true, true) => self.cluster_name.clone(),
+        }
+    }
+}
@@ -0,0 +1,10 @@
+use crate::common::*;
+
+#[derive(Debug, Clone, Getters, new)]
+#[getset(get = "pub")]
+pub struct AlertState {
+    pub fired_at: DateTime<Utc>,
+    /* None until the alarm has been sent successfully -> the alert is fired again in the next cycle */
+    pub last_notified_at: Option<DateTime<Utc>>,
+    pub detail: String,
+}
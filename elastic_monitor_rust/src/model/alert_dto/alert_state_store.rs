@@ -0,0 +1,266 @@
+use crate::common::*;
+
+use crate::enums::{alert_check_type::*, alert_transition_type::*};
+
+use crate::model::alert_dto::{alert_key::*, alert_state::*, alert_transition::*};
+
+#[doc = "Keeps the firing alerts of a single cluster across monitoring cycles."]
+#[derive(Debug, Default)]
+pub struct AlertStateStore {
+    states: HashMap<AlertKey, AlertState>,
+}
+
+impl AlertStateStore {
+    #[doc = "Function that compares the currently active alerts of a check with the stored state
+             and returns only the transitions that must be notified.
+             An alert is recorded as notified only by `mark_notified`, so that a FIRING or REMINDER
+             whose alarm could not be sent is returned again in the next cycle."]
+    /// # Arguments
+    /// * `cluster_name`        - Cluster being monitored
+    /// * `check_type`          - Type of check that produced `active_alerts`
+    /// * `active_alerts`       - Alerts that are currently firing -> (key, detail)
+    /// * `now`                 - Current UTC time
+    /// * `reminder_interval`   - Interval at which a still firing alert is notified again (None -> no reminder)
+    ///
+    /// # Returns
+    /// * Vec<AlertTransition> - FIRING / REMINDER / RESOLVED transitions
+    pub fn evaluate(
+        &mut self,
+        cluster_name: &str,
+        check_type: AlertCheckType,
+        active_alerts: Vec<(AlertKey, String)>,
+        now: DateTime<Utc>,
+        reminder_interval: Option<ChronoDuration>,
+    ) -> Vec<AlertTransition> {
+        let mut transitions: Vec<AlertTransition> = Vec::new();
+        let mut active_keys: HashSet<AlertKey> = HashSet::new();
+
+        for (key, detail) in active_alerts {
+            active_keys.insert(key.clone());
+
+            match self.states.get_mut(&key) {
+                None => {
+                    transitions.push(AlertTransition::new(
+                        key.clone(),
+                        AlertTransitionType::Firing,
+                        now,
+                        now,
+                        detail.clone(),
+                    ));
+                    self.states.insert(key, AlertState::new(now, None, detail));
+                }
+                Some(state) => {
+                    state.detail = detail;
+
+                    let last_notified_at: DateTime<Utc> = match state.last_notified_at {
+                        Some(last_notified_at) => last_notified_at,
+                        None => {
+                            /* The alarm has not been sent yet (failed or silenced) -> fired again */
+                            let mut transition: AlertTransition = AlertTransition::new(
+                                key,
+                                AlertTransitionType::Firing,
+                                state.fired_at,
+                                now,
+                                state.detail.clone(),
+                            );
+                            transition.refired = true;
+                            transitions.push(transition);
+                            continue;
+                        }
+                    };
+
+                    let reminder_due: bool = reminder_interval
+                        .map(|interval| now - last_notified_at >= interval)
+                        .unwrap_or(false);
+
+                    if reminder_due {
+                        transitions.push(AlertTransition::new(
+                            key,
+                            AlertTransitionType::Reminder,
+                            state.fired_at,
+                            now,
+                            state.detail.clone(),
+                        ));
+                    }
+                }
+            }
+        }
+
+        let resolved_keys: Vec<AlertKey> = self
+            .states
+            .keys()
+            .filter(|key| {
+                key.cluster_name() == cluster_name
+                    && *key.check_type() == check_type
+                    && !active_keys.contains(*key)
+            })
+            .cloned()
+            .collect();
+
+        for key in resolved_keys {
+            if let Some(state) = self.states.remove(&key) {
+                let mut transition: AlertTransition = AlertTransition::new(
+                    key,
+                    AlertTransitionType::Resolved,
+                    state.fired_at,
+                    now,
+                    state.detail,
+                );
+                transition.unannounced = state.last_notified_at.is_none();
+                transitions.push(transition);
+            }
+        }
+
+        transitions
+    }
+
+    #[doc = "Function that records the alerts as notified -> called once their alarm has been sent"]
+    /// # Arguments
+    /// * `keys`    - Keys of the alerts whose alarm has been sent
+    /// * `now`     - Current UTC time
+    pub fn mark_notified(&mut self, keys: &HashSet<AlertKey>, now: DateTime<Utc>) {
+        for key in keys {
+            if let Some(state) = self.states.get_mut(key) {
+                state.last_notified_at = Some(now);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(host: &str) -> AlertKey {
+        AlertKey::new(
+            String::from("cluster"),
+            AlertCheckType::NodeConnection,
+            host.to_string(),
+            String::from(""),
+        )
+    }
+
+    fn at(sec: i64) -> DateTime<Utc> {
+        Utc.timestamp_opt(1_700_000_000 + sec, 0).unwrap()
+    }
+
+    fn types(transitions: &[AlertTransition]) -> Vec<AlertTransitionType> {
+        transitions
+            .iter()
+            .map(|transition| *transition.transition_type())
+            .collect()
+    }
+
+    fn evaluate(
+        store: &mut AlertStateStore,
+        hosts: &[&str],
+        now: DateTime<Utc>,
+        reminder_interval: Option<ChronoDuration>,
+    ) -> Vec<AlertTransition> {
+        let active_alerts: Vec<(AlertKey, String)> = hosts
+            .iter()
+            .map(|host| (key(host), format!("{} lost", host)))
+            .collect();
+
+        store.evaluate(
+            "cluster",
+            AlertCheckType::NodeConnection,
+            active_alerts,
+            now,
+            reminder_interval,
+        )
+    }
+
+    fn notify(store: &mut AlertStateStore, transitions: &[AlertTransition], now: DateTime<Utc>) {
+        let keys: HashSet<AlertKey> = transitions
+            .iter()
+            .filter(|transition| transition.transition_type().is_alarm())
+            .map(|transition| transition.key().clone())
+            .collect();
+        store.mark_notified(&keys, now);
+    }
+
+    #[test]
+    fn fires_once_while_active() {
+        let mut store: AlertStateStore = AlertStateStore::default();
+
+        let transitions: Vec<AlertTransition> = evaluate(&mut store, &["a"], at(0), None);
+        assert_eq!(types(&transitions), vec![AlertTransitionType::Firing]);
+        notify(&mut store, &transitions, at(0));
+
+        assert!(evaluate(&mut store, &["a"], at(10), None).is_empty());
+    }
+
+    #[test]
+    fn reminds_after_interval() {
+        let mut store: AlertStateStore = AlertStateStore::default();
+        let interval: Option<ChronoDuration> = Some(ChronoDuration::seconds(60));
+
+        let transitions: Vec<AlertTransition> = evaluate(&mut store, &["a"], at(0), interval);
+        notify(&mut store, &transitions, at(0));
+
+        assert!(evaluate(&mut store, &["a"], at(59), interval).is_empty());
+
+        let transitions: Vec<AlertTransition> = evaluate(&mut store, &["a"], at(60), interval);
+        assert_eq!(types(&transitions), vec![AlertTransitionType::Reminder]);
+        assert_eq!(*transitions[0].fired_at(), at(0));
+        notify(&mut store, &transitions, at(60));
+
+        assert!(evaluate(&mut store, &["a"], at(90), interval).is_empty());
+    }
+
+    #[test]
+    fn resolves_cleared_alert_of_same_check_only() {
+        let mut store: AlertStateStore = AlertStateStore::default();
+
+        let transitions: Vec<AlertTransition> = evaluate(&mut store, &["a", "b"], at(0), None);
+        notify(&mut store, &transitions, at(0));
+
+        /* Another check type does not resolve the node connection alerts */
+        let other: Vec<AlertTransition> = store.evaluate(
+            "cluster",
+            AlertCheckType::ClusterHealth,
+            Vec::new(),
+            at(5),
+            None,
+        );
+        assert!(other.is_empty());
+
+        let transitions: Vec<AlertTransition> = evaluate(&mut store, &["b"], at(10), None);
+        assert_eq!(types(&transitions), vec![AlertTransitionType::Resolved]);
+        assert_eq!(transitions[0].key().host(), "a");
+        assert!(!transitions[0].unannounced());
+
+        /* Fires again as a new alert */
+        let transitions: Vec<AlertTransition> = evaluate(&mut store, &["a", "b"], at(20), None);
+        assert_eq!(types(&transitions), vec![AlertTransitionType::Firing]);
+        assert_eq!(*transitions[0].fired_at(), at(20));
+    }
+
+    #[test]
+    fn fires_again_until_notified() {
+        let mut store: AlertStateStore = AlertStateStore::default();
+
+        /* The alarm could not be sent -> not marked */
+        evaluate(&mut store, &["a"], at(0), None);
+
+        let transitions: Vec<AlertTransition> = evaluate(&mut store, &["a"], at(10), None);
+        assert_eq!(types(&transitions), vec![AlertTransitionType::Firing]);
+        assert_eq!(*transitions[0].fired_at(), at(0));
+        assert!(transitions[0].refired());
+        notify(&mut store, &transitions, at(10));
+
+        assert!(evaluate(&mut store, &["a"], at(20), None).is_empty());
+    }
+
+    #[test]
+    fn resolved_of_unannounced_alert_is_flagged() {
+        let mut store: AlertStateStore = AlertStateStore::default();
+
+        evaluate(&mut store, &["a"], at(0), None);
+
+        let transitions: Vec<AlertTransition> = evaluate(&mut store, &[], at(10), None);
+        assert_eq!(types(&transitions), vec![AlertTransitionType::Resolved]);
+        assert!(transitions[0].unannounced());
+    }
+}
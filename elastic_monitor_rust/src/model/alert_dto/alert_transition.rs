@@ -0,0 +1,23 @@
+use crate::common::*;
+
+use crate::enums::alert_transition_type::*;
+
+use crate::model::alert_dto::alert_key::*;
+
+#[derive(Debug, Clone, Getters, new)]
+#[getset(get = "pub")]
+pub struct AlertTransition {
+    pub key: AlertKey,
+    pub transition_type: AlertTransitionType,
+    pub fired_at: DateTime<Utc>,
+    pub occurred_at: DateTime<Utc>,
+    pub detail: String,
+    #[new(default)]
+    pub silenced: bool,
+    /* FIRING of an alert which already fired but was never announced (send failure or silence) */
+    #[new(default)]
+    pub refired: bool,
+    /* RESOLVED of an alert whose alarm was never sent -> not notified either */
+    #[new(default)]
+    pub unannounced: bool,
+}
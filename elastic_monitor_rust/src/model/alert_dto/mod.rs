@@ -0,0 +1,4 @@
+pub mod alert_key;
+pub mod alert_state;
+pub mod alert_state_store;
+pub mod alert_transition;
@@ -6,3 +6,87 @@ use crate::model::cluster_dto::cluster_info::*;
 pub struct ClusterConfig {
     pub clusters: Vec<ClusterInfo>,
 }
+
+impl ClusterConfig {
+    #[doc = "Function that validates the cluster list -> cluster names must be unique, hosts must exist and the index watchlist and the alert rules must be valid"]
+    pub fn validate(&self) -> anyhow::Result<()> {
+        let mut cluster_names: HashSet<&str> = HashSet::new();
+
+        for cluster in &self.clusters {
+            if cluster.cluster_name.is_empty() {
+                return Err(anyhow!("[ClusterConfig::validate] 'cluster_name' is empty"));
+            }
+
+            if !cluster_names.insert(cluster.cluster_name.as_str()) {
+                return Err(anyhow!(
+                    "[ClusterConfig::validate] Duplicate cluster name: {}",
+                    cluster.cluster_name
+                ));
+            }
+
+            if cluster.hosts.is_empty() {
+                return Err(anyhow!(
+                    "[ClusterConfig::validate] 'hosts' of cluster '{}' is empty",
+                    cluster.cluster_name
+                ));
+            }
+
+            if !cluster.index_watch.indices.is_empty() && cluster.index_watch.interval_sec == 0 {
+                return Err(anyhow!(
+                    "[ClusterConfig::validate] 'index_watch.interval_sec' of cluster '{}' must be greater than 0",
+                    cluster.cluster_name
+                ));
+            }
+
+            if cluster
+                .index_watch
+                .indices
+                .iter()
+                .any(|index| index.trim().is_empty())
+            {
+                return Err(anyhow!(
+                    "[ClusterConfig::validate] 'index_watch.indices' of cluster '{}' has an empty index name",
+                    cluster.cluster_name
+                ));
+            }
+
+            if cluster.hot_threads.enabled && cluster.hot_threads.threads == 0 {
+                return Err(anyhow!(
+                    "[ClusterConfig::validate] 'hot_threads.threads' of cluster '{}' must be greater than 0",
+                    cluster.cluster_name
+                ));
+            }
+
+            for rule in cluster.thread_pool.rejection_rules.iter() {
+                if !cluster.thread_pool.is_included(&rule.pool) {
+                    return Err(anyhow!(
+                        "[ClusterConfig::validate] The thread pool '{}' of a rejection rule of cluster '{}' is not in 'thread_pool.include'",
+                        rule.pool,
+                        cluster.cluster_name
+                    ));
+                }
+            }
+
+            if cluster.search_task_rule.auto_cancel
+                && cluster.search_task_rule.auto_cancel_running_time_sec == 0
+            {
+                return Err(anyhow!(
+                    "[ClusterConfig::validate] 'search_task_rule.auto_cancel_running_time_sec' of cluster '{}' must be greater than 0",
+                    cluster.cluster_name
+                ));
+            }
+
+            if cluster.disk_forecast.enabled
+                && (cluster.disk_forecast.interval_sec == 0
+                    || cluster.disk_forecast.lookback_days == 0)
+            {
+                return Err(anyhow!(
+                    "[ClusterConfig::validate] 'disk_forecast.interval_sec' and 'disk_forecast.lookback_days' of cluster '{}' must be greater than 0",
+                    cluster.cluster_name
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
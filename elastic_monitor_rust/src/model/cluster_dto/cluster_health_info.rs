@@ -0,0 +1,11 @@
+use crate::common::*;
+
+use crate::enums::cluster_health_status::*;
+
+#[doc = "Summary of `GET /_cluster/health`"]
+#[derive(Debug, Clone, Getters, new)]
+#[getset(get = "pub")]
+pub struct ClusterHealthInfo {
+    pub status: ClusterHealthStatus,
+    pub unassigned_shards: u64,
+}
@@ -1,6 +1,14 @@
 use crate::common::*;
 
-#[derive(Serialize, Deserialize, Debug)]
+use crate::enums::cluster_health_status::*;
+
+use crate::model::cluster_dto::{
+    disk_forecast_config::*, gc_rule_config::*, health_rule_config::*, hot_threads_config::*,
+    index_watch_config::*, pending_task_rule_config::*, search_task_rule_config::*,
+    shard_balance_config::*, step_timeout_config::*, thread_pool_config::*,
+};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct ClusterInfo {
     pub cluster_name: String,
     pub hosts: Vec<String>,
@@ -10,4 +18,48 @@ pub struct ClusterInfo {
     pub per_index_pattern: Option<String>,
     pub urgent_index_pattern: Option<String>,
     pub err_log_index_pattern: Option<String>,
+    #[serde(default = "default_monitor_interval_sec")]
+    pub monitor_interval_sec: u64,
+    #[serde(default)]
+    pub step_timeout: StepTimeoutConfig,
+    #[serde(default = "default_health_rules")]
+    pub health_rules: Vec<HealthRuleConfig>,
+    #[serde(default)]
+    pub index_watch: IndexWatchConfig,
+    #[serde(default)]
+    pub pending_task_rule: PendingTaskRuleConfig,
+    #[serde(default)]
+    pub hot_threads: HotThreadsConfig,
+    #[serde(default)]
+    pub disk_forecast: DiskForecastConfig,
+    #[serde(default)]
+    pub gc_rule: GcRuleConfig,
+    #[serde(default)]
+    pub search_task_rule: SearchTaskRuleConfig,
+    #[serde(default)]
+    pub shard_balance: ShardBalanceConfig,
+    #[serde(default)]
+    pub thread_pool: ThreadPoolConfig,
+}
+
+impl ClusterInfo {
+    #[doc = "Function that checks whether the connection settings (hosts, credentials, index patterns) are the same"]
+    pub fn is_same_connection(&self, other: &ClusterInfo) -> bool {
+        self.hosts == other.hosts
+            && self.es_id == other.es_id
+            && self.es_pw == other.es_pw
+            && self.index_pattern == other.index_pattern
+            && self.per_index_pattern == other.per_index_pattern
+            && self.urgent_index_pattern == other.urgent_index_pattern
+            && self.err_log_index_pattern == other.err_log_index_pattern
+    }
+}
+
+fn default_monitor_interval_sec() -> u64 {
+    10
+}
+
+#[doc = "If no rule is configured, only RED is alerted immediately."]
+fn default_health_rules() -> Vec<HealthRuleConfig> {
+    vec![HealthRuleConfig::new(ClusterHealthStatus::Red, 0)]
 }
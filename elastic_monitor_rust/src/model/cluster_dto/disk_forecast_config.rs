@@ -0,0 +1,31 @@
+use crate::common::*;
+
+#[doc = "Disk-full forecast rule -> a linear trend is fitted to the last `lookback_days` days of `disk_usage`
+         of each host, and an alert fires when a watermark is expected to be reached within `horizon_days` days.
+         The watermarks should match `cluster.routing.allocation.disk.watermark.high/flood_stage` of the cluster."]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Getters)]
+#[getset(get = "pub")]
+#[serde(default)]
+pub struct DiskForecastConfig {
+    pub enabled: bool,
+    pub interval_sec: u64,
+    pub lookback_days: u64,
+    pub horizon_days: u64,
+    pub min_samples: usize,
+    pub high_watermark_percent: f64,
+    pub flood_stage_watermark_percent: f64,
+}
+
+impl Default for DiskForecastConfig {
+    fn default() -> Self {
+        DiskForecastConfig {
+            enabled: true,
+            interval_sec: 3600,
+            lookback_days: 7,
+            horizon_days: 7,
+            min_samples: 12,
+            high_watermark_percent: 90.0,
+            flood_stage_watermark_percent: 95.0,
+        }
+    }
+}
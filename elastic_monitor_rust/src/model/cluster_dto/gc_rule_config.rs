@@ -0,0 +1,53 @@
+use crate::common::*;
+
+use crate::model::monitoring::{metric_info::*, node_rate_info::*};
+
+#[doc = "Long GC alert rule -> the alert fires while the share of the last interval a node spent
+         in old-gen collections exceeds `max_old_gc_time_ratio` (%). This usually means a heap death spiral."]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Getters)]
+#[getset(get = "pub")]
+#[serde(default)]
+pub struct GcRuleConfig {
+    pub enabled: bool,
+    pub max_old_gc_time_ratio: f64,
+}
+
+impl Default for GcRuleConfig {
+    fn default() -> Self {
+        GcRuleConfig {
+            enabled: true,
+            max_old_gc_time_ratio: 10.0,
+        }
+    }
+}
+
+impl GcRuleConfig {
+    #[doc = "Function that returns the detail of the alert if the node spent too long in old-gen collections"]
+    /// # Arguments
+    /// * `metric_info` - Metrics of the node collected in this cycle
+    ///
+    /// # Returns
+    /// * Option<String>
+    pub fn get_violation(&self, metric_info: &MetricInfo) -> Option<String> {
+        if !self.enabled {
+            return None;
+        }
+
+        let rate_infos: &NodeRateInfo = &metric_info.rate_infos;
+        let old_gc_time_ratio: f64 = rate_infos.gc_old_time_ratio?;
+
+        if old_gc_time_ratio <= self.max_old_gc_time_ratio {
+            return None;
+        }
+
+        Some(format!(
+            "{}: old GC took {} ms in {:.0} sec ({:.1}% of the interval, {} collections, limit: {}%)",
+            metric_info.name,
+            rate_infos.gc_old_time_delta_ms.unwrap_or(0),
+            rate_infos.interval_sec.unwrap_or(0.0),
+            old_gc_time_ratio,
+            rate_infos.gc_old_count_delta.unwrap_or(0),
+            self.max_old_gc_time_ratio
+        ))
+    }
+}
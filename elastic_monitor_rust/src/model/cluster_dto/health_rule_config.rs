@@ -0,0 +1,13 @@
+use crate::common::*;
+
+use crate::enums::cluster_health_status::*;
+
+#[doc = "Cluster health alert rule -> the alert fires once the cluster has been
+         at `status` (or worse) for at least `duration_sec` seconds. (0 = immediately)"]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Getters, new)]
+#[getset(get = "pub")]
+pub struct HealthRuleConfig {
+    pub status: ClusterHealthStatus,
+    #[serde(default)]
+    pub duration_sec: u64,
+}
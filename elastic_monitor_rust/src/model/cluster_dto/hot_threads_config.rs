@@ -0,0 +1,70 @@
+use crate::common::*;
+
+use crate::model::monitoring::metric_info::*;
+
+#[doc = "Hot threads capture rule -> `_nodes/{node}/hot_threads` is captured once the CPU or heap usage
+         of a node reaches its limit. The same node is not captured again until `cooldown_sec` has passed."]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Getters)]
+#[getset(get = "pub")]
+#[serde(default)]
+pub struct HotThreadsConfig {
+    pub enabled: bool,
+    pub cpu_usage_limit: i64,
+    pub jvm_usage_limit: i64,
+    pub cooldown_sec: u64,
+    pub threads: u64,
+    pub excerpt_max_chars: usize,
+}
+
+impl Default for HotThreadsConfig {
+    fn default() -> Self {
+        HotThreadsConfig {
+            enabled: true,
+            cpu_usage_limit: 90,
+            jvm_usage_limit: 90,
+            cooldown_sec: 600,
+            threads: 3,
+            excerpt_max_chars: 2000,
+        }
+    }
+}
+
+impl HotThreadsConfig {
+    #[doc = "Function that returns why the hot threads of the node should be captured -> None if there is no spike"]
+    /// # Arguments
+    /// * `metric_info` - Metrics of the node collected in this cycle
+    ///
+    /// # Returns
+    /// * Option<String>
+    pub fn get_trigger(&self, metric_info: &MetricInfo) -> Option<String> {
+        if !self.enabled {
+            return None;
+        }
+
+        let mut triggers: Vec<String> = Vec::new();
+
+        if let Some(cpu_usage) = metric_info.cpu_usage {
+            if cpu_usage >= self.cpu_usage_limit {
+                triggers.push(format!(
+                    "cpu_usage: {}% (limit: {}%)",
+                    cpu_usage, self.cpu_usage_limit
+                ));
+            }
+        }
+
+        if let Some(jvm_usage) = metric_info.jvm_usage {
+            if jvm_usage >= self.jvm_usage_limit {
+                triggers.push(format!(
+                    "jvm_usage: {}% (limit: {}%)",
+                    jvm_usage, self.jvm_usage_limit
+                ));
+            }
+        }
+
+        if triggers.is_empty() {
+            None
+        } else {
+            Some(triggers.join(", "))
+        }
+    }
+}
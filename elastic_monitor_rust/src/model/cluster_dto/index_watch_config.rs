@@ -0,0 +1,20 @@
+use crate::common::*;
+
+#[doc = "Watchlist of indices whose `_stats` are collected periodically.
+         Index names and wildcard patterns (e.g. `order_*`) can be mixed."]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Getters)]
+#[getset(get = "pub")]
+#[serde(default)]
+pub struct IndexWatchConfig {
+    pub interval_sec: u64,
+    pub indices: Vec<String>,
+}
+
+impl Default for IndexWatchConfig {
+    fn default() -> Self {
+        IndexWatchConfig {
+            interval_sec: 60,
+            indices: Vec::new(),
+        }
+    }
+}
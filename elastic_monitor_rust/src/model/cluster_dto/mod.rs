@@ -1,2 +1,14 @@
 pub mod cluster_config;
+pub mod cluster_health_info;
 pub mod cluster_info;
+pub mod disk_forecast_config;
+pub mod gc_rule_config;
+pub mod health_rule_config;
+pub mod hot_threads_config;
+pub mod index_watch_config;
+pub mod pending_task_rule_config;
+pub mod search_task_rule_config;
+pub mod shard_balance_config;
+pub mod step_timeout_config;
+pub mod thread_pool_config;
+pub mod unassigned_shard_info;
@@ -0,0 +1,62 @@
+use crate::common::*;
+
+use crate::model::monitoring::pending_task_info::*;
+
+#[doc = "Pending cluster task alert rule -> the alert fires once the queue length or
+         the `time_in_queue` of the oldest task exceeds its limit for `consecutive_cycles` cycles in a row."]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Getters)]
+#[getset(get = "pub")]
+#[serde(default)]
+pub struct PendingTaskRuleConfig {
+    pub max_queue_length: u64,
+    pub max_time_in_queue_sec: u64,
+    pub consecutive_cycles: u64,
+}
+
+impl Default for PendingTaskRuleConfig {
+    fn default() -> Self {
+        PendingTaskRuleConfig {
+            max_queue_length: 100,
+            max_time_in_queue_sec: 60,
+            consecutive_cycles: 3,
+        }
+    }
+}
+
+impl PendingTaskRuleConfig {
+    #[doc = "Function that returns the limits exceeded by the pending tasks -> (metric name, detail)"]
+    /// # Arguments
+    /// * `pending_task_info` - Summary of the pending cluster tasks
+    ///
+    /// # Returns
+    /// * Vec<(String, String)>
+    pub fn get_violations(&self, pending_task_info: &PendingTaskInfo) -> Vec<(String, String)> {
+        let mut violations: Vec<(String, String)> = Vec::new();
+
+        if *pending_task_info.queue_length() > self.max_queue_length {
+            violations.push((
+                String::from("pending_task_queue_length"),
+                format!(
+                    "pending tasks: {} (limit: {})",
+                    pending_task_info.queue_length(),
+                    self.max_queue_length
+                ),
+            ));
+        }
+
+        if *pending_task_info.oldest_time_in_queue_ms() > self.max_time_in_queue_sec * 1000 {
+            violations.push((
+                String::from("pending_task_time_in_queue"),
+                format!(
+                    "oldest task has been queued for {} ms (limit: {} sec) - [{}] {}",
+                    pending_task_info.oldest_time_in_queue_ms(),
+                    self.max_time_in_queue_sec,
+                    pending_task_info.oldest_task_priority(),
+                    pending_task_info.oldest_task_source()
+                ),
+            ));
+        }
+
+        violations
+    }
+}
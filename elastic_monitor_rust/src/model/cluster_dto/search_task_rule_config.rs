@@ -0,0 +1,27 @@
+use crate::common::*;
+
+#[doc = "Long-running search task rule -> the alert fires for every search task running longer than
+         `max_running_time_sec`. If `auto_cancel` is set, cancellable tasks running longer than
+         `auto_cancel_running_time_sec` are cancelled (meant for non-critical clusters)."]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Getters)]
+#[getset(get = "pub")]
+#[serde(default)]
+pub struct SearchTaskRuleConfig {
+    pub enabled: bool,
+    pub max_running_time_sec: u64,
+    pub auto_cancel: bool,
+    pub auto_cancel_running_time_sec: u64,
+    pub description_max_chars: usize,
+}
+
+impl Default for SearchTaskRuleConfig {
+    fn default() -> Self {
+        SearchTaskRuleConfig {
+            enabled: true,
+            max_running_time_sec: 60,
+            auto_cancel: false,
+            auto_cancel_running_time_sec: 300,
+            description_max_chars: 300,
+        }
+    }
+}
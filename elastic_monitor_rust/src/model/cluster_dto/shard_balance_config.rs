@@ -0,0 +1,98 @@
+use crate::common::*;
+
+use crate::model::monitoring::shard_balance_info::*;
+
+const BYTES_PER_GB: f64 = 1024.0 * 1024.0 * 1024.0;
+
+#[doc = "Shard balance rule -> the alert fires for every data node whose shard count or store size
+         exceeds the mean of the nodes with the same data roles by more than the skew limit (%).
+         Tiers whose mean is below `min_mean_shard_cnt` / `min_mean_store_size_gb` are not evaluated."]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Getters)]
+#[getset(get = "pub")]
+#[serde(default)]
+pub struct ShardBalanceConfig {
+    pub enabled: bool,
+    pub max_shard_cnt_skew_percent: f64,
+    pub max_store_size_skew_percent: f64,
+    pub min_mean_shard_cnt: f64,
+    pub min_mean_store_size_gb: f64,
+    pub top_indices: usize,
+}
+
+impl Default for ShardBalanceConfig {
+    fn default() -> Self {
+        ShardBalanceConfig {
+            enabled: true,
+            max_shard_cnt_skew_percent: 30.0,
+            max_store_size_skew_percent: 30.0,
+            min_mean_shard_cnt: 10.0,
+            min_mean_store_size_gb: 1.0,
+            top_indices: 5,
+        }
+    }
+}
+
+impl ShardBalanceConfig {
+    #[doc = "Function that returns the detail of the alert if the node holds too many shards or too much data"]
+    /// # Arguments
+    /// * `balance_info` - Shard balance of the node
+    ///
+    /// # Returns
+    /// * Option<String>
+    pub fn get_violation(&self, balance_info: &ShardBalanceInfo) -> Option<String> {
+        if !self.enabled {
+            return None;
+        }
+
+        let mut reasons: Vec<String> = Vec::new();
+
+        if balance_info.mean_shard_cnt >= self.min_mean_shard_cnt
+            && balance_info.shard_cnt_skew_percent > self.max_shard_cnt_skew_percent
+        {
+            reasons.push(format!(
+                "{} shards (+{:.1}% of the mean {:.1}, limit: {}%)",
+                balance_info.shard_cnt,
+                balance_info.shard_cnt_skew_percent,
+                balance_info.mean_shard_cnt,
+                self.max_shard_cnt_skew_percent
+            ));
+        }
+
+        if balance_info.mean_store_size_byte / BYTES_PER_GB >= self.min_mean_store_size_gb
+            && balance_info.store_size_skew_percent > self.max_store_size_skew_percent
+        {
+            reasons.push(format!(
+                "{:.1} GB (+{:.1}% of the mean {:.1} GB, limit: {}%)",
+                balance_info.store_size_byte as f64 / BYTES_PER_GB,
+                balance_info.store_size_skew_percent,
+                balance_info.mean_store_size_byte / BYTES_PER_GB,
+                self.max_store_size_skew_percent
+            ));
+        }
+
+        if reasons.is_empty() {
+            return None;
+        }
+
+        let heaviest_indices: Vec<String> = balance_info
+            .heaviest_indices
+            .iter()
+            .map(|index_usage| {
+                format!(
+                    "{} ({} shards, {:.1} GB)",
+                    index_usage.index_name,
+                    index_usage.shard_cnt,
+                    index_usage.store_size_byte as f64 / BYTES_PER_GB
+                )
+            })
+            .collect();
+
+        Some(format!(
+            "{} [{}]: {}\nheaviest indices: {}",
+            balance_info.node_name,
+            balance_info.data_roles,
+            reasons.join(", "),
+            heaviest_indices.join(", ")
+        ))
+    }
+}
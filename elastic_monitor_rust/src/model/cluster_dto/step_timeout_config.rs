@@ -0,0 +1,29 @@
+use crate::common::*;
+
+#[doc = "Timeout (seconds) of each step of a monitoring cycle"]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Getters)]
+#[getset(get = "pub")]
+#[serde(default)]
+pub struct StepTimeoutConfig {
+    pub node_check_sec: u64,
+    pub health_check_sec: u64,
+    pub metric_collect_sec: u64,
+    pub urgent_check_sec: u64,
+    pub pending_task_check_sec: u64,
+    pub search_task_check_sec: u64,
+    pub shard_balance_check_sec: u64,
+}
+
+impl Default for StepTimeoutConfig {
+    fn default() -> Self {
+        StepTimeoutConfig {
+            node_check_sec: 30,
+            health_check_sec: 30,
+            metric_collect_sec: 60,
+            urgent_check_sec: 30,
+            pending_task_check_sec: 30,
+            search_task_check_sec: 30,
+            shard_balance_check_sec: 30,
+        }
+    }
+}
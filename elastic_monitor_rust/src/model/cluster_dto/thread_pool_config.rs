@@ -0,0 +1,77 @@
+use crate::common::*;
+
+use crate::model::monitoring::{metric_info::*, thread_pool_info::*};
+
+#[doc = "Thread pool rejection alert rule -> the alert fires while a node rejects more than
+         `max_rejected_per_sec` tasks per second in the thread pool `pool`."]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Getters, new)]
+#[getset(get = "pub")]
+pub struct ThreadPoolRejectionRule {
+    pub pool: String,
+    #[serde(default)]
+    pub max_rejected_per_sec: f64,
+}
+
+#[doc = "Thread pool collection -> only the pools in `include` are indexed (every pool if empty)"]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Getters)]
+#[getset(get = "pub")]
+#[serde(default)]
+pub struct ThreadPoolConfig {
+    pub include: Vec<String>,
+    pub rejection_rules: Vec<ThreadPoolRejectionRule>,
+}
+
+impl Default for ThreadPoolConfig {
+    fn default() -> Self {
+        ThreadPoolConfig {
+            include: Vec::new(),
+            rejection_rules: vec![
+                ThreadPoolRejectionRule::new(String::from("search"), 1.0),
+                ThreadPoolRejectionRule::new(String::from("write"), 1.0),
+            ],
+        }
+    }
+}
+
+impl ThreadPoolConfig {
+    #[doc = "Function that checks whether a thread pool has to be collected"]
+    /// # Arguments
+    /// * `pool_name` - Name of the thread pool
+    pub fn is_included(&self, pool_name: &str) -> bool {
+        self.include.is_empty() || self.include.iter().any(|include| include == pool_name)
+    }
+
+    #[doc = "Function that returns the thread pools of the node rejecting too many tasks -> (pool name, detail)"]
+    /// # Arguments
+    /// * `metric_info` - Metrics of the node collected in this cycle
+    ///
+    /// # Returns
+    /// * Vec<(String, String)>
+    pub fn get_violations(&self, metric_info: &MetricInfo) -> Vec<(String, String)> {
+        self.rejection_rules
+            .iter()
+            .filter_map(|rule| {
+                let thread_pool: &ThreadPoolInfo = metric_info.thread_pools.get(&rule.pool)?;
+                let rejected_per_sec: f64 = thread_pool.rejected_per_sec?;
+
+                if rejected_per_sec <= rule.max_rejected_per_sec {
+                    return None;
+                }
+
+                Some((
+                    rule.pool.clone(),
+                    format!(
+                        "{}: '{}' thread pool rejected {:.2} tasks/s (active: {}, queue: {}/{}, limit: {}/s)",
+                        metric_info.name,
+                        rule.pool,
+                        rejected_per_sec,
+                        thread_pool.active,
+                        thread_pool.queue,
+                        thread_pool.queue_size,
+                        rule.max_rejected_per_sec
+                    ),
+                ))
+            })
+            .collect()
+    }
+}
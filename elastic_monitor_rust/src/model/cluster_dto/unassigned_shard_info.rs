@@ -0,0 +1,97 @@
+use crate::common::*;
+
+/* Maximum number of decider explanations kept per shard -> keeps the alert message short */
+const MAX_DECIDER_EXPLANATIONS: usize = 3;
+
+#[doc = "Unassigned shard with the result of `_cluster/allocation/explain`"]
+#[derive(Debug, Clone, Serialize, Deserialize, Getters, new)]
+#[getset(get = "pub")]
+pub struct UnassignedShardInfo {
+    pub index_name: String,
+    pub shard: u32,
+    pub primary: bool,
+    pub unassigned_reason: String,
+    #[new(default)]
+    pub can_allocate: String,
+    #[new(default)]
+    pub explanation: String,
+}
+
+impl UnassignedShardInfo {
+    #[doc = "Function that parses a line of `GET /_cat/shards?h=index,shard,prirep,state,unassigned.reason`.
+             Returns None if the shard is not unassigned."]
+    /// # Arguments
+    /// * `line` - A line of `_cat/shards`
+    ///
+    /// # Returns
+    /// * Option<UnassignedShardInfo>
+    pub fn from_cat_shards_line(line: &str) -> Option<Self> {
+        let parts: Vec<&str> = line.split_whitespace().collect();
+
+        if parts.len() < 4 || parts[3] != "UNASSIGNED" {
+            return None;
+        }
+
+        Some(UnassignedShardInfo::new(
+            parts[0].to_string(),
+            parts[1].parse().ok()?,
+            parts[2] == "p",
+            parts.get(4).unwrap_or(&"").to_string(),
+        ))
+    }
+
+    #[doc = "Function that applies the response of `_cluster/allocation/explain`.
+             The overall explanation is kept with the explanations of the deciders that said NO."]
+    /// # Arguments
+    /// * `explain` - Response of `_cluster/allocation/explain`
+    pub fn apply_allocation_explain(&mut self, explain: &Value) {
+        self.can_allocate = explain["can_allocate"].as_str().unwrap_or("").to_string();
+
+        let mut explanations: Vec<String> = Vec::new();
+
+        if let Some(allocate_explanation) = explain["allocate_explanation"].as_str() {
+            explanations.push(allocate_explanation.to_string());
+        }
+
+        let deciders = explain["node_allocation_decisions"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .flat_map(|node_decision| node_decision["deciders"].as_array().into_iter().flatten());
+
+        let mut decider_explanations: Vec<String> = Vec::new();
+
+        for decider in deciders {
+            if decider["decision"].as_str() != Some("NO") {
+                continue;
+            }
+
+            let decider_explanation: String = format!(
+                "[{}] {}",
+                decider["decider"].as_str().unwrap_or("-"),
+                decider["explanation"].as_str().unwrap_or("-")
+            );
+
+            if !decider_explanations.contains(&decider_explanation) {
+                decider_explanations.push(decider_explanation);
+            }
+
+            if decider_explanations.len() >= MAX_DECIDER_EXPLANATIONS {
+                break;
+            }
+        }
+
+        explanations.extend(decider_explanations);
+        self.explanation = explanations.join("\n");
+    }
+
+    #[doc = "Function that returns the shard as `index[shard] p|r`"]
+    pub fn get_shard_name(&self) -> String {
+        format!(
+            "{}[{}] {}",
+            self.index_name,
+            self.shard,
+            if self.primary { "p" } else { "r" }
+        )
+    }
+}
@@ -0,0 +1,24 @@
+use crate::common::*;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Getters)]
+#[getset(get = "pub")]
+pub struct AlertConfig {
+    /* Interval at which a still firing alert is sent again. 0 -> no reminder */
+    pub reminder_interval_sec: u64,
+}
+
+impl Default for AlertConfig {
+    fn default() -> Self {
+        AlertConfig {
+            reminder_interval_sec: 1800,
+        }
+    }
+}
+
+impl AlertConfig {
+    #[doc = "Reminder interval of firing alerts - None if reminders are disabled"]
+    pub fn get_reminder_interval(&self) -> Option<ChronoDuration> {
+        (self.reminder_interval_sec > 0)
+            .then(|| ChronoDuration::seconds(self.reminder_interval_sec as i64))
+    }
+}
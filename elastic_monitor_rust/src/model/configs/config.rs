@@ -3,13 +3,18 @@ use crate::common::*;
 use crate::utils_modules::io_utils::*;
 
 use crate::model::configs::{
-    mon_elastic_config::*, report_config::*, smtp_config::*, telegram_config::*, use_case_config::*,
+    alert_config::*, mon_elastic_config::*, report_config::*, smtp_config::*, telegram_config::*,
+    use_case_config::*,
 };
 
 use crate::env_configuration::env_config::*;
 
-static SERVER_CONFIG: once_lazy<Arc<Config>> =
-    once_lazy::new(|| Arc::new(initialize_server_config()));
+/*
+    The configuration is kept behind a lock so that it can be swapped when the file changes.
+    Readers take a snapshot (Arc) -> a reload never exposes a half-updated configuration.
+*/
+static SERVER_CONFIG: once_lazy<std::sync::RwLock<Arc<Config>>> =
+    once_lazy::new(|| std::sync::RwLock::new(Arc::new(initialize_server_config())));
 
 #[doc = "Function to initialize System configuration information instances"]
 pub fn initialize_server_config() -> Config {
@@ -19,14 +24,46 @@ pub fn initialize_server_config() -> Config {
     system_config
 }
 
+#[doc = "Function that returns a snapshot of the current system configuration"]
+pub fn get_server_config() -> Arc<Config> {
+    let server_config = SERVER_CONFIG
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+    Arc::clone(&server_config)
+}
+
+#[doc = "Function that reloads the system configuration file.
+         The new configuration is validated first and only swapped in if it is valid.
+         The `monitor_es` section requires a restart, so the running value is kept."]
+pub fn reload_server_config() -> anyhow::Result<()> {
+    let mut new_config: Config = Config::load()?;
+    new_config.validate()?;
+
+    let cur_config: Arc<Config> = get_server_config();
+
+    if new_config.monitor_es != cur_config.monitor_es {
+        warn!("[reload_server_config] Changes to the `monitor_es` section require a restart. The running value is kept.");
+        new_config.monitor_es = cur_config.monitor_es.clone();
+    }
+
+    let mut server_config = SERVER_CONFIG
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+    *server_config = Arc::new(new_config);
+
+    Ok(())
+}
+
 #[doc = "Information of SMTP configuration"]
-pub fn get_smtp_config_info() -> &'static SmtpConfig {
-    &SERVER_CONFIG.smtp
+pub fn get_smtp_config_info() -> SmtpConfig {
+    get_server_config().smtp.clone()
 }
 
 #[doc = "Information of Telegram configuration"]
-pub fn get_telegram_config_info() -> &'static TelegramConfig {
-    &SERVER_CONFIG.telegram
+pub fn get_telegram_config_info() -> TelegramConfig {
+    get_server_config().telegram.clone()
 }
 
 // #[doc = "Information of Usecase configuration"]
@@ -35,31 +72,36 @@ pub fn get_telegram_config_info() -> &'static TelegramConfig {
 // }
 
 #[doc = "Information of Elasticsearch configuration"]
-pub fn get_mon_es_config_info() -> &'static MonElasticConfig {
-    &SERVER_CONFIG.monitor_es
+pub fn get_mon_es_config_info() -> MonElasticConfig {
+    get_server_config().monitor_es.clone()
 }
 
 #[doc = "Daily report information"]
-pub fn get_daily_report_config_info() -> &'static ReportConfig {
-    &SERVER_CONFIG.daily_report
+pub fn get_daily_report_config_info() -> ReportConfig {
+    get_server_config().daily_report.clone()
 }
 
 #[doc = "Weekly report information"]
-pub fn get_weekly_report_config_info() -> &'static ReportConfig {
-    &SERVER_CONFIG.weekly_report
+pub fn get_weekly_report_config_info() -> ReportConfig {
+    get_server_config().weekly_report.clone()
 }
 
 #[doc = "Monthly report information"]
-pub fn get_monthly_report_config_info() -> &'static ReportConfig {
-    &SERVER_CONFIG.monthly_report
+pub fn get_monthly_report_config_info() -> ReportConfig {
+    get_server_config().monthly_report.clone()
 }
 
 #[doc = "Yearly report information"]
-pub fn get_yearly_report_config_info() -> &'static ReportConfig {
-    &SERVER_CONFIG.yearly_report
+pub fn get_yearly_report_config_info() -> ReportConfig {
+    get_server_config().yearly_report.clone()
+}
+
+#[doc = "Alert lifecycle configuration"]
+pub fn get_alert_config_info() -> AlertConfig {
+    get_server_config().alert.clone()
 }
 
-#[derive(Debug, Deserialize, Getters)]
+#[derive(Debug, Clone, Deserialize, Getters)]
 #[getset(get = "pub")]
 pub struct Config {
     pub smtp: SmtpConfig,
@@ -70,11 +112,13 @@ pub struct Config {
     pub weekly_report: ReportConfig,
     pub monthly_report: ReportConfig,
     pub yearly_report: ReportConfig,
+    #[serde(default)]
+    pub alert: AlertConfig,
 }
 
 impl Config {
     pub fn new() -> Self {
-        let system_config: Config = match read_toml_from_file::<Config>(&SYSTEM_CONFIG_PATH) {
+        let system_config: Config = match Self::load() {
             Ok(system_config) => system_config,
             Err(e) => {
                 error!(
@@ -97,6 +141,41 @@ impl Config {
             weekly_report: system_config.weekly_report,
             monthly_report: system_config.monthly_report,
             yearly_report: system_config.yearly_report,
+            alert: system_config.alert,
+        }
+    }
+
+    #[doc = "Function that reads the system configuration file"]
+    pub fn load() -> anyhow::Result<Self> {
+        read_toml_from_file::<Config>(&SYSTEM_CONFIG_PATH).map_err(|e| {
+            anyhow!(
+                "[Config::load] Failed to read '{}': {:?}",
+                *SYSTEM_CONFIG_PATH,
+                e
+            )
+        })
+    }
+
+    #[doc = "Function that validates values which cannot be checked by deserialization"]
+    pub fn validate(&self) -> anyhow::Result<()> {
+        let report_configs: [(&str, &ReportConfig); 4] = [
+            ("daily_report", &self.daily_report),
+            ("weekly_report", &self.weekly_report),
+            ("monthly_report", &self.monthly_report),
+            ("yearly_report", &self.yearly_report),
+        ];
+
+        for (section, report_config) in report_configs {
+            cron::Schedule::from_str(report_config.cron_schedule()).map_err(|e| {
+                anyhow!(
+                    "[Config::validate] Invalid cron schedule in [{}] '{}': {:?}",
+                    section,
+                    report_config.cron_schedule(),
+                    e
+                )
+            })?;
         }
+
+        Ok(())
     }
 }
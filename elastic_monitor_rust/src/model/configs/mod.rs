@@ -1,3 +1,4 @@
+pub mod alert_config;
 pub mod config;
 pub mod mon_elastic_config;
 pub mod report_config;
@@ -1,6 +1,6 @@
 use crate::common::*;
 
-#[derive(Serialize, Deserialize, Debug, Getters)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Getters)]
 #[getset(get = "pub")]
 pub struct MonElasticConfig {
     pub cluster_name: String,
@@ -12,4 +12,10 @@ pub struct MonElasticConfig {
     pub per_index_pattern: String,
     pub urgent_index_pattern: String,
     pub err_log_index_pattern: String,
+    #[serde(default)]
+    pub cycle_index_pattern: Option<String>,
+    #[serde(default)]
+    pub pending_task_index_pattern: Option<String>,
+    #[serde(default)]
+    pub cluster_metric_index_pattern: Option<String>,
 }
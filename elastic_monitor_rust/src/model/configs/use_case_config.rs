@@ -1,6 +1,6 @@
 use crate::common::*;
 
-#[derive(Serialize, Deserialize, Debug, Getters)]
+#[derive(Serialize, Deserialize, Debug, Clone, Getters)]
 #[getset(get = "pub")]
 pub struct UseCaseConfig {
     pub use_case: String,
@@ -36,3 +36,35 @@ pub struct DateHistogramAggregation {
 pub struct ErrorLogsAggregation {
     pub logs_per_time: DateHistogramAggregation,
 }
+
+#[derive(Debug, Deserialize, Default)]
+pub struct AvgAggregation {
+    pub value: Option<f64>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+pub struct DiskUsageTimeBucket {
+    pub key: i64,
+    pub avg_disk_usage: AvgAggregation,
+}
+
+#[derive(Debug, Deserialize, Default)]
+pub struct DiskUsageTimeAggregation {
+    pub buckets: Vec<DiskUsageTimeBucket>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+pub struct DiskUsageHostBucket {
+    pub key: String,
+    pub disk_usage_per_time: DiskUsageTimeAggregation,
+}
+
+#[derive(Debug, Deserialize, Default)]
+pub struct DiskUsageHostAggregation {
+    pub buckets: Vec<DiskUsageHostBucket>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+pub struct DiskUsageAggregation {
+    pub disk_usage_per_host: DiskUsageHostAggregation,
+}
@@ -0,0 +1,100 @@
+use crate::common::*;
+
+use crate::enums::es_distribution::*;
+
+use crate::model::elastic_dto::metric_field_mapping::*;
+
+/* 7.x `in_flight_requests` breaker -> `inflight_requests` from Elasticsearch 8.x */
+const INFLIGHT_BREAKER_OVERRIDE: (&str, &str) =
+    ("breakers.in_flight_requests", "breakers.inflight_requests");
+
+/*
+    Segment memory fields which are only tracked by Lucene 8 (Elasticsearch 7.x, OpenSearch 1.x).
+    Elasticsearch 8.x deprecated them and reports them as 0.
+    The memory of the index writer, the version map and the fixed bit sets is still tracked.
+*/
+const LUCENE8_SEGMENT_MEMORY_FIELDS: [&str; 7] = [
+    "indices.segments.memory_in_bytes",
+    "indices.segments.terms_memory_in_bytes",
+    "indices.segments.stored_fields_memory_in_bytes",
+    "indices.segments.term_vectors_memory_in_bytes",
+    "indices.segments.norms_memory_in_bytes",
+    "indices.segments.points_memory_in_bytes",
+    "indices.segments.doc_values_memory_in_bytes",
+];
+
+#[doc = "Version of a cluster -> detected from `GET /`"]
+#[derive(Debug, Clone, PartialEq, Getters, new)]
+#[getset(get = "pub")]
+pub struct EsVersionInfo {
+    pub distribution: EsDistribution,
+    pub number: String,
+    pub major: u32,
+}
+
+impl EsVersionInfo {
+    #[doc = "Function that parses the response of `GET /`"]
+    /// # Arguments
+    /// * `root_info` - Response body of `GET /`
+    ///
+    /// # Returns
+    /// * Result<EsVersionInfo, anyhow::Error>
+    pub fn from_root_info(root_info: &Value) -> anyhow::Result<Self> {
+        let version: &Value = root_info
+            .get("version")
+            .ok_or_else(|| anyhow!("[EsVersionInfo::from_root_info] 'version' field is missing"))?;
+
+        let number: &str = version
+            .get("number")
+            .and_then(Value::as_str)
+            .ok_or_else(|| {
+                anyhow!("[EsVersionInfo::from_root_info] 'version.number' field is missing")
+            })?;
+
+        let major: u32 = number
+            .split('.')
+            .next()
+            .and_then(|major| major.parse::<u32>().ok())
+            .ok_or_else(|| {
+                anyhow!(
+                    "[EsVersionInfo::from_root_info] Invalid version number: {}",
+                    number
+                )
+            })?;
+
+        /* OpenSearch reports `version.distribution: opensearch` */
+        let distribution: EsDistribution = match version.get("distribution").and_then(Value::as_str)
+        {
+            Some("opensearch") => EsDistribution::OpenSearch,
+            _ => EsDistribution::Elasticsearch,
+        };
+
+        Ok(EsVersionInfo::new(distribution, number.to_string(), major))
+    }
+
+    #[doc = "Function that returns the field mapping of `_nodes/stats` for this version"]
+    pub fn get_metric_field_mapping(&self) -> MetricFieldMapping {
+        match (self.distribution, self.major) {
+            /* OpenSearch is a fork of Elasticsearch 7.10 -> same field names as 7.x */
+            (EsDistribution::OpenSearch, 0..=1) => MetricFieldMapping::default(),
+            /* OpenSearch 2.x~ moved to Lucene 9 -> the segment memory is no longer tracked */
+            (EsDistribution::OpenSearch, _) => {
+                MetricFieldMapping::new(vec![], LUCENE8_SEGMENT_MEMORY_FIELDS.to_vec())
+            }
+            (EsDistribution::Elasticsearch, 0..=7) => MetricFieldMapping::default(),
+            /*
+                8.x~: breaker renamed, Lucene 8 segment memory fields no longer meaningful.
+                9.x reports the same node stats as 8.x, and unknown later versions use the latest known mapping.
+            */
+            (EsDistribution::Elasticsearch, _) => MetricFieldMapping::new(
+                vec![INFLIGHT_BREAKER_OVERRIDE],
+                LUCENE8_SEGMENT_MEMORY_FIELDS.to_vec(),
+            ),
+        }
+    }
+
+    #[doc = "Human readable name -> e.g. elasticsearch 8.11.1"]
+    pub fn get_version_name(&self) -> String {
+        format!("{} {}", self.distribution.get_name(), self.number)
+    }
+}
@@ -0,0 +1,50 @@
+use crate::common::*;
+
+use crate::utils_modules::json_utils::*;
+
+#[doc = "Mapping of the `_nodes/stats` field paths which differ between versions.
+         Paths are written in the 7.x form and converted to the form of the monitored cluster."]
+#[derive(Debug, Clone, Default, new)]
+pub struct MetricFieldMapping {
+    /* (7.x path prefix, path prefix of the monitored version) */
+    path_overrides: Vec<(&'static str, &'static str)>,
+    /* 7.x path prefixes which the monitored version no longer reports (or reports without meaning) */
+    not_applicable_paths: Vec<&'static str>,
+}
+
+impl MetricFieldMapping {
+    #[doc = "Function that returns the rest of the path after the prefix -> None unless the prefix ends at a path segment"]
+    fn strip_path_prefix<'a>(path: &'a str, prefix: &str) -> Option<&'a str> {
+        path.strip_prefix(prefix)
+            .filter(|rest| rest.is_empty() || rest.starts_with('.'))
+    }
+
+    #[doc = "Function that converts a 7.x field path into the path of the monitored version"]
+    pub fn get_path(&self, path: &str) -> String {
+        for (from_prefix, to_prefix) in &self.path_overrides {
+            if let Some(rest) = Self::strip_path_prefix(path, from_prefix) {
+                return format!("{}{}", to_prefix, rest);
+            }
+        }
+
+        path.to_string()
+    }
+
+    #[doc = "Function that checks whether a 7.x field path is not applicable to the monitored version"]
+    pub fn is_not_applicable(&self, path: &str) -> bool {
+        self.not_applicable_paths
+            .iter()
+            .any(|prefix| Self::strip_path_prefix(path, prefix).is_some())
+    }
+
+    #[doc = "Function that reads a value of `_nodes/stats` through the mapping"]
+    /// # Arguments
+    /// * `json_value`  - Stats of a node
+    /// * `path`        - Field path (7.x form)
+    ///
+    /// # Returns
+    /// * Result<T, anyhow::Error>
+    pub fn get_value<T: FromStr>(&self, json_value: &Value, path: &str) -> anyhow::Result<T> {
+        get_value_by_path(json_value, &self.get_path(path))
+    }
+}
@@ -1,2 +1,5 @@
 pub mod dummy_data;
 pub mod elastic_source_parser;
+pub mod es_version_info;
+pub mod metric_field_mapping;
+pub mod node_stats_parser;
@@ -0,0 +1,65 @@
+use crate::common::*;
+
+use crate::model::elastic_dto::metric_field_mapping::*;
+
+#[doc = "Parser that reads the fields of a node of `_nodes/stats`.
+         A field that cannot be read does not fail the whole node -> it is collected as a missing field.
+         A field that the version of the cluster does not report is not read and collected as not applicable."]
+#[derive(Debug)]
+pub struct NodeStatsParser<'a> {
+    node_info: &'a Value,
+    field_mapping: &'a MetricFieldMapping,
+    /* (field path, reason) */
+    field_errors: Vec<(String, String)>,
+    not_applicable_fields: Vec<String>,
+}
+
+impl<'a> NodeStatsParser<'a> {
+    pub fn new(node_info: &'a Value, field_mapping: &'a MetricFieldMapping) -> Self {
+        NodeStatsParser {
+            node_info,
+            field_mapping,
+            field_errors: Vec::new(),
+            not_applicable_fields: Vec::new(),
+        }
+    }
+
+    #[doc = "Function that reads a field -> None if the field is missing, cannot be parsed or is not applicable"]
+    /// # Arguments
+    /// * `path` - Field path (7.x form)
+    ///
+    /// # Returns
+    /// * Option<T>
+    pub fn get_value<T: FromStr>(&mut self, path: &str) -> Option<T> {
+        if self.field_mapping.is_not_applicable(path) {
+            self.not_applicable_fields.push(path.to_string());
+            return None;
+        }
+
+        match self.field_mapping.get_value(self.node_info, path) {
+            Ok(value) => Some(value),
+            Err(e) => {
+                self.field_errors.push((path.to_string(), e.to_string()));
+                None
+            }
+        }
+    }
+
+    #[doc = "Function that returns the paths of the fields which could not be read"]
+    pub fn get_missing_fields(&self) -> Vec<String> {
+        self.field_errors
+            .iter()
+            .map(|(path, _)| path.clone())
+            .collect()
+    }
+
+    #[doc = "Function that returns the paths of the fields which the version of the cluster does not report"]
+    pub fn get_not_applicable_fields(&self) -> Vec<String> {
+        self.not_applicable_fields.clone()
+    }
+
+    #[doc = "Function that returns the collected field errors -> (field path, reason)"]
+    pub fn into_field_errors(self) -> Vec<(String, String)> {
+        self.field_errors
+    }
+}
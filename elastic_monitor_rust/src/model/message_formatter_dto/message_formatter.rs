@@ -3,6 +3,9 @@ use crate::common::*;
 pub trait MessageFormatter {
     fn get_telegram_format(&self) -> String;
     fn get_email_format(&self) -> HtmlContents;
+    fn get_email_subject(&self) -> String {
+        String::from("[Elasticsearch] Error Alert")
+    }
 }
 
 #[derive(Debug, new)]
@@ -0,0 +1,84 @@
+use crate::common::*;
+
+use crate::model::{message_formatter_dto::message_formatter::*, monitoring::hot_threads_info::*};
+
+use crate::env_configuration::env_config::*;
+
+#[derive(Debug, Getters, new)]
+#[getset(get = "pub")]
+pub struct MessageFormatterHotThreads {
+    pub cluster_name: String,
+    pub hot_threads_infos: Vec<HotThreadsInfo>,
+    pub excerpt_max_chars: usize,
+}
+
+impl MessageFormatterHotThreads {
+    #[doc = "Function that escapes the hot threads output so that it can be placed in the html body
+             -> stack frames such as `<init>` would otherwise be read as tags."]
+    fn escape_html(text: &str) -> String {
+        text.replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;")
+    }
+}
+
+impl MessageFormatter for MessageFormatterHotThreads {
+    #[doc = "Telgram 형식으로 변환해주는 함수 -> Message Too Long 이슈 때문에, hot threads 는 메일로만 전달."]
+    fn get_telegram_format(&self) -> String {
+        let mut msg_contents: String = String::new();
+        msg_contents.push_str(format!("==== Error Alert [{}] ====\n", self.cluster_name).as_str());
+        msg_contents.push_str(format!("[cluster name]\n{}\n\n", self.cluster_name).as_str());
+
+        msg_contents.push_str("[err_subject]\nCPU/heap spike - hot threads captured\n\n");
+        msg_contents.push_str("[err_detail]\n");
+
+        for hot_threads_info in self.hot_threads_infos() {
+            msg_contents.push_str(
+                format!(
+                    " {} - {}\n",
+                    hot_threads_info.node_name(),
+                    hot_threads_info.trigger()
+                )
+                .as_str(),
+            );
+        }
+
+        msg_contents
+            .push_str("\nThe hot threads are attached to the alert email and the error log.\n");
+
+        msg_contents
+    }
+
+    #[doc = "Email 형식에 맞게 변환"]
+    fn get_email_format(&self) -> HtmlContents {
+        let mut html_forms: String = String::new();
+
+        for hot_threads_info in self.hot_threads_infos() {
+            let html_form: String = format!(
+                "
+                <tr>
+                    <td style='border: 1px solid #ddd; padding: 8px; text-align: left;'>{}</td>
+                    <td style='border: 1px solid #ddd; padding: 8px; text-align: left; color: red;'>Hot threads of '{}' captured ({})</td>
+                    <td style='border: 1px solid #ddd; padding: 8px; text-align: left;'><pre style='white-space: pre-wrap; font-size: 11px;'>{}</pre></td>
+                    <td style='border: 1px solid #ddd; padding: 8px; text-align: left;'>{}</td>
+                </tr>
+                ",
+                self.cluster_name,
+                hot_threads_info.node_name(),
+                hot_threads_info.trigger(),
+                Self::escape_html(&hot_threads_info.get_excerpt(self.excerpt_max_chars)),
+                hot_threads_info.host()
+            );
+
+            html_forms.push_str(&html_form);
+        }
+
+        let mut html_form_map: HashMap<String, String> = HashMap::new();
+        html_form_map.insert("cluster_info".to_string(), html_forms);
+
+        let html_format: &once_lazy<String> = &HTML_TEMPLATE_PATH;
+        let html_contents: HtmlContents = HtmlContents::new(html_form_map, html_format.to_string());
+
+        html_contents
+    }
+}
@@ -1,13 +1,21 @@
 use crate::common::*;
 
-use crate::model::{message_formatter_dto::message_formatter::*, search_indicies::*};
+use crate::model::{
+    cluster_dto::unassigned_shard_info::*, message_formatter_dto::message_formatter::*,
+    search_indicies::*,
+};
+
+/* Maximum length of an allocation explanation in the Telegram message -> Message Too Long */
+const TELEGRAM_EXPLANATION_MAX_LEN: usize = 200;
 
 #[derive(Debug, new)]
 pub struct MessageFormatterIndex {
     pub cluster_name: String,
     pub host: Vec<String>,
     pub err_subject: String,
+    pub unassigned_shards: u64,
     pub err_index_detail: Vec<SearchIndicies>,
+    pub unassigned_shard_infos: Vec<UnassignedShardInfo>,
 }
 
 impl MessageFormatter for MessageFormatterIndex {
@@ -23,6 +31,32 @@ impl MessageFormatter for MessageFormatterIndex {
         msg_contents.push_str(format!("==== Error Alert [{}] ====\n", self.cluster_name).as_str());
         msg_contents.push_str(format!("[cluster name]\n{}\n\n", self.cluster_name).as_str());
         msg_contents.push_str(format!("[err_subject]\n{}\n\n", self.err_subject).as_str());
+        msg_contents
+            .push_str(format!("[unassigned shards]\n{}\n\n", self.unassigned_shards).as_str());
+
+        if !self.unassigned_shard_infos.is_empty() {
+            msg_contents.push_str("[allocation explain]\n");
+
+            for shard in &self.unassigned_shard_infos {
+                let explanation: String = shard
+                    .explanation()
+                    .chars()
+                    .take(TELEGRAM_EXPLANATION_MAX_LEN)
+                    .collect();
+
+                msg_contents.push_str(
+                    format!(
+                        "{} ({}): {}\n",
+                        shard.get_shard_name(),
+                        shard.unassigned_reason(),
+                        explanation
+                    )
+                    .as_str(),
+                );
+            }
+
+            msg_contents.push('\n');
+        }
 
         let host_str = self.host.join("\n");
         msg_contents.push_str(format!("[host]\n{}\n\n", host_str).as_str());
@@ -52,6 +86,10 @@ impl MessageFormatter for MessageFormatterIndex {
         }
 
         html_form_map.insert("cluster_info".to_string(), cluster_html_forms);
+        html_form_map.insert(
+            "unassigned_shards".to_string(),
+            self.unassigned_shards.to_string(),
+        );
 
         let mut index_html_form = String::new();
 
@@ -81,6 +119,27 @@ impl MessageFormatter for MessageFormatterIndex {
 
         html_form_map.insert("index_info".to_string(), index_html_form);
 
+        let mut shard_html_form: String = String::new();
+
+        for shard in &self.unassigned_shard_infos {
+            let inner_html_form: String = format!(
+                "<tr>
+                    <td style='border: 1px solid #ddd; padding: 8px; text-align: left;'>{}</td>
+                    <td style='border: 1px solid #ddd; padding: 8px; text-align: left;'>{}</td>
+                    <td style='border: 1px solid #ddd; padding: 8px; text-align: left;'>{}</td>
+                    <td style='border: 1px solid #ddd; padding: 8px; text-align: left; white-space: pre-wrap;'>{}</td>
+                </tr>",
+                shard.get_shard_name(),
+                shard.unassigned_reason(),
+                shard.can_allocate(),
+                shard.explanation()
+            );
+
+            shard_html_form.push_str(&inner_html_form);
+        }
+
+        html_form_map.insert("unassigned_shard_info".to_string(), shard_html_form);
+
         let html_contents: HtmlContents =
             HtmlContents::new(html_form_map, "./html/detail_info.html".to_string());
 
@@ -0,0 +1,74 @@
+use crate::common::*;
+
+use crate::model::{alert_dto::alert_transition::*, message_formatter_dto::message_formatter::*};
+
+use crate::utils_modules::time_utils::*;
+
+#[derive(Debug, Getters, new)]
+#[getset(get = "pub")]
+pub struct MessageFormatterResolved {
+    pub cluster_name: String,
+    pub resolved_infos: Vec<AlertTransition>,
+}
+
+impl MessageFormatter for MessageFormatterResolved {
+    #[doc = "Telgram 형식으로 변환해주는 함수"]
+    fn get_telegram_format(&self) -> String {
+        let mut msg_contents: String = String::new();
+        msg_contents.push_str(format!("==== RESOLVED [{}] ====\n", self.cluster_name).as_str());
+        msg_contents.push_str(format!("[cluster name]\n{}\n\n", self.cluster_name).as_str());
+        msg_contents.push_str("[resolved_detail]\n");
+
+        for resolved in self.resolved_infos() {
+            msg_contents.push_str(
+                format!(
+                    " [{}] {} - firing since {}\n",
+                    resolved.key().check_type().get_name(),
+                    resolved.key().get_target_name(),
+                    convert_date_to_str_human(resolved.fired_at().with_timezone(&Local), Local)
+                )
+                .as_str(),
+            );
+        }
+
+        msg_contents
+    }
+
+    #[doc = "Email 형식에 맞게 변환"]
+    fn get_email_format(&self) -> HtmlContents {
+        let mut html_forms: String = String::new();
+
+        for resolved in self.resolved_infos() {
+            let html_form: String = format!(
+                "
+                <tr>
+                    <td style='border: 1px solid #ddd; padding: 8px; text-align: left;'>{}</td>
+                    <td style='border: 1px solid #ddd; padding: 8px; text-align: left;'>{}</td>
+                    <td style='border: 1px solid #ddd; padding: 8px; text-align: left;'>{}</td>
+                    <td style='border: 1px solid #ddd; padding: 8px; text-align: left;'>{}</td>
+                    <td style='border: 1px solid #ddd; padding: 8px; text-align: left;'>{}</td>
+                    <td style='border: 1px solid #ddd; padding: 8px; text-align: left; color: green;'>{}</td>
+                </tr>
+                ",
+                self.cluster_name,
+                resolved.key().check_type().get_name(),
+                resolved.key().get_target_name(),
+                resolved.detail(),
+                convert_date_to_str_human(resolved.fired_at().with_timezone(&Local), Local),
+                convert_date_to_str_human(resolved.occurred_at().with_timezone(&Local), Local)
+            );
+
+            html_forms.push_str(&html_form);
+        }
+
+        let mut html_form_map: HashMap<String, String> = HashMap::new();
+        html_form_map.insert("resolved_info".to_string(), html_forms);
+
+        HtmlContents::new(html_form_map, "./html/resolved_info.html".to_string())
+    }
+
+    #[doc = "Email subject of the resolved notification"]
+    fn get_email_subject(&self) -> String {
+        String::from("[Elasticsearch] Resolved")
+    }
+}
@@ -2,6 +2,8 @@ use crate::common::*;
 
 use crate::model::message_formatter_dto::message_formatter::*;
 
+use crate::enums::urgent_severity::*;
+
 use crate::env_configuration::env_config::*;
 
 #[derive(Debug, Getters, new)]
@@ -10,6 +12,21 @@ pub struct UrgentAlarmInfo {
     pub host: String,
     pub metric_name: String,
     pub metric_value_str: String,
+    pub severity: UrgentSeverity,
+    pub condition: String, /* e.g. `> 90 for 3 samples` */
+    /* Node of the alarm -> only set for the Elasticsearch node metrics (several nodes can share a host) */
+    #[new(default)]
+    pub node_name: Option<String>,
+}
+
+impl UrgentAlarmInfo {
+    #[doc = "Host of the alarm, with the node name if known -> e.g. `10.0.0.1 (node-1)`"]
+    pub fn get_target_name(&self) -> String {
+        match &self.node_name {
+            Some(node_name) => format!("{} ({})", self.host, node_name),
+            None => self.host.clone(),
+        }
+    }
 }
 
 #[derive(Debug, Getters, new)]
@@ -36,10 +53,12 @@ impl MessageFormatter for MessageFormatterUrgent {
         for urgent_info in self.urgent_infos() {
             msg_contents.push_str(
                 format!(
-                    " {}: {} - {}\n",
+                    " [{}] {}: {} ({}) - {}\n",
+                    urgent_info.severity().get_name(),
                     urgent_info.metric_name(),
                     urgent_info.metric_value_str(),
-                    urgent_info.host()
+                    urgent_info.condition(),
+                    urgent_info.get_target_name()
                 )
                 .as_str(),
             );
@@ -56,15 +75,16 @@ impl MessageFormatter for MessageFormatterUrgent {
                 "
                 <tr>
                     <td style='border: 1px solid #ddd; padding: 8px; text-align: left;'>{}</td>
-                    <td style='border: 1px solid #ddd; padding: 8px; text-align: left; color: red;'>There was a problem with '{}' indicators. </td>
-                    <td style='border: 1px solid #ddd; padding: 8px; text-align: left; color: red;'>{}: {}</td>
+                    <td style='border: 1px solid #ddd; padding: 8px; text-align: left; color: red;'>[{}] There was a problem with '{}' indicators. </td>
+                    <td style='border: 1px solid #ddd; padding: 8px; text-align: left; color: red;'>{}: {} ({})</td>
                     <td style='border: 1px solid #ddd; padding: 8px; text-align: left;'>{}</td>
                 </tr>
                 ",
                 self.cluster_name,
+                urgent_info.severity().get_name(),
                 urgent_info.metric_name(),
-                urgent_info.metric_name(), urgent_info.metric_value_str(),
-                urgent_info.host()
+                urgent_info.metric_name(), urgent_info.metric_value_str(), urgent_info.condition(),
+                urgent_info.get_target_name()
             );
 
             html_forms.push_str(&html_form);
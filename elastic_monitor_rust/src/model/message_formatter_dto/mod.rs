@@ -1,4 +1,6 @@
 pub mod message_formatter;
+pub mod message_formatter_hot_threads;
 pub mod message_formatter_index;
 pub mod message_formatter_node;
+pub mod message_formatter_resolved;
 pub mod message_formatter_urgent;
@@ -1,3 +1,4 @@
+pub mod alert_dto;
 pub mod cluster_dto;
 pub mod configs;
 pub mod elastic_dto;
@@ -7,6 +8,8 @@ pub mod receiver_email;
 pub mod receiver_email_list;
 pub mod reports;
 pub mod search_indicies;
+pub mod silence_dto;
 pub mod sqlserver;
+pub mod task_dto;
 pub mod thread_pool_stat;
 pub mod urgent_dto;
@@ -0,0 +1,426 @@
+use crate::common::*;
+
+use crate::enums::anomaly_source::*;
+
+/* Baselines not updated for this long (node removed, rule deleted ...) are dropped from the store */
+const BASELINE_RETENTION_DAYS: i64 = 7;
+
+#[doc = "Rolling baseline of a metric of a target -> exponentially weighted mean and variance"]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Getters)]
+#[getset(get = "pub")]
+pub struct AnomalyBaseline {
+    pub mean: f64,
+    pub variance: f64,
+    pub sample_cnt: u64,
+    pub updated_at_millis: i64,
+}
+
+#[doc = "Latest sample of a target compared with the baseline it had before the sample was added"]
+#[derive(Debug, Clone, Getters, new)]
+#[getset(get = "pub")]
+pub struct AnomalyObservation {
+    pub value: f64,
+    pub z_score: f64,
+    pub mean: f64,
+    pub std_deviation: f64,
+}
+
+#[doc = "Samples of a baseline target (node name or host) collected in this cycle"]
+#[derive(Debug, new)]
+pub struct AnomalyTargetSamples<'a> {
+    pub target: String,
+    pub host: String,
+    pub samples: Vec<(DateTime<Utc>, &'a Value)>, /* oldest first */
+}
+
+impl AnomalyBaseline {
+    #[doc = "Function that builds a baseline from the statistics of the stored history"]
+    /// # Arguments
+    /// * `sample_cnt`          - Number of samples in the history
+    /// * `mean`                - Average of the samples
+    /// * `std_deviation`       - Standard deviation of the samples
+    /// * `updated_at_millis`   - End of the history -> older samples are not added again
+    ///
+    /// # Returns
+    /// * AnomalyBaseline
+    pub fn from_stats(
+        sample_cnt: u64,
+        mean: f64,
+        std_deviation: f64,
+        updated_at_millis: i64,
+    ) -> Self {
+        AnomalyBaseline {
+            mean,
+            variance: std_deviation * std_deviation,
+            sample_cnt,
+            updated_at_millis,
+        }
+    }
+
+    #[doc = "Function that extracts the baselines from the response of the bootstrap aggregation
+             -> `per_target` terms buckets with a `metric_{i}` extended_stats per metric name"]
+    /// # Arguments
+    /// * `aggregations`        - `aggregations` of the search response
+    /// * `metric_names`        - Metric names in the order of the `metric_{i}` aggregations
+    /// * `updated_at_millis`   - End of the history
+    ///
+    /// # Returns
+    /// * Vec<(String, String, AnomalyBaseline)> - (target, metric name, baseline)
+    pub fn from_bootstrap_aggregation(
+        aggregations: &Value,
+        metric_names: &[String],
+        updated_at_millis: i64,
+    ) -> Vec<(String, String, AnomalyBaseline)> {
+        let mut baselines: Vec<(String, String, AnomalyBaseline)> = Vec::new();
+
+        for bucket in aggregations["per_target"]["buckets"]
+            .as_array()
+            .into_iter()
+            .flatten()
+        {
+            let target: &str = match bucket["key"].as_str() {
+                Some(target) => target,
+                None => continue,
+            };
+
+            for (idx, metric_name) in metric_names.iter().enumerate() {
+                let stats: &Value = &bucket[format!("metric_{}", idx)];
+                let sample_cnt: u64 = stats["count"].as_u64().unwrap_or(0);
+
+                /* `avg` is null when no document of the target holds the metric */
+                let (Some(mean), Some(std_deviation)) =
+                    (stats["avg"].as_f64(), stats["std_deviation"].as_f64())
+                else {
+                    continue;
+                };
+
+                if sample_cnt == 0 {
+                    continue;
+                }
+
+                baselines.push((
+                    target.to_string(),
+                    metric_name.clone(),
+                    AnomalyBaseline::from_stats(sample_cnt, mean, std_deviation, updated_at_millis),
+                ));
+            }
+        }
+
+        baselines
+    }
+
+    pub fn get_std_deviation(&self) -> f64 {
+        self.variance.max(0.0).sqrt()
+    }
+
+    #[doc = "Function that returns how many standard deviations the value is from the mean.
+             Returns None while the baseline has no spread (e.g. a metric which never changed)."]
+    pub fn get_z_score(&self, value: f64) -> Option<f64> {
+        let std_deviation: f64 = self.get_std_deviation();
+
+        if std_deviation <= f64::EPSILON {
+            return None;
+        }
+
+        Some((value - self.mean) / std_deviation)
+    }
+
+    #[doc = "Function that adds a sample to the baseline (exponentially weighted mean and variance)"]
+    /// # Arguments
+    /// * `value`               - Value of the sample
+    /// * `alpha`               - Weight of the sample, in (0, 1]
+    /// * `sampled_at_millis`   - Time of the sample
+    pub fn update(&mut self, value: f64, alpha: f64, sampled_at_millis: i64) {
+        if self.sample_cnt == 0 {
+            self.mean = value;
+            self.variance = 0.0;
+        } else {
+            let diff: f64 = value - self.mean;
+            let increment: f64 = alpha * diff;
+
+            self.mean += increment;
+            self.variance = (1.0 - alpha) * (self.variance + diff * increment);
+        }
+
+        self.sample_cnt += 1;
+        self.updated_at_millis = sampled_at_millis;
+    }
+}
+
+#[doc = "Baselines of every target and metric -> persisted into a JSON file so that they survive restarts"]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AnomalyBaselineStore {
+    /* `{source}/{target}/{metric name}` -> baseline */
+    pub baselines: HashMap<String, AnomalyBaseline>,
+    /* `{source}/{metric name}` of the rules already bootstrapped from the history by this process */
+    #[serde(skip)]
+    pub bootstrapped: HashSet<String>,
+}
+
+impl AnomalyBaselineStore {
+    pub fn get_key(source: AnomalySource, target: &str, metric_name: &str) -> String {
+        format!("{}/{}/{}", source.get_name(), target, metric_name)
+    }
+
+    pub fn get_bootstrap_key(source: AnomalySource, metric_name: &str) -> String {
+        format!("{}/{}", source.get_name(), metric_name)
+    }
+
+    #[doc = "Function that returns the file of the baselines of a cluster -> `{file stem}_{cluster name}.{extension}`.
+             Node names (e.g. `node-1`) are shared between clusters, so every cluster keeps its baselines in its own file."]
+    /// # Arguments
+    /// * `baseline_path`   - `ANOMALY_BASELINE_PATH`
+    /// * `cluster_name`    - Name of the cluster
+    ///
+    /// # Returns
+    /// * String
+    pub fn get_cluster_file_path(baseline_path: &str, cluster_name: &str) -> String {
+        let path: &Path = Path::new(baseline_path);
+
+        /* Characters which are not safe in a file name are replaced */
+        let cluster_name: String = cluster_name
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.' {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+
+        let file_stem: String = path
+            .file_stem()
+            .map(|file_stem| file_stem.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        let file_name: String = match path.extension() {
+            Some(extension) => format!(
+                "{}_{}.{}",
+                file_stem,
+                cluster_name,
+                extension.to_string_lossy()
+            ),
+            None => format!("{}_{}", file_stem, cluster_name),
+        };
+
+        path.with_file_name(file_name).to_string_lossy().to_string()
+    }
+
+    #[doc = "Function that adds the baselines built from the history.
+             A baseline already in the store (e.g. loaded from the file) is kept as it is."]
+    /// # Arguments
+    /// * `source`      - Source of the metrics
+    /// * `baselines`   - (target, metric name, baseline)
+    pub fn insert_bootstrapped(
+        &mut self,
+        source: AnomalySource,
+        baselines: Vec<(String, String, AnomalyBaseline)>,
+    ) {
+        for (target, metric_name, baseline) in baselines {
+            self.baselines
+                .entry(Self::get_key(source, &target, &metric_name))
+                .or_insert(baseline);
+        }
+    }
+
+    #[doc = "Function that adds the samples newer than the baseline and returns the observation of the latest one.
+             Each sample is compared with the baseline before it is added, and no observation is returned
+             until the baseline holds `min_samples` samples."]
+    /// # Arguments
+    /// * `key`         - Key of the baseline
+    /// * `samples`     - (time in millis, value), oldest first
+    /// * `alpha`       - Weight of a sample in the baseline
+    /// * `min_samples` - Samples required before anomalies are flagged
+    ///
+    /// # Returns
+    /// * Option<AnomalyObservation>
+    pub fn observe(
+        &mut self,
+        key: &str,
+        samples: &[(i64, f64)],
+        alpha: f64,
+        min_samples: u64,
+    ) -> Option<AnomalyObservation> {
+        let baseline: &mut AnomalyBaseline = self.baselines.entry(key.to_string()).or_default();
+        let mut observation: Option<AnomalyObservation> = None;
+
+        for (sampled_at_millis, value) in samples {
+            /* Samples already added in a previous cycle (the search ranges overlap) */
+            if baseline.sample_cnt > 0 && *sampled_at_millis <= baseline.updated_at_millis {
+                continue;
+            }
+
+            observation = if baseline.sample_cnt >= min_samples {
+                baseline.get_z_score(*value).map(|z_score| {
+                    AnomalyObservation::new(
+                        *value,
+                        z_score,
+                        baseline.mean,
+                        baseline.get_std_deviation(),
+                    )
+                })
+            } else {
+                None
+            };
+
+            baseline.update(*value, alpha, *sampled_at_millis);
+        }
+
+        observation
+    }
+
+    #[doc = "Function that drops the baselines which have not been updated for `BASELINE_RETENTION_DAYS`"]
+    pub fn remove_stale(&mut self, now: DateTime<Utc>) {
+        let oldest_millis: i64 =
+            (now - ChronoDuration::days(BASELINE_RETENTION_DAYS)).timestamp_millis();
+
+        self.baselines
+            .retain(|_, baseline| baseline.updated_at_millis >= oldest_millis);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::utils_modules::io_utils::*;
+
+    const KEY: &str = "es_metric/node-1/cpu_usage";
+
+    /* (sampled at, value) one second apart starting at `start_millis` */
+    fn samples(start_millis: i64, values: &[f64]) -> Vec<(i64, f64)> {
+        values
+            .iter()
+            .enumerate()
+            .map(|(idx, value)| (start_millis + idx as i64 * 1000, *value))
+            .collect()
+    }
+
+    #[test]
+    fn no_observation_until_min_samples() {
+        let mut store: AnomalyBaselineStore = AnomalyBaselineStore::default();
+
+        assert!(store
+            .observe(KEY, &samples(0, &[10.0, 12.0, 11.0]), 0.5, 3)
+            .is_none());
+        assert_eq!(store.baselines[KEY].sample_cnt, 3);
+
+        /* The fourth sample is compared with the baseline of the first three */
+        let observation: AnomalyObservation =
+            store.observe(KEY, &samples(3000, &[30.0]), 0.5, 3).unwrap();
+
+        assert_eq!(observation.value, 30.0);
+        assert!(observation.z_score > 3.0);
+    }
+
+    #[test]
+    fn samples_already_added_are_skipped() {
+        let mut store: AnomalyBaselineStore = AnomalyBaselineStore::default();
+
+        store.observe(KEY, &samples(0, &[10.0, 12.0]), 0.5, 1);
+        let baseline: AnomalyBaseline = store.baselines[KEY].clone();
+
+        /* Overlapping search range -> only the sample at 2000 is new */
+        store.observe(KEY, &samples(0, &[10.0, 12.0, 14.0]), 0.5, 1);
+
+        assert_eq!(store.baselines[KEY].sample_cnt, baseline.sample_cnt + 1);
+        assert_eq!(store.baselines[KEY].updated_at_millis, 2000);
+    }
+
+    #[test]
+    fn bootstrapped_baseline_accepts_samples_after_the_history() {
+        let mut store: AnomalyBaselineStore = AnomalyBaselineStore::default();
+
+        /* History ends right before the first live sample */
+        store.insert_bootstrapped(
+            AnomalySource::EsMetric,
+            vec![(
+                String::from("node-1"),
+                String::from("cpu_usage"),
+                AnomalyBaseline::from_stats(100, 20.0, 2.0, 9_999),
+            )],
+        );
+
+        let observation: AnomalyObservation = store
+            .observe(KEY, &samples(10_000, &[26.0]), 0.1, 30)
+            .unwrap();
+
+        assert_eq!(observation.mean, 20.0);
+        assert!((observation.z_score - 3.0).abs() < 1e-9);
+        assert_eq!(store.baselines[KEY].sample_cnt, 101);
+    }
+
+    #[test]
+    fn flat_baseline_has_no_observation() {
+        let mut store: AnomalyBaselineStore = AnomalyBaselineStore::default();
+
+        assert!(store
+            .observe(KEY, &samples(0, &[5.0, 5.0, 5.0, 5.0]), 0.5, 2)
+            .is_none());
+    }
+
+    #[test]
+    fn stale_baselines_are_removed() {
+        let now: DateTime<Utc> = Utc.timestamp_opt(1_700_000_000, 0).unwrap();
+        let mut store: AnomalyBaselineStore = AnomalyBaselineStore::default();
+
+        let stale_millis: i64 =
+            (now - ChronoDuration::days(BASELINE_RETENTION_DAYS + 1)).timestamp_millis();
+
+        store.observe(KEY, &[(now.timestamp_millis(), 1.0)], 0.5, 1);
+        store.observe("es_metric/node-2/cpu_usage", &[(stale_millis, 1.0)], 0.5, 1);
+        store.remove_stale(now);
+
+        assert_eq!(store.baselines.len(), 1);
+        assert!(store.baselines.contains_key(KEY));
+    }
+
+    #[test]
+    fn cluster_file_path_is_next_to_the_configured_path() {
+        assert_eq!(
+            AnomalyBaselineStore::get_cluster_file_path(
+                "./data/anomaly_baseline.json",
+                "cluster-a"
+            ),
+            "./data/anomaly_baseline_cluster-a.json"
+        );
+        assert_eq!(
+            AnomalyBaselineStore::get_cluster_file_path("./data/anomaly_baseline", "prod es/1"),
+            "./data/anomaly_baseline_prod_es_1"
+        );
+    }
+
+    #[test]
+    fn baselines_of_two_clusters_are_kept_apart() {
+        let baseline_dir: std::path::PathBuf =
+            std::env::temp_dir().join(format!("anomaly_baseline_test_{}", std::process::id()));
+        let baseline_path: String = baseline_dir
+            .join("anomaly_baseline.json")
+            .to_string_lossy()
+            .to_string();
+
+        /* Both clusters have a node named `node-1` */
+        let mut store_a: AnomalyBaselineStore = AnomalyBaselineStore::default();
+        store_a.observe(KEY, &[(1000, 10.0)], 0.5, 1);
+
+        let mut store_b: AnomalyBaselineStore = AnomalyBaselineStore::default();
+        store_b.observe(KEY, &[(1000, 90.0)], 0.5, 1);
+
+        let path_a: String =
+            AnomalyBaselineStore::get_cluster_file_path(&baseline_path, "cluster-a");
+        let path_b: String =
+            AnomalyBaselineStore::get_cluster_file_path(&baseline_path, "cluster-b");
+
+        write_json_to_file(&path_a, &store_a).unwrap();
+        write_json_to_file(&path_b, &store_b).unwrap();
+
+        let loaded_a: AnomalyBaselineStore = read_json_from_file(&path_a).unwrap();
+        let loaded_b: AnomalyBaselineStore = read_json_from_file(&path_b).unwrap();
+
+        std::fs::remove_dir_all(&baseline_dir).unwrap();
+
+        assert_eq!(loaded_a.baselines[KEY].mean, 10.0);
+        assert_eq!(loaded_b.baselines[KEY].mean, 90.0);
+    }
+}
@@ -1,8 +1,32 @@
 use crate::common::*;
 
-#[derive(Debug, Deserialize, Serialize, Clone, new)]
+#[derive(Debug, Deserialize, Serialize, Clone, Getters)]
+#[getset(get = "pub")]
 pub struct BreakerInfo {
-    limit_size_in_bytes: u64,
-    estimated_size_in_bytes: u64,
-    tripped: u64,
+    limit_size_in_bytes: Option<u64>,
+    estimated_size_in_bytes: Option<u64>,
+    tripped: Option<u64>,
+    usage_percent: Option<f64>, /* estimated / limit */
+}
+
+impl BreakerInfo {
+    pub fn new(
+        limit_size_in_bytes: Option<u64>,
+        estimated_size_in_bytes: Option<u64>,
+        tripped: Option<u64>,
+    ) -> Self {
+        let usage_percent: Option<f64> = match (limit_size_in_bytes, estimated_size_in_bytes) {
+            (Some(limit), Some(estimated)) if limit > 0 => {
+                Some(estimated as f64 / limit as f64 * 100.0)
+            }
+            _ => None,
+        };
+
+        BreakerInfo {
+            limit_size_in_bytes,
+            estimated_size_in_bytes,
+            tripped,
+            usage_percent,
+        }
+    }
 }
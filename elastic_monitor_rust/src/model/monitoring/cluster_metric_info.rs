@@ -0,0 +1,64 @@
+use crate::common::*;
+
+#[doc = "Cluster-wide statistics of a monitoring cycle -> collected from `GET /_cluster/health` and `GET /_cluster/stats`"]
+#[derive(Debug, Clone, Serialize, Deserialize, Getters)]
+#[getset(get = "pub")]
+pub struct ClusterMetricInfo {
+    pub timestamp: String,
+    pub cluster_name: String,
+    pub status: Option<String>,
+    pub number_of_nodes: Option<u64>,
+    pub number_of_data_nodes: Option<u64>,
+    pub total_shards: Option<u64>,
+    pub active_primary_shards: Option<u64>,
+    pub active_shards: Option<u64>,
+    pub active_shards_percent: Option<f64>,
+    pub relocating_shards: Option<u64>,
+    pub initializing_shards: Option<u64>,
+    pub unassigned_shards: Option<u64>,
+    pub delayed_unassigned_shards: Option<u64>,
+    pub number_of_pending_tasks: Option<u64>,
+    pub index_count: Option<u64>,
+    pub docs_count: Option<u64>,
+    pub docs_deleted: Option<u64>,
+    pub store_size_byte: Option<u64>,
+}
+
+impl ClusterMetricInfo {
+    #[doc = "Function that builds the cluster statistics from the responses of `_cluster/health` and `_cluster/stats`"]
+    /// # Arguments
+    /// * `health`          - Response of `GET /_cluster/health`
+    /// * `stats`           - Response of `GET /_cluster/stats`
+    /// * `cluster_name`    - Name of the cluster
+    /// * `timestamp`       - Collection time (string)
+    ///
+    /// # Returns
+    /// * ClusterMetricInfo
+    pub fn from_cluster_infos(
+        health: &Value,
+        stats: &Value,
+        cluster_name: &str,
+        timestamp: &str,
+    ) -> Self {
+        ClusterMetricInfo {
+            timestamp: timestamp.to_string(),
+            cluster_name: cluster_name.to_string(),
+            status: health["status"].as_str().map(str::to_string),
+            number_of_nodes: health["number_of_nodes"].as_u64(),
+            number_of_data_nodes: health["number_of_data_nodes"].as_u64(),
+            total_shards: stats["indices"]["shards"]["total"].as_u64(),
+            active_primary_shards: health["active_primary_shards"].as_u64(),
+            active_shards: health["active_shards"].as_u64(),
+            active_shards_percent: health["active_shards_percent_as_number"].as_f64(),
+            relocating_shards: health["relocating_shards"].as_u64(),
+            initializing_shards: health["initializing_shards"].as_u64(),
+            unassigned_shards: health["unassigned_shards"].as_u64(),
+            delayed_unassigned_shards: health["delayed_unassigned_shards"].as_u64(),
+            number_of_pending_tasks: health["number_of_pending_tasks"].as_u64(),
+            index_count: stats["indices"]["count"].as_u64(),
+            docs_count: stats["indices"]["docs"]["count"].as_u64(),
+            docs_deleted: stats["indices"]["docs"]["deleted"].as_u64(),
+            store_size_byte: stats["indices"]["store"]["size_in_bytes"].as_u64(),
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use crate::common::*;
+
+#[doc = "Output of `GET /_nodes/{node}/hot_threads` captured when the CPU or heap usage of a node spiked"]
+#[derive(Debug, Clone, Serialize, Deserialize, Getters, new)]
+#[getset(get = "pub")]
+pub struct HotThreadsInfo {
+    pub timestamp: String,
+    pub host: String,
+    pub node_name: String,
+    pub trigger: String,
+    pub hot_threads: String,
+}
+
+impl HotThreadsInfo {
+    #[doc = "Function that returns the beginning of the hot threads output -> used in the alert message"]
+    /// # Arguments
+    /// * `max_chars` - Maximum number of characters of the excerpt
+    ///
+    /// # Returns
+    /// * String
+    pub fn get_excerpt(&self, max_chars: usize) -> String {
+        let hot_threads: &str = self.hot_threads.trim();
+
+        match hot_threads.char_indices().nth(max_chars) {
+            Some((byte_idx, _)) => format!("{}\n... (truncated)", &hot_threads[..byte_idx]),
+            None => hot_threads.to_string(),
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use crate::common::*;
+
+use crate::model::monitoring::node_rate_info::*;
+
+#[doc = "Statistics of an index of the watchlist -> collected from `GET /{index}/_stats`"]
+#[derive(Builder, Clone, Serialize, Deserialize, Debug, Getters)]
+#[getset(get = "pub")]
+pub struct IndexMetricInfo {
+    pub timestamp: String,
+    pub cluster_name: String,
+    pub index_name: String,
+    pub docs_count: Option<u64>,
+    pub docs_deleted: Option<u64>,
+    pub store_size_byte: Option<u64>,
+    pub primary_store_size_byte: Option<u64>,
+    pub translog_operation: Option<i64>,
+    pub translog_operation_size: Option<i64>,
+    pub translog_uncommited_operation: Option<i64>,
+    pub translog_uncommited_operation_size: Option<i64>,
+    pub flush_total: Option<i64>,
+    pub refresh_total: Option<i64>,
+    pub refresh_listener: Option<i64>,
+    pub merge_current: Option<u64>,
+    pub merge_total: Option<u64>,
+    pub merge_total_time_in_millis: Option<u64>,
+    pub merge_total_size_byte: Option<u64>,
+    /* Indexing/search rates of the last interval -> computed against the previous sample of the index */
+    pub rate_infos: NodeRateInfo,
+    pub missing_fields: Vec<String>,
+}
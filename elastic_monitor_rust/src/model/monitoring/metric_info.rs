@@ -1,6 +1,8 @@
 use crate::common::*;
 
-use crate::model::monitoring::{breaker_info::*, segment_info::*};
+use crate::model::monitoring::{
+    breaker_info::*, node_rate_info::*, segment_info::*, thread_pool_info::*,
+};
 
 #[derive(Builder, Clone, Serialize, Deserialize, Debug, Getters, new)]
 #[getset(get = "pub", set = "pub")]
@@ -8,55 +10,49 @@ pub struct MetricInfo {
     pub timestamp: String,
     pub host: String,
     pub name: String,
-    pub jvm_usage: i64,
-    pub cpu_usage: i64,
-    pub disk_usage: i64,
-    pub jvm_young_usage_byte: i64,
-    pub jvm_old_usage_byte: i64,
-    pub jvm_survivor_usage_byte: i64,
-    pub jvm_buffer_pool_mapped_count: u64,
-    pub jvm_buffer_pool_mapped_use_byte: u64,
-    pub jvm_buffer_pool_mapped_total_byte: u64,
-    pub jvm_buffer_pool_direct_count: u64,
-    pub jvm_buffer_pool_direct_use_byte: u64,
-    pub jvm_buffer_pool_direct_total_byte: u64,
-    pub query_cache_hit: f64,
-    pub cache_memory_size: i64,
-    pub os_swap_total_in_bytes: i64,
-    pub os_swap_usage: f64,
-    pub http_current_open: i64,
-    pub node_shard_cnt: i64,
-    pub indexing_latency: f64,
-    pub query_latency: f64,
-    pub fetch_latency: f64,
-    pub translog_operation: i64,
-    pub translog_operation_size: i64,
-    pub translog_uncommitted_operation: i64,
-    pub translog_uncommitted_operation_size: i64,
-    pub flush_total: i64,
-    pub refresh_total: i64,
-    pub refresh_listener: i64,
-    pub search_active_thread: u32,
-    pub search_thread_queue: u32,
-    pub search_rejected_thread: u32,
-    pub write_active_thread: u32,
-    pub write_thread_queue: u32,
-    pub write_rejected_thread: u32,
-    pub bulk_active_thread: u32,
-    pub bulk_thread_queue: u32,
-    pub bulk_rejected_thread: u32,
-    pub get_active_thread: u32,
-    pub get_thread_queue: u32,
-    pub get_rejected_thread: u32,
-    pub management_active_thread: u32,
-    pub management_thread_queue: u32,
-    pub management_rejected_thread: u32,
-    pub generic_active_thread: u32,
-    pub generic_thread_queue: u32,
-    pub generic_rejected_thread: u32,
+    pub jvm_usage: Option<i64>,
+    pub cpu_usage: Option<i64>,
+    pub disk_usage: Option<i64>,
+    pub jvm_young_usage_byte: Option<i64>,
+    pub jvm_old_usage_byte: Option<i64>,
+    pub jvm_survivor_usage_byte: Option<i64>,
+    pub jvm_gc_young_count: Option<i64>,
+    pub jvm_gc_young_time_ms: Option<i64>,
+    pub jvm_gc_old_count: Option<i64>,
+    pub jvm_gc_old_time_ms: Option<i64>,
+    pub jvm_buffer_pool_mapped_count: Option<u64>,
+    pub jvm_buffer_pool_mapped_use_byte: Option<u64>,
+    pub jvm_buffer_pool_mapped_total_byte: Option<u64>,
+    pub jvm_buffer_pool_direct_count: Option<u64>,
+    pub jvm_buffer_pool_direct_use_byte: Option<u64>,
+    pub jvm_buffer_pool_direct_total_byte: Option<u64>,
+    pub query_cache_hit: Option<f64>,
+    pub cache_memory_size: Option<i64>,
+    pub os_swap_total_in_bytes: Option<i64>,
+    pub os_swap_usage: Option<f64>,
+    pub http_current_open: Option<i64>,
+    pub node_shard_cnt: Option<i64>,
+    pub indexing_latency: Option<f64>,
+    pub query_latency: Option<f64>,
+    pub fetch_latency: Option<f64>,
+    pub translog_operation: Option<i64>,
+    pub translog_operation_size: Option<i64>,
+    pub translog_uncommitted_operation: Option<i64>,
+    pub translog_uncommitted_operation_size: Option<i64>,
+    pub flush_total: Option<i64>,
+    pub refresh_total: Option<i64>,
+    pub refresh_listener: Option<i64>,
+    /* thread pool name -> stats (only the pools of the include list) */
+    pub thread_pools: BTreeMap<String, ThreadPoolInfo>,
     pub segment_infos: SegmentInfo,
     pub breaker_request: BreakerInfo,
     pub breaker_fielddata: BreakerInfo,
     pub breaker_inflight_requests: BreakerInfo,
     pub breaker_parent: BreakerInfo,
+    /* Rates and latencies of the last interval -> the fields above are cumulative since node start */
+    pub rate_infos: NodeRateInfo,
+    /* Fields which could not be collected from the node (e.g. `os.swap.total_in_bytes` in a container) */
+    pub missing_fields: Vec<String>,
+    /* Fields which the version of the cluster does not report (e.g. the segment memory of 8.x~) -> not missing */
+    pub not_applicable_fields: Vec<String>,
 }
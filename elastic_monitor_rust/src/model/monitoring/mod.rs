@@ -1,3 +1,15 @@
+pub mod anomaly_baseline;
 pub mod breaker_info;
+pub mod cluster_metric_info;
+pub mod hot_threads_info;
+pub mod index_metric_info;
 pub mod metric_info;
+pub mod monitor_cycle_info;
+pub mod node_counter_sample;
+pub mod node_membership_info;
+pub mod node_rate_info;
+pub mod pending_task_info;
+pub mod search_task_info;
 pub mod segment_info;
+pub mod shard_balance_info;
+pub mod thread_pool_info;
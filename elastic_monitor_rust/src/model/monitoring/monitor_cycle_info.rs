@@ -0,0 +1,22 @@
+use crate::common::*;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Getters, new)]
+#[getset(get = "pub")]
+pub struct MonitorStepInfo {
+    pub step_name: String,
+    pub elapsed_ms: u64,
+    pub status: String, /* ok, error, timeout */
+}
+
+#[doc = "Timing information of a single monitoring cycle"]
+#[derive(Debug, Clone, Serialize, Deserialize, Getters, new)]
+#[getset(get = "pub")]
+pub struct MonitorCycleInfo {
+    pub timestamp: String,
+    pub cluster_name: String,
+    pub interval_ms: u64,
+    pub cycle_duration_ms: u64,
+    pub overrun: bool,
+    pub skipped_cycles: u64,
+    pub steps: Vec<MonitorStepInfo>,
+}
@@ -0,0 +1,218 @@
+use crate::model::monitoring::node_rate_info::*;
+
+#[doc = "Cumulative counters of a node at a point in time -> kept per node ID to compute per-interval rates"]
+#[derive(Debug, Clone)]
+pub struct NodeCounterSample {
+    pub sampled_at_millis: i64,
+    pub uptime_in_millis: Option<i64>,
+    pub index_total: Option<i64>,
+    pub index_time_in_millis: Option<i64>,
+    pub query_total: Option<i64>,
+    pub query_time_in_millis: Option<i64>,
+    pub fetch_total: Option<i64>,
+    pub fetch_time_in_millis: Option<i64>,
+    pub flush_total: Option<i64>,
+    pub refresh_total: Option<i64>,
+    pub translog_operation: Option<i64>,
+    pub breaker_request_tripped: Option<u64>,
+    pub breaker_fielddata_tripped: Option<u64>,
+    pub breaker_inflight_requests_tripped: Option<u64>,
+    pub breaker_parent_tripped: Option<u64>,
+    pub gc_young_count: Option<i64>,
+    pub gc_young_time_in_millis: Option<i64>,
+    pub gc_old_count: Option<i64>,
+    pub gc_old_time_in_millis: Option<i64>,
+}
+
+impl NodeCounterSample {
+    #[doc = "Function that checks whether the node has been restarted since the previous sample.
+             Cumulative counters are reset when a node restarts (the node ID is kept)."]
+    /// # Arguments
+    /// * `prev` - Previous sample of the same node
+    fn is_reset_since(&self, prev: &NodeCounterSample) -> bool {
+        let decreased = |cur: Option<i64>, prev: Option<i64>| -> bool {
+            matches!((cur, prev), (Some(cur), Some(prev)) if cur < prev)
+        };
+
+        decreased(self.uptime_in_millis, prev.uptime_in_millis)
+            || decreased(self.index_total, prev.index_total)
+            || decreased(self.query_total, prev.query_total)
+            || decreased(self.fetch_total, prev.fetch_total)
+            || decreased(self.flush_total, prev.flush_total)
+            || decreased(self.refresh_total, prev.refresh_total)
+            || decreased(self.gc_young_count, prev.gc_young_count)
+            || decreased(self.gc_old_count, prev.gc_old_count)
+    }
+
+    #[doc = "Function that computes the rates and latencies of the interval between two samples"]
+    /// # Arguments
+    /// * `prev` - Previous sample of the same node (None on the first sample)
+    ///
+    /// # Returns
+    /// * NodeRateInfo
+    pub fn get_rate_info(&self, prev: Option<&NodeCounterSample>) -> NodeRateInfo {
+        let prev: &NodeCounterSample = match prev {
+            Some(prev) if !self.is_reset_since(prev) => prev,
+            _ => return NodeRateInfo::default(),
+        };
+
+        let interval_millis: i64 = self.sampled_at_millis - prev.sampled_at_millis;
+
+        if interval_millis <= 0 {
+            return NodeRateInfo::default();
+        }
+
+        let interval_sec: f64 = interval_millis as f64 / 1000.0;
+
+        let delta = |cur: Option<i64>, prev: Option<i64>| -> Option<i64> { Some(cur? - prev?) };
+        let delta_u64 =
+            |cur: Option<u64>, prev: Option<u64>| -> Option<u64> { cur?.checked_sub(prev?) };
+        let rate = |delta: Option<i64>| -> Option<f64> { Some(delta? as f64 / interval_sec) };
+        /* % of the interval spent doing something (e.g. GC) */
+        let time_ratio = |time_delta: Option<i64>| -> Option<f64> {
+            Some(time_delta? as f64 / interval_millis as f64 * 100.0)
+        };
+
+        /* ms/op of the interval -> 0 if there was no operation */
+        let latency = |time_delta: Option<i64>, op_delta: Option<i64>| -> Option<f64> {
+            let (time_delta, op_delta) = (time_delta?, op_delta?);
+
+            if op_delta == 0 {
+                Some(0.0)
+            } else {
+                Some(time_delta as f64 / op_delta as f64)
+            }
+        };
+
+        let index_delta: Option<i64> = delta(self.index_total, prev.index_total);
+        let query_delta: Option<i64> = delta(self.query_total, prev.query_total);
+        let fetch_delta: Option<i64> = delta(self.fetch_total, prev.fetch_total);
+        let gc_young_time_delta: Option<i64> =
+            delta(self.gc_young_time_in_millis, prev.gc_young_time_in_millis);
+        let gc_old_time_delta: Option<i64> =
+            delta(self.gc_old_time_in_millis, prev.gc_old_time_in_millis);
+
+        NodeRateInfo {
+            interval_sec: Some(interval_sec),
+            indexing_rate: rate(index_delta),
+            indexing_latency: latency(
+                delta(self.index_time_in_millis, prev.index_time_in_millis),
+                index_delta,
+            ),
+            query_rate: rate(query_delta),
+            query_latency: latency(
+                delta(self.query_time_in_millis, prev.query_time_in_millis),
+                query_delta,
+            ),
+            fetch_rate: rate(fetch_delta),
+            fetch_latency: latency(
+                delta(self.fetch_time_in_millis, prev.fetch_time_in_millis),
+                fetch_delta,
+            ),
+            flush_rate: rate(delta(self.flush_total, prev.flush_total)),
+            refresh_rate: rate(delta(self.refresh_total, prev.refresh_total)),
+            translog_operation_delta: delta(self.translog_operation, prev.translog_operation),
+            breaker_request_tripped: delta_u64(
+                self.breaker_request_tripped,
+                prev.breaker_request_tripped,
+            ),
+            breaker_fielddata_tripped: delta_u64(
+                self.breaker_fielddata_tripped,
+                prev.breaker_fielddata_tripped,
+            ),
+            breaker_inflight_requests_tripped: delta_u64(
+                self.breaker_inflight_requests_tripped,
+                prev.breaker_inflight_requests_tripped,
+            ),
+            breaker_parent_tripped: delta_u64(
+                self.breaker_parent_tripped,
+                prev.breaker_parent_tripped,
+            ),
+            gc_young_count_delta: delta(self.gc_young_count, prev.gc_young_count),
+            gc_young_time_delta_ms: gc_young_time_delta,
+            gc_young_time_ratio: time_ratio(gc_young_time_delta),
+            gc_old_count_delta: delta(self.gc_old_count, prev.gc_old_count),
+            gc_old_time_delta_ms: gc_old_time_delta,
+            gc_old_time_ratio: time_ratio(gc_old_time_delta),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(sampled_at_sec: i64, uptime_sec: i64, index_total: i64) -> NodeCounterSample {
+        NodeCounterSample {
+            sampled_at_millis: sampled_at_sec * 1000,
+            uptime_in_millis: Some(uptime_sec * 1000),
+            index_total: Some(index_total),
+            index_time_in_millis: Some(index_total * 2),
+            query_total: Some(0),
+            query_time_in_millis: Some(0),
+            fetch_total: None,
+            fetch_time_in_millis: None,
+            flush_total: Some(0),
+            refresh_total: Some(0),
+            translog_operation: Some(0),
+            breaker_request_tripped: Some(0),
+            breaker_fielddata_tripped: None,
+            breaker_inflight_requests_tripped: None,
+            breaker_parent_tripped: None,
+            gc_young_count: Some(0),
+            gc_young_time_in_millis: Some(0),
+            gc_old_count: Some(0),
+            gc_old_time_in_millis: Some(0),
+        }
+    }
+
+    #[test]
+    fn rates_of_the_interval() {
+        let prev: NodeCounterSample = sample(100, 1_000, 1_000);
+        let cur: NodeCounterSample = sample(110, 1_010, 1_500);
+
+        let rate_info: NodeRateInfo = cur.get_rate_info(Some(&prev));
+
+        assert_eq!(rate_info.interval_sec, Some(10.0));
+        assert_eq!(rate_info.indexing_rate, Some(50.0));
+        assert_eq!(rate_info.indexing_latency, Some(2.0));
+        /* No operation in the interval -> 0 ms/op */
+        assert_eq!(rate_info.query_latency, Some(0.0));
+        /* Counter not reported by the node */
+        assert_eq!(rate_info.fetch_rate, None);
+    }
+
+    #[test]
+    fn first_sample_has_no_rates() {
+        let rate_info: NodeRateInfo = sample(110, 1_010, 1_500).get_rate_info(None);
+
+        assert_eq!(rate_info.interval_sec, None);
+        assert_eq!(rate_info.indexing_rate, None);
+    }
+
+    #[test]
+    fn counter_reset_after_restart_has_no_rates() {
+        let prev: NodeCounterSample = sample(100, 1_000, 1_000);
+
+        /* Node restarted -> uptime and counters start again from 0 */
+        let restarted: NodeCounterSample = sample(110, 5, 20);
+        let rate_info: NodeRateInfo = restarted.get_rate_info(Some(&prev));
+
+        assert_eq!(rate_info.interval_sec, None);
+        assert_eq!(rate_info.indexing_rate, None);
+
+        /* A single decreased counter is enough, even if the uptime is not reported */
+        let mut counter_reset: NodeCounterSample = sample(110, 1_010, 20);
+        counter_reset.uptime_in_millis = None;
+
+        assert_eq!(counter_reset.get_rate_info(Some(&prev)).indexing_rate, None);
+    }
+
+    #[test]
+    fn out_of_order_sample_has_no_rates() {
+        let prev: NodeCounterSample = sample(110, 1_010, 1_000);
+        let cur: NodeCounterSample = sample(110, 1_010, 1_000);
+
+        assert_eq!(cur.get_rate_info(Some(&prev)).interval_sec, None);
+    }
+}
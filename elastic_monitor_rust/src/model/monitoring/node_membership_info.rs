@@ -0,0 +1,154 @@
+use crate::common::*;
+
+use crate::enums::node_membership_change_type::*;
+
+#[doc = "Member node of the cluster -> identified by its node ID, which is kept across restarts"]
+#[derive(Debug, Clone, Getters, new)]
+#[getset(get = "pub")]
+pub struct NodeMemberInfo {
+    pub node_name: String,
+    pub host: String,
+    pub uptime_ms: Option<u64>,
+}
+
+#[doc = "Change of the cluster membership between two monitoring cycles"]
+#[derive(Debug, Clone, Getters, new)]
+#[getset(get = "pub")]
+pub struct NodeMembershipChange {
+    pub change_type: NodeMembershipChangeType,
+    pub host: String,
+    pub node_name: String,
+    pub detail: String,
+}
+
+#[doc = "Member nodes and elected master of the cluster at a point in time"]
+#[derive(Debug, Clone, Default, Getters)]
+#[getset(get = "pub")]
+pub struct NodeMembershipInfo {
+    /* node ID -> member node */
+    pub nodes: HashMap<String, NodeMemberInfo>,
+    pub master_node_id: Option<String>,
+}
+
+impl NodeMembershipInfo {
+    #[doc = "Function that builds the membership from `GET /_nodes/stats/jvm` and `GET /_cat/master?h=id`"]
+    /// # Arguments
+    /// * `node_stats`  - Response of `GET /_nodes/stats/jvm`
+    /// * `cat_master`  - Response of `GET /_cat/master?h=id`
+    ///
+    /// # Returns
+    /// * NodeMembershipInfo
+    pub fn from_node_stats(node_stats: &Value, cat_master: &str) -> Self {
+        let nodes: HashMap<String, NodeMemberInfo> = node_stats["nodes"]
+            .as_object()
+            .into_iter()
+            .flatten()
+            .map(|(node_id, node)| {
+                (
+                    node_id.clone(),
+                    NodeMemberInfo::new(
+                        node["name"].as_str().unwrap_or(node_id).to_string(),
+                        node["host"].as_str().unwrap_or("").to_string(),
+                        node["jvm"]["uptime_in_millis"].as_u64(),
+                    ),
+                )
+            })
+            .collect();
+
+        let master_node_id: Option<String> = cat_master
+            .split_whitespace()
+            .next()
+            .map(|master_node_id| master_node_id.to_string());
+
+        NodeMembershipInfo {
+            nodes,
+            master_node_id,
+        }
+    }
+
+    #[doc = "Function that returns the name of a node, or its ID if the node is not a member"]
+    fn get_node_name(&self, node_id: &str) -> String {
+        self.nodes
+            .get(node_id)
+            .map(|node| node.node_name.clone())
+            .unwrap_or_else(|| node_id.to_string())
+    }
+
+    #[doc = "Function that compares the membership with the previous cycle.
+             A node whose JVM uptime went backwards has been restarted -> caught even if it came back within a cycle."]
+    /// # Arguments
+    /// * `prev` - Membership of the previous cycle
+    ///
+    /// # Returns
+    /// * Vec<NodeMembershipChange>
+    pub fn get_changes(&self, prev: &NodeMembershipInfo) -> Vec<NodeMembershipChange> {
+        let mut changes: Vec<NodeMembershipChange> = Vec::new();
+
+        for (node_id, node) in self.nodes.iter() {
+            match prev.nodes.get(node_id) {
+                None => changes.push(NodeMembershipChange::new(
+                    NodeMembershipChangeType::Joined,
+                    node.host.clone(),
+                    node.node_name.clone(),
+                    format!("{} ({}) joined the cluster", node.node_name, node_id),
+                )),
+                Some(prev_node) => {
+                    if let (Some(uptime_ms), Some(prev_uptime_ms)) =
+                        (node.uptime_ms, prev_node.uptime_ms)
+                    {
+                        if uptime_ms < prev_uptime_ms {
+                            changes.push(NodeMembershipChange::new(
+                                NodeMembershipChangeType::Restarted,
+                                node.host.clone(),
+                                node.node_name.clone(),
+                                format!(
+                                    "{} ({}) restarted (uptime: {:.0} sec)",
+                                    node.node_name,
+                                    node_id,
+                                    uptime_ms as f64 / 1000.0
+                                ),
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+
+        for (node_id, prev_node) in prev.nodes.iter() {
+            if !self.nodes.contains_key(node_id) {
+                changes.push(NodeMembershipChange::new(
+                    NodeMembershipChangeType::Left,
+                    prev_node.host.clone(),
+                    prev_node.node_name.clone(),
+                    format!("{} ({}) left the cluster", prev_node.node_name, node_id),
+                ));
+            }
+        }
+
+        /* No master is reported while an election is in progress -> compared once a master is elected */
+        if let (Some(master_node_id), Some(prev_master_node_id)) =
+            (&self.master_node_id, &prev.master_node_id)
+        {
+            if master_node_id != prev_master_node_id {
+                let node_name: String = self.get_node_name(master_node_id);
+
+                changes.push(NodeMembershipChange::new(
+                    NodeMembershipChangeType::MasterChanged,
+                    self.nodes
+                        .get(master_node_id)
+                        .map(|node| node.host.clone())
+                        .unwrap_or_default(),
+                    node_name.clone(),
+                    format!(
+                        "Elected master changed: {} -> {}",
+                        prev.get_node_name(prev_master_node_id),
+                        node_name
+                    ),
+                ));
+            }
+        }
+
+        changes.sort_by(|a, b| a.detail.cmp(&b.detail));
+        changes
+    }
+}
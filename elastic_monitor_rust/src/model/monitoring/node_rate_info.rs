@@ -0,0 +1,28 @@
+use crate::common::*;
+
+#[doc = "Rates and latencies of a node during the last monitoring interval.
+         Every value is None on the first sample of a node and right after the node has been restarted."]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Getters)]
+#[getset(get = "pub")]
+pub struct NodeRateInfo {
+    pub interval_sec: Option<f64>,
+    pub indexing_rate: Option<f64>,    /* ops/s */
+    pub indexing_latency: Option<f64>, /* ms/op */
+    pub query_rate: Option<f64>,
+    pub query_latency: Option<f64>,
+    pub fetch_rate: Option<f64>,
+    pub fetch_latency: Option<f64>,
+    pub flush_rate: Option<f64>,
+    pub refresh_rate: Option<f64>,
+    pub translog_operation_delta: Option<i64>, /* gauge -> can be negative after a flush */
+    pub breaker_request_tripped: Option<u64>,
+    pub breaker_fielddata_tripped: Option<u64>,
+    pub breaker_inflight_requests_tripped: Option<u64>,
+    pub breaker_parent_tripped: Option<u64>,
+    pub gc_young_count_delta: Option<i64>,
+    pub gc_young_time_delta_ms: Option<i64>,
+    pub gc_young_time_ratio: Option<f64>, /* % of the interval spent in young GC */
+    pub gc_old_count_delta: Option<i64>,
+    pub gc_old_time_delta_ms: Option<i64>,
+    pub gc_old_time_ratio: Option<f64>, /* % of the interval spent in old GC */
+}
@@ -0,0 +1,51 @@
+use crate::common::*;
+
+#[doc = "Summary of the pending cluster tasks -> collected from `GET /_cluster/pending_tasks`"]
+#[derive(Debug, Clone, Serialize, Deserialize, Getters, new)]
+#[getset(get = "pub")]
+pub struct PendingTaskInfo {
+    pub timestamp: String,
+    pub cluster_name: String,
+    pub queue_length: u64,
+    pub oldest_time_in_queue_ms: u64,
+    pub oldest_task_source: String,
+    pub oldest_task_priority: String,
+}
+
+impl PendingTaskInfo {
+    #[doc = "Function that summarizes the response of `GET /_cluster/pending_tasks`"]
+    /// # Arguments
+    /// * `pending_tasks`   - Response of `GET /_cluster/pending_tasks`
+    /// * `cluster_name`    - Name of the cluster
+    /// * `timestamp`       - Collection time (string)
+    ///
+    /// # Returns
+    /// * PendingTaskInfo
+    pub fn from_pending_tasks(pending_tasks: &Value, cluster_name: &str, timestamp: &str) -> Self {
+        let tasks: &[Value] = pending_tasks["tasks"]
+            .as_array()
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+
+        let oldest_task: Option<&Value> = tasks
+            .iter()
+            .max_by_key(|task| task["time_in_queue_millis"].as_u64().unwrap_or(0));
+
+        PendingTaskInfo::new(
+            timestamp.to_string(),
+            cluster_name.to_string(),
+            tasks.len() as u64,
+            oldest_task
+                .and_then(|task| task["time_in_queue_millis"].as_u64())
+                .unwrap_or(0),
+            oldest_task
+                .and_then(|task| task["source"].as_str())
+                .unwrap_or("")
+                .to_string(),
+            oldest_task
+                .and_then(|task| task["priority"].as_str())
+                .unwrap_or("")
+                .to_string(),
+        )
+    }
+}
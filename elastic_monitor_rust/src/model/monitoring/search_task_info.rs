@@ -0,0 +1,73 @@
+use crate::common::*;
+
+#[doc = "Search task of the task management API -> collected from `GET /_tasks?actions=*search*&detailed`"]
+#[derive(Debug, Clone, Serialize, Deserialize, Getters)]
+#[getset(get = "pub")]
+pub struct SearchTaskInfo {
+    pub task_id: String,
+    pub node_name: String,
+    pub host: String,
+    pub action: String,
+    pub description: String,
+    pub running_time_ms: u64,
+    pub cancellable: bool,
+}
+
+impl SearchTaskInfo {
+    #[doc = "Function that extracts the search tasks running longer than the limit.
+             Only parent tasks are kept -> the shard-level child tasks are cancelled together with their parent."]
+    /// # Arguments
+    /// * `tasks`               - Response of `GET /_tasks?actions=*search*&detailed`
+    /// * `min_running_time_ms` - Tasks running shorter than this are ignored
+    ///
+    /// # Returns
+    /// * Vec<SearchTaskInfo>
+    pub fn from_tasks_response(tasks: &Value, min_running_time_ms: u64) -> Vec<Self> {
+        let mut search_tasks: Vec<SearchTaskInfo> = Vec::new();
+
+        for (node_id, node) in tasks["nodes"].as_object().into_iter().flatten() {
+            let node_name: &str = node["name"].as_str().unwrap_or(node_id);
+            let host: &str = node["host"].as_str().unwrap_or("");
+
+            for (task_id, task) in node["tasks"].as_object().into_iter().flatten() {
+                if task.get("parent_task_id").is_some() {
+                    continue;
+                }
+
+                let running_time_ms: u64 =
+                    task["running_time_in_nanos"].as_u64().unwrap_or(0) / 1_000_000;
+
+                if running_time_ms < min_running_time_ms {
+                    continue;
+                }
+
+                search_tasks.push(SearchTaskInfo {
+                    task_id: task_id.to_string(),
+                    node_name: node_name.to_string(),
+                    host: host.to_string(),
+                    action: task["action"].as_str().unwrap_or("").to_string(),
+                    description: task["description"].as_str().unwrap_or("").to_string(),
+                    running_time_ms,
+                    cancellable: task["cancellable"].as_bool().unwrap_or(false),
+                });
+            }
+        }
+
+        /* The longest running task first */
+        search_tasks.sort_by_key(|search_task| std::cmp::Reverse(search_task.running_time_ms));
+        search_tasks
+    }
+
+    #[doc = "Function that returns the beginning of the task description (query) -> used in the alert message"]
+    /// # Arguments
+    /// * `max_chars` - Maximum number of characters of the excerpt
+    ///
+    /// # Returns
+    /// * String
+    pub fn get_description_excerpt(&self, max_chars: usize) -> String {
+        match self.description.char_indices().nth(max_chars) {
+            Some((byte_idx, _)) => format!("{}...", &self.description[..byte_idx]),
+            None => self.description.clone(),
+        }
+    }
+}
@@ -0,0 +1,181 @@
+use crate::common::*;
+
+#[doc = "Shards of an index held by a data node"]
+#[derive(Debug, Clone, Serialize, Deserialize, Getters, new)]
+#[getset(get = "pub")]
+pub struct IndexShardUsage {
+    pub index_name: String,
+    pub shard_cnt: u64,
+    pub store_size_byte: u64,
+}
+
+#[doc = "Shard balance of a data node -> deviation of its shard count and store size
+         from the mean of the data nodes having the same data roles (tier)"]
+#[derive(Debug, Clone, Serialize, Deserialize, Getters, new)]
+#[getset(get = "pub")]
+pub struct ShardBalanceInfo {
+    pub host: String,
+    pub node_name: String,
+    pub data_roles: String,
+    #[new(default)]
+    pub shard_cnt: u64,
+    #[new(default)]
+    pub store_size_byte: u64,
+    #[new(default)]
+    pub mean_shard_cnt: f64,
+    #[new(default)]
+    pub mean_store_size_byte: f64,
+    #[new(default)]
+    pub shard_cnt_skew_percent: f64,
+    #[new(default)]
+    pub store_size_skew_percent: f64,
+    #[new(default)]
+    pub heaviest_indices: Vec<IndexShardUsage>, /* largest store size first */
+}
+
+impl ShardBalanceInfo {
+    #[doc = "Function that returns the data roles of a node as a sorted, comma separated string.
+             Returns None for nodes holding no data (master-only, coordinating-only, ml ...)."]
+    /// # Arguments
+    /// * `roles` - `roles` of the node in `GET /_nodes/stats`
+    ///
+    /// # Returns
+    /// * Option<String>
+    fn get_data_roles(roles: &Value) -> Option<String> {
+        let mut data_roles: Vec<&str> = roles
+            .as_array()?
+            .iter()
+            .filter_map(|role| role.as_str())
+            .filter(|role| *role == "data" || role.starts_with("data_"))
+            .collect();
+
+        if data_roles.is_empty() {
+            return None;
+        }
+
+        data_roles.sort();
+        Some(data_roles.join(","))
+    }
+
+    #[doc = "Function that computes the shard balance of every data node.
+             The mean is taken among the nodes with the same data roles, so that hot/warm/cold tiers are not compared with each other."]
+    /// # Arguments
+    /// * `node_stats`      - Response of `GET /_nodes/stats` (only `host`, `name` and `roles` are used)
+    /// * `cat_shards`      - Response of `GET /_cat/shards?h=index,state,store,node&bytes=b`
+    /// * `top_indices`     - Number of the heaviest indices kept per node
+    ///
+    /// # Returns
+    /// * Vec<ShardBalanceInfo>
+    pub fn from_node_shards(node_stats: &Value, cat_shards: &str, top_indices: usize) -> Vec<Self> {
+        let mut balance_map: HashMap<String, ShardBalanceInfo> = HashMap::new();
+
+        for (node_id, node) in node_stats["nodes"].as_object().into_iter().flatten() {
+            let data_roles: String = match Self::get_data_roles(&node["roles"]) {
+                Some(data_roles) => data_roles,
+                None => continue,
+            };
+
+            let node_name: String = node["name"].as_str().unwrap_or(node_id).to_string();
+
+            balance_map.insert(
+                node_name.clone(),
+                ShardBalanceInfo::new(
+                    node["host"].as_str().unwrap_or("").to_string(),
+                    node_name,
+                    data_roles,
+                ),
+            );
+        }
+
+        /* node name -> index name -> (shard count, store size) */
+        let mut index_map: HashMap<String, HashMap<String, (u64, u64)>> = HashMap::new();
+
+        for line in cat_shards.lines() {
+            let parts: Vec<&str> = line.split_whitespace().collect();
+
+            /* A relocating shard is counted on its source node until the relocation completes */
+            if parts.len() < 4 || (parts[1] != "STARTED" && parts[1] != "RELOCATING") {
+                continue;
+            }
+
+            let balance_info: &mut ShardBalanceInfo = match balance_map.get_mut(parts[3]) {
+                Some(balance_info) => balance_info,
+                None => continue,
+            };
+
+            let store_size_byte: u64 = parts[2].parse().unwrap_or(0);
+
+            balance_info.shard_cnt += 1;
+            balance_info.store_size_byte += store_size_byte;
+
+            let index_usage: &mut (u64, u64) = index_map
+                .entry(parts[3].to_string())
+                .or_default()
+                .entry(parts[0].to_string())
+                .or_default();
+            index_usage.0 += 1;
+            index_usage.1 += store_size_byte;
+        }
+
+        let mut role_groups: HashMap<String, (u64, u64, u64)> = HashMap::new();
+
+        for balance_info in balance_map.values() {
+            let group: &mut (u64, u64, u64) = role_groups
+                .entry(balance_info.data_roles.clone())
+                .or_default();
+            group.0 += 1;
+            group.1 += balance_info.shard_cnt;
+            group.2 += balance_info.store_size_byte;
+        }
+
+        let mut balance_infos: Vec<ShardBalanceInfo> = balance_map.into_values().collect();
+
+        for balance_info in balance_infos.iter_mut() {
+            let (node_cnt, total_shard_cnt, total_store_size_byte) =
+                role_groups[&balance_info.data_roles];
+
+            balance_info.mean_shard_cnt = total_shard_cnt as f64 / node_cnt as f64;
+            balance_info.mean_store_size_byte = total_store_size_byte as f64 / node_cnt as f64;
+
+            if balance_info.mean_shard_cnt > 0.0 {
+                balance_info.shard_cnt_skew_percent = (balance_info.shard_cnt as f64
+                    - balance_info.mean_shard_cnt)
+                    / balance_info.mean_shard_cnt
+                    * 100.0;
+            }
+
+            if balance_info.mean_store_size_byte > 0.0 {
+                balance_info.store_size_skew_percent = (balance_info.store_size_byte as f64
+                    - balance_info.mean_store_size_byte)
+                    / balance_info.mean_store_size_byte
+                    * 100.0;
+            }
+
+            let mut heaviest_indices: Vec<IndexShardUsage> = index_map
+                .remove(&balance_info.node_name)
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(index_name, (shard_cnt, store_size_byte))| {
+                    IndexShardUsage::new(index_name, shard_cnt, store_size_byte)
+                })
+                .collect();
+
+            heaviest_indices.sort_by(|a, b| {
+                b.store_size_byte
+                    .cmp(&a.store_size_byte)
+                    .then_with(|| b.shard_cnt.cmp(&a.shard_cnt))
+            });
+            heaviest_indices.truncate(top_indices);
+
+            balance_info.heaviest_indices = heaviest_indices;
+        }
+
+        balance_infos.sort_by(|a, b| {
+            a.data_roles
+                .cmp(&b.data_roles)
+                .then_with(|| a.node_name.cmp(&b.node_name))
+        });
+
+        balance_infos
+    }
+}
@@ -0,0 +1,53 @@
+use crate::common::*;
+
+#[doc = "Stats of a thread pool of a node -> collected from `GET /_cat/thread_pool`"]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Getters, new)]
+#[getset(get = "pub")]
+pub struct ThreadPoolInfo {
+    pub active: u32,
+    pub queue: u32,
+    pub queue_size: i64, /* -1 -> unbounded queue */
+    pub largest: u32,
+    pub completed: u64,
+    pub rejected: u64,
+    /* Rejections per second during the last interval -> None on the first sample and right after a restart */
+    #[new(default)]
+    pub rejected_per_sec: Option<f64>,
+}
+
+#[doc = "Rejected counters of the thread pools of a node at a point in time -> kept per node name to compute the rejection rate"]
+#[derive(Debug, Clone, new)]
+pub struct ThreadPoolSample {
+    pub sampled_at_millis: i64,
+    pub rejected: HashMap<String, u64>,
+}
+
+impl ThreadPoolSample {
+    #[doc = "Function that computes the rejections per second of a thread pool since the previous sample"]
+    /// # Arguments
+    /// * `prev`        - Previous sample of the same node (None on the first sample)
+    /// * `pool_name`   - Name of the thread pool
+    ///
+    /// # Returns
+    /// * Option<f64>
+    pub fn get_rejected_per_sec(
+        &self,
+        prev: Option<&ThreadPoolSample>,
+        pool_name: &str,
+    ) -> Option<f64> {
+        let prev: &ThreadPoolSample = prev?;
+        let interval_millis: i64 = self.sampled_at_millis - prev.sampled_at_millis;
+
+        if interval_millis <= 0 {
+            return None;
+        }
+
+        /* The counter is reset when the node restarts */
+        let rejected_delta: u64 = self
+            .rejected
+            .get(pool_name)?
+            .checked_sub(*prev.rejected.get(pool_name)?)?;
+
+        Some(rejected_delta as f64 / (interval_millis as f64 / 1000.0))
+    }
+}
@@ -0,0 +1,248 @@
+use crate::common::*;
+
+use crate::model::cluster_dto::disk_forecast_config::*;
+
+#[doc = "Disk-full forecast of a host -> linear trend of `disk_usage` and the expected time of each watermark"]
+#[derive(Debug, Clone, Getters)]
+#[getset(get = "pub")]
+pub struct DiskForecastInfo {
+    pub host: String,
+    pub disk_usage: f64,
+    pub growth_per_day: f64,
+    pub high_watermark_at: Option<DateTime<Utc>>,
+    pub flood_stage_at: Option<DateTime<Utc>>,
+}
+
+impl DiskForecastInfo {
+    #[doc = "Function that fits a least-squares line to the disk usage samples of a host.
+             Returns None if there are not enough samples to draw a trend."]
+    /// # Arguments
+    /// * `host` - Host of the node
+    /// * `samples` - (epoch millis, disk usage %) sorted by time
+    /// * `config` - Disk forecast rule of the cluster
+    /// * `now` - Current time
+    ///
+    /// # Returns
+    /// * Option<DiskForecastInfo>
+    pub fn from_samples(
+        host: &str,
+        samples: &[(i64, f64)],
+        config: &DiskForecastConfig,
+        now: DateTime<Utc>,
+    ) -> Option<Self> {
+        if samples.len() < config.min_samples.max(2) {
+            return None;
+        }
+
+        /* x: days since the first sample, y: disk usage (%) */
+        let first_millis: i64 = samples[0].0;
+        let points: Vec<(f64, f64)> = samples
+            .iter()
+            .map(|(millis, usage)| ((millis - first_millis) as f64 / 86_400_000.0, *usage))
+            .collect();
+
+        let count: f64 = points.len() as f64;
+        let mean_x: f64 = points.iter().map(|(x, _)| x).sum::<f64>() / count;
+        let mean_y: f64 = points.iter().map(|(_, y)| y).sum::<f64>() / count;
+
+        let covariance: f64 = points
+            .iter()
+            .map(|(x, y)| (x - mean_x) * (y - mean_y))
+            .sum();
+        let variance: f64 = points.iter().map(|(x, _)| (x - mean_x).powi(2)).sum();
+
+        if variance == 0.0 {
+            return None;
+        }
+
+        let growth_per_day: f64 = covariance / variance;
+        let disk_usage: f64 = samples[samples.len() - 1].1;
+
+        let reached_at = |watermark: f64| -> Option<DateTime<Utc>> {
+            if disk_usage >= watermark {
+                return Some(now);
+            }
+
+            if growth_per_day <= 0.0 {
+                return None;
+            }
+
+            let days_left: f64 = (watermark - disk_usage) / growth_per_day;
+            now.checked_add_signed(ChronoDuration::seconds((days_left * 86_400.0) as i64))
+        };
+
+        Some(DiskForecastInfo {
+            host: host.to_string(),
+            disk_usage,
+            growth_per_day,
+            high_watermark_at: reached_at(config.high_watermark_percent),
+            flood_stage_at: reached_at(config.flood_stage_watermark_percent),
+        })
+    }
+
+    #[doc = "Function that returns the watermarks expected to be reached before the horizon -> (metric name, detail)"]
+    /// # Arguments
+    /// * `config` - Disk forecast rule of the cluster
+    /// * `now` - Current time
+    ///
+    /// # Returns
+    /// * Vec<(String, String)>
+    pub fn get_violations(
+        &self,
+        config: &DiskForecastConfig,
+        now: DateTime<Utc>,
+    ) -> Vec<(String, String)> {
+        let horizon: DateTime<Utc> = now + ChronoDuration::days(config.horizon_days as i64);
+
+        [
+            (
+                "disk_high_watermark",
+                config.high_watermark_percent,
+                self.high_watermark_at,
+            ),
+            (
+                "disk_flood_stage_watermark",
+                config.flood_stage_watermark_percent,
+                self.flood_stage_at,
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(metric_name, watermark, reached_at)| {
+            let reached_at: DateTime<Utc> = reached_at.filter(|at| *at <= horizon)?;
+
+            Some((
+                metric_name.to_string(),
+                format!(
+                    "{}: disk {:.1}% (+{:.2}%/day) -> {}% expected at {}",
+                    self.host,
+                    self.disk_usage,
+                    self.growth_per_day,
+                    watermark,
+                    reached_at.with_timezone(&Local).format("%Y-%m-%d %H:%M")
+                ),
+            ))
+        })
+        .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY_MILLIS: i64 = 86_400_000;
+
+    fn now() -> DateTime<Utc> {
+        Utc.timestamp_opt(1_700_000_000, 0).unwrap()
+    }
+
+    fn config() -> DiskForecastConfig {
+        DiskForecastConfig {
+            min_samples: 3,
+            ..DiskForecastConfig::default()
+        }
+    }
+
+    /* One sample per day, the last one at `now` */
+    fn daily_samples(usages: &[f64]) -> Vec<(i64, f64)> {
+        let last_millis: i64 = now().timestamp_millis();
+        let first_millis: i64 = last_millis - (usages.len() as i64 - 1) * DAY_MILLIS;
+
+        usages
+            .iter()
+            .enumerate()
+            .map(|(idx, usage)| (first_millis + idx as i64 * DAY_MILLIS, *usage))
+            .collect()
+    }
+
+    #[test]
+    fn linear_growth_reaches_the_watermarks() {
+        let forecast: DiskForecastInfo = DiskForecastInfo::from_samples(
+            "host1",
+            &daily_samples(&[80.0, 82.0, 84.0]),
+            &config(),
+            now(),
+        )
+        .unwrap();
+
+        assert!((forecast.growth_per_day - 2.0).abs() < 1e-9);
+        assert_eq!(forecast.disk_usage, 84.0);
+        assert_eq!(
+            forecast.high_watermark_at,
+            Some(now() + ChronoDuration::days(3))
+        );
+        assert_eq!(
+            forecast.flood_stage_at,
+            Some(now() + ChronoDuration::seconds(475_200))
+        );
+    }
+
+    #[test]
+    fn watermark_already_reached_is_now() {
+        let forecast: DiskForecastInfo = DiskForecastInfo::from_samples(
+            "host1",
+            &daily_samples(&[89.0, 90.0, 91.0]),
+            &config(),
+            now(),
+        )
+        .unwrap();
+
+        assert_eq!(forecast.high_watermark_at, Some(now()));
+    }
+
+    #[test]
+    fn flat_or_shrinking_usage_never_reaches_the_watermarks() {
+        let forecast: DiskForecastInfo = DiskForecastInfo::from_samples(
+            "host1",
+            &daily_samples(&[70.0, 65.0, 60.0]),
+            &config(),
+            now(),
+        )
+        .unwrap();
+
+        assert!(forecast.growth_per_day < 0.0);
+        assert_eq!(forecast.high_watermark_at, None);
+        assert_eq!(forecast.flood_stage_at, None);
+    }
+
+    #[test]
+    fn not_enough_samples_has_no_forecast() {
+        assert!(DiskForecastInfo::from_samples(
+            "host1",
+            &daily_samples(&[80.0, 82.0]),
+            &config(),
+            now()
+        )
+        .is_none());
+
+        /* Samples taken at the same time -> no trend */
+        let same_time: Vec<(i64, f64)> = vec![(0, 80.0), (0, 81.0), (0, 82.0)];
+        assert!(DiskForecastInfo::from_samples("host1", &same_time, &config(), now()).is_none());
+    }
+
+    #[test]
+    fn only_watermarks_within_the_horizon_are_violations() {
+        let forecast: DiskForecastInfo = DiskForecastInfo::from_samples(
+            "host1",
+            &daily_samples(&[80.0, 82.0, 84.0]),
+            &config(),
+            now(),
+        )
+        .unwrap();
+
+        /* high: 3 days, flood stage: 5.5 days */
+        let violations: Vec<String> = forecast
+            .get_violations(
+                &DiskForecastConfig {
+                    horizon_days: 4,
+                    ..config()
+                },
+                now(),
+            )
+            .into_iter()
+            .map(|(metric_name, _)| metric_name)
+            .collect();
+
+        assert_eq!(violations, vec![String::from("disk_high_watermark")]);
+    }
+}
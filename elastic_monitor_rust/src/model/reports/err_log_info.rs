@@ -9,4 +9,7 @@ pub struct ErrorLogInfo {
     pub timestamp: String,
     pub err_title: String,
     pub err_detail: String,
+    #[new(default)]
+    #[serde(default)]
+    pub silenced: bool,
 }
@@ -1,3 +1,4 @@
+pub mod disk_forecast_info;
 pub mod err_agg_history_bucket;
 pub mod err_log_info;
 pub mod report_range;
@@ -0,0 +1,262 @@
+use crate::common::*;
+
+use crate::enums::alert_check_type::*;
+
+use crate::model::alert_dto::alert_key::*;
+
+use crate::utils_modules::time_utils::*;
+
+#[doc = "Maintenance window / silence rule.
+         Matchers that are not set match everything.
+         The rule is active either between `start_at` and `end_at` (RFC3339),
+         or for `duration_sec` seconds every time `cron_schedule` fires (local time)."]
+#[derive(Serialize, Deserialize, Debug, Clone, Getters)]
+#[getset(get = "pub")]
+pub struct SilenceRule {
+    #[serde(default)]
+    pub comment: String,
+    pub cluster_name: Option<String>,
+    pub host: Option<String>,
+    pub check_type: Option<AlertCheckType>,
+    pub metric_name: Option<String>,
+    pub start_at: Option<String>,
+    pub end_at: Option<String>,
+    pub cron_schedule: Option<String>,
+    pub duration_sec: Option<u64>,
+}
+
+impl SilenceRule {
+    #[doc = "Function that checks whether the alert key is a target of the rule"]
+    /// # Arguments
+    /// * `key` - Alert key
+    ///
+    /// # Returns
+    /// * bool
+    pub fn is_match(&self, key: &AlertKey) -> bool {
+        let match_field = |matcher: &Option<String>, value: &str| -> bool {
+            matcher.as_ref().is_none_or(|matcher| matcher == value)
+        };
+
+        /* The metric name only exists for urgent metric alerts -> `{node name}/{metric}` for the node metrics. */
+        let metric_matched: bool = match &self.metric_name {
+            Some(metric_name) => match key.check_type() {
+                AlertCheckType::UrgentMetric => key.target() == metric_name,
+                AlertCheckType::EsMetric => key
+                    .target()
+                    .split_once('/')
+                    .is_some_and(|(_, target_metric)| target_metric == metric_name),
+                _ => false,
+            },
+            None => true,
+        };
+
+        match_field(&self.cluster_name, key.cluster_name())
+            && match_field(&self.host, key.host())
+            && self
+                .check_type
+                .is_none_or(|check_type| check_type == *key.check_type())
+            && metric_matched
+    }
+
+    #[doc = "Function that checks whether the rule is active at the given time"]
+    /// # Arguments
+    /// * `now` - Current time
+    ///
+    /// # Returns
+    /// * anyhow::Result<bool>
+    pub fn is_active(&self, now: DateTime<Utc>) -> anyhow::Result<bool> {
+        /* 1. Recurring window -> active if the schedule fired within the last `duration_sec` seconds */
+        if let Some(cron_schedule) = &self.cron_schedule {
+            let schedule: cron::Schedule =
+                cron::Schedule::from_str(cron_schedule).map_err(|e| {
+                    anyhow!(
+                        "[SilenceRule::is_active] Failed to parse cron schedule '{}': {:?}",
+                        cron_schedule,
+                        e
+                    )
+                })?;
+
+            let duration_sec: u64 = self.duration_sec.ok_or_else(|| {
+                anyhow!(
+                    "[SilenceRule::is_active] 'duration_sec' is required for cron schedule '{}'",
+                    cron_schedule
+                )
+            })?;
+
+            let now_local: DateTime<Local> = now.with_timezone(&Local);
+            let window_start: DateTime<Local> =
+                now_local - ChronoDuration::seconds(duration_sec as i64);
+
+            return Ok(schedule
+                .after(&window_start)
+                .next()
+                .is_some_and(|fired_at| fired_at <= now_local));
+        }
+
+        /* 2. Fixed window */
+        if self.start_at.is_none() && self.end_at.is_none() {
+            return Err(anyhow!(
+                "[SilenceRule::is_active] Either 'start_at'/'end_at' or 'cron_schedule' must be set. ({})",
+                self.comment
+            ));
+        }
+
+        let started: bool = match &self.start_at {
+            Some(start_at) => convert_str_to_datetime(start_at, Utc)? <= now,
+            None => true,
+        };
+
+        let not_ended: bool = match &self.end_at {
+            Some(end_at) => now < convert_str_to_datetime(end_at, Utc)?,
+            None => true,
+        };
+
+        Ok(started && not_ended)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, Getters)]
+#[getset(get = "pub")]
+pub struct SilenceRuleList {
+    #[serde(default)]
+    pub silence: Vec<SilenceRule>,
+}
+
+impl SilenceRuleList {
+    #[doc = "Function that checks whether the alert is silenced by one of the active rules.
+             A rule that cannot be evaluated is ignored so that alerts are never lost by a typo."]
+    /// # Arguments
+    /// * `key` - Alert key
+    /// * `now` - Current time
+    ///
+    /// # Returns
+    /// * bool
+    pub fn is_silenced(&self, key: &AlertKey, now: DateTime<Utc>) -> bool {
+        self.silence.iter().any(|rule| {
+            if !rule.is_match(key) {
+                return false;
+            }
+
+            match rule.is_active(now) {
+                Ok(active) => active,
+                Err(e) => {
+                    error!("[SilenceRuleList::is_silenced] {:?}", e);
+                    false
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn silence_rule(
+        start_at: Option<&str>,
+        end_at: Option<&str>,
+        cron_schedule: Option<&str>,
+        duration_sec: Option<u64>,
+    ) -> SilenceRule {
+        SilenceRule {
+            comment: String::from("test"),
+            cluster_name: None,
+            host: None,
+            check_type: None,
+            metric_name: None,
+            start_at: start_at.map(String::from),
+            end_at: end_at.map(String::from),
+            cron_schedule: cron_schedule.map(String::from),
+            duration_sec,
+        }
+    }
+
+    /* The cron schedule is evaluated in local time */
+    fn local(hour: u32, min: u32, sec: u32) -> DateTime<Utc> {
+        Local
+            .with_ymd_and_hms(2026, 3, 10, hour, min, sec)
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    fn cron_window_is_active_for_duration_after_fire() {
+        /* Every day at 02:00 for one hour -> [02:00, 03:00) */
+        let rule: SilenceRule = silence_rule(None, None, Some("0 0 2 * * *"), Some(3600));
+
+        assert!(!rule.is_active(local(1, 59, 59)).unwrap());
+        assert!(rule.is_active(local(2, 0, 0)).unwrap());
+        assert!(rule.is_active(local(2, 30, 0)).unwrap());
+        assert!(rule.is_active(local(2, 59, 59)).unwrap());
+        assert!(!rule.is_active(local(3, 0, 0)).unwrap());
+    }
+
+    #[test]
+    fn cron_window_spans_midnight() {
+        /* Every day at 23:30 for one hour */
+        let rule: SilenceRule = silence_rule(None, None, Some("0 30 23 * * *"), Some(3600));
+
+        assert!(rule.is_active(local(0, 15, 0)).unwrap());
+        assert!(!rule.is_active(local(0, 31, 0)).unwrap());
+    }
+
+    #[test]
+    fn cron_window_requires_duration() {
+        let rule: SilenceRule = silence_rule(None, None, Some("0 0 2 * * *"), None);
+        assert!(rule.is_active(local(2, 0, 0)).is_err());
+    }
+
+    #[test]
+    fn invalid_cron_schedule_is_an_error() {
+        let rule: SilenceRule = silence_rule(None, None, Some("every day"), Some(60));
+        assert!(rule.is_active(local(2, 0, 0)).is_err());
+    }
+
+    #[test]
+    fn fixed_window() {
+        let rule: SilenceRule = silence_rule(
+            Some("2026-03-10T00:00:00Z"),
+            Some("2026-03-10T01:00:00Z"),
+            None,
+            None,
+        );
+        let at = |hour: u32, min: u32| Utc.with_ymd_and_hms(2026, 3, 10, hour, min, 0).unwrap();
+
+        assert!(rule.is_active(at(0, 0)).unwrap());
+        assert!(rule.is_active(at(0, 59)).unwrap());
+        assert!(!rule.is_active(at(1, 0)).unwrap());
+        assert!(silence_rule(None, None, None, None)
+            .is_active(at(0, 0))
+            .is_err());
+    }
+
+    #[test]
+    fn metric_name_matches_urgent_metric_alerts_only() {
+        let mut rule: SilenceRule = silence_rule(None, None, Some("0 0 2 * * *"), Some(60));
+        rule.metric_name = Some(String::from("cpu_usage"));
+
+        let key = |check_type: AlertCheckType| {
+            AlertKey::new(
+                String::from("cluster"),
+                check_type,
+                String::from("10.0.0.1"),
+                String::from("cpu_usage"),
+            )
+        };
+
+        assert!(rule.is_match(&key(AlertCheckType::UrgentMetric)));
+        assert!(!rule.is_match(&key(AlertCheckType::PendingTasks)));
+
+        let es_metric_key = |target: &str| {
+            AlertKey::new(
+                String::from("cluster"),
+                AlertCheckType::EsMetric,
+                String::from("10.0.0.1"),
+                target.to_string(),
+            )
+        };
+
+        assert!(rule.is_match(&es_metric_key("node-1/cpu_usage")));
+        assert!(!rule.is_match(&es_metric_key("node-1/jvm_usage")));
+    }
+}
@@ -0,0 +1,11 @@
+use crate::common::*;
+
+use crate::enums::task_state::*;
+
+#[derive(Debug, Clone, Getters, new)]
+#[getset(get = "pub")]
+pub struct TaskStatus {
+    pub state: TaskState,
+    pub restart_count: u32,
+    pub last_error: Option<String>,
+}
@@ -1,11 +1,42 @@
 use crate::common::*;
 
-#[derive(Serialize, Deserialize, Debug, Getters, new)]
+#[derive(Serialize, Deserialize, Debug, Getters)]
 #[getset(get = "pub")]
 pub struct ThreadPoolStat {
     node_name: String,
     name: String,
     active: u32,
     queue: u32,
-    rejected: u32,
+    rejected: u64,
+    queue_size: i64,
+    largest: u32,
+    completed: u64,
+}
+
+impl ThreadPoolStat {
+    #[doc = "Function that parses a line of
+             `GET /_cat/thread_pool?h=node_name,name,active,queue,rejected,queue_size,largest,completed`"]
+    /// # Arguments
+    /// * `line` - A line of `_cat/thread_pool`
+    ///
+    /// # Returns
+    /// * Option<ThreadPoolStat>
+    pub fn from_cat_thread_pool_line(line: &str) -> Option<Self> {
+        let parts: Vec<&str> = line.split_whitespace().collect();
+
+        if parts.len() < 8 {
+            return None;
+        }
+
+        Some(ThreadPoolStat {
+            node_name: parts[0].to_string(),
+            name: parts[1].to_string(),
+            active: parts[2].parse().unwrap_or(0),
+            queue: parts[3].parse().unwrap_or(0),
+            rejected: parts[4].parse().unwrap_or(0),
+            queue_size: parts[5].parse().unwrap_or(-1), /* `-1` or empty -> unbounded */
+            largest: parts[6].parse().unwrap_or(0),
+            completed: parts[7].parse().unwrap_or(0),
+        })
+    }
 }
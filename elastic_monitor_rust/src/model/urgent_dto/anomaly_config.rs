@@ -0,0 +1,92 @@
+use crate::common::*;
+
+use crate::enums::{anomaly_direction::*, anomaly_source::*, urgent_severity::*};
+
+#[doc = "Anomaly mode of the urgent rules -> every rule keeps a rolling baseline (EWMA mean/stddev) per target and metric,
+         and a sample further than `sigma` standard deviations from it is flagged as an anomaly."]
+#[derive(Serialize, Deserialize, Debug, Clone, Getters)]
+#[getset(get = "pub")]
+#[serde(default)]
+pub struct AnomalyConfig {
+    /* Weight of the newest sample in the baseline -> the smaller, the slower the baseline follows the metric */
+    pub alpha: f64,
+    pub sigma: f64,
+    /* Samples required in the baseline before anomalies are flagged */
+    pub min_samples: u64,
+    /* History of the monitoring cluster used to build a missing baseline -> 0 to build it from live samples only */
+    pub bootstrap_hours: u64,
+    pub severity: UrgentSeverity,
+    pub rules: Vec<AnomalyRule>,
+}
+
+impl Default for AnomalyConfig {
+    fn default() -> Self {
+        AnomalyConfig {
+            alpha: 0.05,
+            sigma: 3.0,
+            min_samples: 30,
+            bootstrap_hours: 24,
+            severity: UrgentSeverity::Warning,
+            rules: Vec::new(),
+        }
+    }
+}
+
+#[doc = "Anomaly rule -> unset `sigma` and `severity` fall back to the values of `AnomalyConfig`"]
+#[derive(Serialize, Deserialize, Debug, Clone, Getters)]
+#[getset(get = "pub")]
+pub struct AnomalyRule {
+    pub source: AnomalySource,
+    pub metric_name: String,
+    #[serde(default)]
+    pub direction: AnomalyDirection,
+    pub sigma: Option<f64>,
+    pub severity: Option<UrgentSeverity>,
+}
+
+impl AnomalyConfig {
+    #[doc = "Function that returns the rules evaluated on the metrics of a source"]
+    pub fn get_rules(&self, source: AnomalySource) -> Vec<&AnomalyRule> {
+        self.rules
+            .iter()
+            .filter(|rule| rule.source == source)
+            .collect()
+    }
+
+    #[doc = "Function that validates the anomaly mode -> `alpha` must be in (0, 1] and every `sigma` positive"]
+    pub fn validate(&self) -> anyhow::Result<()> {
+        if !(self.alpha > 0.0 && self.alpha <= 1.0) {
+            return Err(anyhow!(
+                "[AnomalyConfig::validate] 'alpha' must be in (0, 1], but is {}",
+                self.alpha
+            ));
+        }
+
+        let sigmas = std::iter::once(("anomaly", self.sigma)).chain(
+            self.rules
+                .iter()
+                .filter_map(|rule| rule.sigma.map(|sigma| (rule.metric_name.as_str(), sigma))),
+        );
+
+        for (name, sigma) in sigmas {
+            if sigma <= 0.0 {
+                return Err(anyhow!(
+                    "[AnomalyConfig::validate] 'sigma' of '{}' must be greater than 0",
+                    name
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl AnomalyRule {
+    pub fn get_sigma(&self, anomaly_config: &AnomalyConfig) -> f64 {
+        self.sigma.unwrap_or(anomaly_config.sigma)
+    }
+
+    pub fn get_severity(&self, anomaly_config: &AnomalyConfig) -> UrgentSeverity {
+        self.severity.unwrap_or(anomaly_config.severity)
+    }
+}
@@ -1,2 +1,3 @@
+pub mod anomaly_config;
 pub mod urgent_config;
 pub mod urgent_info;
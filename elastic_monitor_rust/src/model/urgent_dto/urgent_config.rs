@@ -1,14 +1,432 @@
 use crate::common::*;
 
-#[derive(Serialize, Deserialize, Debug, Getters)]
+use crate::enums::{comparison_operator::*, urgent_severity::*};
+
+use crate::model::{
+    message_formatter_dto::message_formatter_urgent::*, urgent_dto::anomaly_config::*,
+};
+
+use crate::utils_modules::json_utils::*;
+
+#[doc = "Urgent rule -> `metric_name <operator> limit` must hold for the last `consecutive_samples` samples
+         and, if `duration_sec` is set, continuously for at least `duration_sec` seconds."]
+#[derive(Serialize, Deserialize, Debug, Clone, Getters)]
 #[getset(get = "pub")]
 pub struct UrgentConfig {
     pub metric_name: String,
+    #[serde(default)]
+    pub operator: ComparisonOperator,
+    pub limit: f64,
+    #[serde(default = "default_consecutive_samples")]
+    pub consecutive_samples: usize,
+    #[serde(default)]
+    pub duration_sec: u64,
+    #[serde(default)]
+    pub severity: UrgentSeverity,
+    #[serde(default)]
+    pub overrides: Vec<UrgentRuleOverride>,
+}
+
+fn default_consecutive_samples() -> usize {
+    1
+}
+
+#[doc = "Override of an urgent rule for a cluster and/or a host.
+         Unset fields keep the value of the rule; a host override wins over a cluster override."]
+#[derive(Serialize, Deserialize, Debug, Clone, Getters)]
+#[getset(get = "pub")]
+pub struct UrgentRuleOverride {
+    pub cluster_name: Option<String>,
+    pub host: Option<String>,
+    pub enabled: Option<bool>,
+    pub operator: Option<ComparisonOperator>,
+    pub limit: Option<f64>,
+    pub consecutive_samples: Option<usize>,
+    pub duration_sec: Option<u64>,
+    pub severity: Option<UrgentSeverity>,
+}
+
+impl UrgentRuleOverride {
+    fn is_matched(&self, cluster_name: &str, host: &str) -> bool {
+        self.cluster_name
+            .as_deref()
+            .is_none_or(|override_cluster| override_cluster == cluster_name)
+            && self
+                .host
+                .as_deref()
+                .is_none_or(|override_host| override_host == host)
+    }
+
+    #[doc = "Specificity of the override -> cluster + host > host > cluster > none"]
+    fn get_specificity(&self) -> u8 {
+        (self.host.is_some() as u8) * 2 + (self.cluster_name.is_some() as u8)
+    }
+}
+
+#[doc = "Urgent rule resolved for a host (overrides applied)"]
+#[derive(Debug, Clone, Getters)]
+#[getset(get = "pub")]
+pub struct UrgentCondition {
+    pub operator: ComparisonOperator,
     pub limit: f64,
+    pub consecutive_samples: usize,
+    pub duration_sec: u64,
+    pub severity: UrgentSeverity,
+}
+
+impl UrgentConfig {
+    #[doc = "Function that resolves the rule for a host of a cluster. Returns None if an override disables it."]
+    /// # Arguments
+    /// * `cluster_name`    - Cluster being monitored
+    /// * `host`            - Host of the sample
+    ///
+    /// # Returns
+    /// * Option<UrgentCondition>
+    pub fn get_condition(&self, cluster_name: &str, host: &str) -> Option<UrgentCondition> {
+        let mut condition: UrgentCondition = UrgentCondition {
+            operator: self.operator,
+            limit: self.limit,
+            consecutive_samples: self.consecutive_samples,
+            duration_sec: self.duration_sec,
+            severity: self.severity,
+        };
+
+        let mut overrides: Vec<&UrgentRuleOverride> = self
+            .overrides
+            .iter()
+            .filter(|rule_override| rule_override.is_matched(cluster_name, host))
+            .collect();
+
+        /* The most specific override is applied last */
+        overrides.sort_by_key(|rule_override| rule_override.get_specificity());
+
+        let mut enabled: bool = true;
+
+        for rule_override in overrides {
+            enabled = rule_override.enabled.unwrap_or(enabled);
+            condition.operator = rule_override.operator.unwrap_or(condition.operator);
+            condition.limit = rule_override.limit.unwrap_or(condition.limit);
+            condition.consecutive_samples = rule_override
+                .consecutive_samples
+                .unwrap_or(condition.consecutive_samples);
+            condition.duration_sec = rule_override.duration_sec.unwrap_or(condition.duration_sec);
+            condition.severity = rule_override.severity.unwrap_or(condition.severity);
+        }
+
+        enabled.then_some(condition)
+    }
+
+    #[doc = "Function that evaluates the rule on the samples of a host.
+             The metric is resolved by field name or dotted path, so any numeric field of the sample can be used."]
+    /// # Arguments
+    /// * `cluster_name`    - Cluster being monitored
+    /// * `host`            - Host of the samples
+    /// * `samples`         - (timestamp, sample) of the host, oldest first
+    ///
+    /// # Returns
+    /// * Option<UrgentAlarmInfo>
+    pub fn evaluate(
+        &self,
+        cluster_name: &str,
+        host: &str,
+        samples: &[(DateTime<Utc>, &Value)],
+    ) -> Option<UrgentAlarmInfo> {
+        let condition: UrgentCondition = self.get_condition(cluster_name, host)?;
+
+        let values: Vec<(DateTime<Utc>, f64)> = samples
+            .iter()
+            .filter_map(|(timestamp, sample)| {
+                get_numeric_value(sample, &self.metric_name).map(|value| (*timestamp, value))
+            })
+            .collect();
+
+        /* Version dependent fields are expected to be missing -> not an error */
+        if values.is_empty() {
+            debug!(
+                "[UrgentConfig::evaluate] Metric '{}' is missing or not numeric in the samples of '{}'",
+                self.metric_name, host
+            );
+            return None;
+        }
+
+        let value: f64 = condition.evaluate(&values)?;
+
+        Some(UrgentAlarmInfo::new(
+            host.to_string(),
+            self.metric_name.clone(),
+            value.to_string(),
+            condition.severity,
+            condition.get_description(),
+        ))
+    }
+
+    #[doc = "Function that returns the longest `duration_sec` of the rule and its overrides"]
+    pub fn get_max_duration_sec(&self) -> u64 {
+        self.overrides
+            .iter()
+            .filter_map(|rule_override| rule_override.duration_sec)
+            .fold(self.duration_sec, u64::max)
+    }
+}
+
+impl UrgentCondition {
+    #[doc = "Function that evaluates the condition on the samples of a host.
+             Returns the latest value if the condition holds for the required samples and duration."]
+    /// # Arguments
+    /// * `samples` - (timestamp, value) of the host, oldest first
+    ///
+    /// # Returns
+    /// * Option<f64>
+    pub fn evaluate(&self, samples: &[(DateTime<Utc>, f64)]) -> Option<f64> {
+        let (latest_at, latest_value) = *samples.last()?;
+
+        /* Samples at the end of the series for which the condition holds */
+        let matched_cnt: usize = samples
+            .iter()
+            .rev()
+            .take_while(|(_, value)| self.operator.matches(*value, self.limit))
+            .count();
+
+        if matched_cnt == 0 || matched_cnt < self.consecutive_samples.max(1) {
+            return None;
+        }
+
+        if self.duration_sec > 0 {
+            let (matched_since, _) = samples[samples.len() - matched_cnt];
+
+            if latest_at - matched_since < ChronoDuration::seconds(self.duration_sec as i64) {
+                return None;
+            }
+        }
+
+        Some(latest_value)
+    }
+
+    #[doc = "Function that returns the condition as text -> e.g. `> 90 for 3 samples / 300 sec`"]
+    pub fn get_description(&self) -> String {
+        let mut description: String = format!("{} {}", self.operator.get_symbol(), self.limit);
+
+        if self.consecutive_samples > 1 || self.duration_sec > 0 {
+            let mut windows: Vec<String> = Vec::new();
+
+            if self.consecutive_samples > 1 {
+                windows.push(format!("{} samples", self.consecutive_samples));
+            }
+
+            if self.duration_sec > 0 {
+                windows.push(format!("{} sec", self.duration_sec));
+            }
+
+            description.push_str(&format!(" for {}", windows.join(" / ")));
+        }
+
+        description
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Getters)]
 #[getset(get = "pub")]
 pub struct UrgentConfigList {
+    /* Time range searched for the latest samples -> must cover `consecutive_samples` of every rule */
+    #[serde(default = "default_lookback_sec")]
+    pub lookback_sec: u64,
+    /* Rules on the documents of the urgent index (system metrics shipped by the collection agent) */
+    #[serde(default)]
     pub urgent: Vec<UrgentConfig>,
+    /* Rules on the Elasticsearch node metrics (`MetricInfo`) collected by this monitor */
+    #[serde(default)]
+    pub es_metric: Vec<UrgentConfig>,
+    /* Baseline anomaly detection, evaluated alongside the threshold rules */
+    #[serde(default)]
+    pub anomaly: AnomalyConfig,
+}
+
+fn default_lookback_sec() -> u64 {
+    60
+}
+
+impl UrgentConfigList {
+    #[doc = "Function that returns the time range (sec) to be searched -> the longest duration plus the lookback"]
+    pub fn get_search_range_sec(&self) -> u64 {
+        self.urgent
+            .iter()
+            .chain(self.es_metric.iter())
+            .map(UrgentConfig::get_max_duration_sec)
+            .max()
+            .unwrap_or(0)
+            + self.lookback_sec
+    }
+
+    #[doc = "Function that validates the urgent rules -> every rule and override needs at least one sample"]
+    pub fn validate(&self) -> anyhow::Result<()> {
+        for urgent_config in self.urgent.iter().chain(self.es_metric.iter()) {
+            let consecutive_samples = std::iter::once(urgent_config.consecutive_samples).chain(
+                urgent_config
+                    .overrides
+                    .iter()
+                    .filter_map(|rule_override| rule_override.consecutive_samples),
+            );
+
+            for consecutive_samples in consecutive_samples {
+                if consecutive_samples == 0 {
+                    return Err(anyhow!(
+                        "[UrgentConfigList::validate] 'consecutive_samples' of '{}' must be greater than 0",
+                        urgent_config.metric_name
+                    ));
+                }
+            }
+        }
+
+        self.anomaly
+            .validate()
+            .map_err(|e| anyhow!("[UrgentConfigList::validate] {:?}", e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn urgent_config(toml_str: &str) -> UrgentConfig {
+        toml::from_str::<UrgentConfig>(toml_str).unwrap()
+    }
+
+    fn condition(consecutive_samples: usize, duration_sec: u64) -> UrgentCondition {
+        UrgentCondition {
+            operator: ComparisonOperator::Gt,
+            limit: 90.0,
+            consecutive_samples,
+            duration_sec,
+            severity: UrgentSeverity::Critical,
+        }
+    }
+
+    /* One sample every 10 seconds */
+    fn samples(values: &[f64]) -> Vec<(DateTime<Utc>, f64)> {
+        values
+            .iter()
+            .enumerate()
+            .map(|(idx, value)| {
+                (
+                    Utc.timestamp_opt(1_700_000_000 + idx as i64 * 10, 0)
+                        .unwrap(),
+                    *value,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn condition_requires_consecutive_samples() {
+        assert_eq!(
+            condition(3, 0).evaluate(&samples(&[95.0, 96.0, 97.0])),
+            Some(97.0)
+        );
+        /* The latest samples must match -> an older match does not count */
+        assert_eq!(
+            condition(3, 0).evaluate(&samples(&[95.0, 50.0, 96.0, 97.0])),
+            None
+        );
+        assert_eq!(condition(1, 0).evaluate(&samples(&[95.0, 50.0])), None);
+        assert_eq!(condition(1, 0).evaluate(&[]), None);
+    }
+
+    #[test]
+    fn condition_requires_duration() {
+        /* Matched for 20 seconds */
+        let values: Vec<(DateTime<Utc>, f64)> = samples(&[50.0, 95.0, 96.0, 97.0]);
+
+        assert_eq!(condition(1, 20).evaluate(&values), Some(97.0));
+        assert_eq!(condition(1, 30).evaluate(&values), None);
+    }
+
+    #[test]
+    fn host_override_wins_over_cluster_override() {
+        let config: UrgentConfig = urgent_config(
+            r#"
+            metric_name = "cpu_usage"
+            limit = 90
+            severity = "warning"
+
+            [[overrides]]
+            host = "10.0.0.1"
+            limit = 70
+
+            [[overrides]]
+            cluster_name = "cluster-a"
+            limit = 80
+            severity = "critical"
+            "#,
+        );
+
+        let host_condition: UrgentCondition =
+            config.get_condition("cluster-a", "10.0.0.1").unwrap();
+        /* The limit of the host override, the severity of the cluster override */
+        assert_eq!(host_condition.limit, 70.0);
+        assert_eq!(host_condition.severity, UrgentSeverity::Critical);
+
+        let cluster_condition: UrgentCondition =
+            config.get_condition("cluster-a", "10.0.0.2").unwrap();
+        assert_eq!(cluster_condition.limit, 80.0);
+
+        let default_condition: UrgentCondition =
+            config.get_condition("cluster-b", "10.0.0.2").unwrap();
+        assert_eq!(default_condition.limit, 90.0);
+        assert_eq!(default_condition.severity, UrgentSeverity::Warning);
+    }
+
+    #[test]
+    fn cluster_and_host_override_is_the_most_specific() {
+        let config: UrgentConfig = urgent_config(
+            r#"
+            metric_name = "cpu_usage"
+            limit = 90
+
+            [[overrides]]
+            cluster_name = "cluster-a"
+            host = "10.0.0.1"
+            enabled = false
+
+            [[overrides]]
+            host = "10.0.0.1"
+            enabled = true
+            limit = 70
+            "#,
+        );
+
+        assert!(config.get_condition("cluster-a", "10.0.0.1").is_none());
+        assert_eq!(
+            config.get_condition("cluster-b", "10.0.0.1").unwrap().limit,
+            70.0
+        );
+    }
+
+    #[test]
+    fn evaluate_reads_dotted_paths() {
+        let config: UrgentConfig = urgent_config(
+            r#"
+            metric_name = "breaker_parent.usage_percent"
+            limit = 85
+            consecutive_samples = 2
+            "#,
+        );
+
+        let sample_a: Value = json!({ "breaker_parent": { "usage_percent": 88.0 } });
+        let sample_b: Value = json!({ "breaker_parent": { "usage_percent": 90.5 } });
+        let now: DateTime<Utc> = Utc.timestamp_opt(1_700_000_000, 0).unwrap();
+
+        let alarm: UrgentAlarmInfo = config
+            .evaluate(
+                "cluster-a",
+                "10.0.0.1",
+                &[
+                    (now, &sample_a),
+                    (now + ChronoDuration::seconds(10), &sample_b),
+                ],
+            )
+            .unwrap();
+
+        assert_eq!(alarm.metric_value_str(), "90.5");
+        assert_eq!(alarm.condition(), "> 85 for 2 samples");
+    }
 }
@@ -1,8 +1,8 @@
 use crate::common::*;
 
-use crate::model::cluster_dto::cluster_config::*;
+use crate::model::cluster_dto::{cluster_config::*, cluster_info::*};
 use crate::model::configs::{config::get_mon_es_config_info, mon_elastic_config::*};
-use crate::model::elastic_dto::elastic_source_parser::*;
+use crate::model::elastic_dto::{elastic_source_parser::*, es_version_info::*};
 
 use crate::utils_modules::io_utils::*;
 
@@ -15,11 +15,16 @@ use crate::traits::repository::es_repository_trait::*;
 pub struct EsRepositoryImpl {
     pub cluster_name: String,
     pub es_client: Elasticsearch,
-    pub hosts: Vec<String>,
+    pub hosts: Vec<String>,              /* Full list of configured hosts */
+    pub disabled_hosts: HashSet<String>, /* Hosts excluded from the connection pool */
     pub index_pattern: Option<String>,
-    pub per_index_pattern: Option<String>, /* deprecated... */
+    pub per_index_pattern: Option<String>,
     pub urgent_index_pattern: Option<String>,
     pub err_log_index_pattern: Option<String>,
+    pub cycle_index_pattern: Option<String>,
+    pub pending_task_index_pattern: Option<String>,
+    pub cluster_metric_index_pattern: Option<String>,
+    pub version_info: Option<EsVersionInfo>, /* Detected from `GET /` -> None until detected */
     pub es_id: Option<String>,
     pub es_pw: Option<String>,
 }
@@ -58,10 +63,15 @@ impl EsRepositoryImpl {
             cluster_name: cluster_name.to_string(),
             es_client,
             hosts,
+            disabled_hosts: HashSet::new(),
             index_pattern: log_index_pattern.map(str::to_string),
             per_index_pattern: per_index_pattern.map(str::to_string),
             urgent_index_pattern: urgent_index_pattern.map(str::to_string),
             err_log_index_pattern: err_log_index_pattern.map(str::to_string),
+            cycle_index_pattern: None,
+            pending_task_index_pattern: None,
+            cluster_metric_index_pattern: None,
+            version_info: None,
             es_id: es_id_opt,
             es_pw: es_pw_opt,
         })
@@ -124,6 +134,50 @@ impl EsRepositoryImpl {
         }
     }
 
+    #[doc = "Function that checks the connection status of the given hosts concurrently."]
+    /// # Arguments
+    /// * `hosts` - Hosts to check
+    ///
+    /// # Returns
+    /// * Vec<(String, bool)> - 각 호스트별 연결 상태
+    async fn check_hosts_connection(&self, hosts: &[String]) -> Vec<(String, bool)> {
+        let mut futures = FuturesUnordered::new();
+
+        let es_id: Option<&str> = self.es_id.as_deref();
+        let es_pw: Option<&str> = self.es_pw.as_deref();
+
+        for host in hosts {
+            let host: String = host.clone();
+
+            let url_result: Result<Url, anyhow::Error> = Self::build_es_url(&host, es_id, es_pw);
+
+            futures.push(async move {
+                match url_result {
+                    Ok(es_cluster_url) => {
+                        let is_connected: bool =
+                            Self::check_single_node_connection(es_cluster_url).await;
+                        (host, is_connected)
+                    }
+                    Err(e) => {
+                        warn!(
+                            "[EsRepositoryImpl::check_hosts_connection] invalid url for host {}: {:?}",
+                            host, e
+                        );
+                        (host, false)
+                    }
+                }
+            });
+        }
+
+        let mut results: Vec<(String, bool)> = Vec::new();
+
+        while let Some(result) = futures.next().await {
+            results.push(result);
+        }
+
+        results
+    }
+
     #[doc = "Function that create elasticsearch url"]
     fn build_es_url(host: &str, es_id: Option<&str>, es_pw: Option<&str>) -> anyhow::Result<Url> {
         let url: String = match (es_id, es_pw) {
@@ -165,6 +219,32 @@ impl EsRepository for EsRepositoryImpl {
         }
     }
 
+    #[doc = "Function that detects the version of the cluster from `GET /` (Elasticsearch or OpenSearch)"]
+    /// # Returns
+    /// * Result<EsVersionInfo, anyhow::Error>
+    async fn detect_cluster_version(&mut self) -> anyhow::Result<EsVersionInfo> {
+        let response: Response = self
+            .es_client
+            .info()
+            .send()
+            .await
+            .map_err(|e| anyhow!("[EsRepositoryImpl::detect_cluster_version] {:?}", e))?;
+
+        if !response.status_code().is_success() {
+            return Err(anyhow!(
+                "[EsRepositoryImpl::detect_cluster_version] Failed to GET /: Status Code: {}",
+                response.status_code()
+            ));
+        }
+
+        let root_info: Value = response.json().await?;
+        let version_info: EsVersionInfo = EsVersionInfo::from_root_info(&root_info)?;
+
+        self.set_version_info(Some(version_info.clone()));
+
+        Ok(version_info)
+    }
+
     #[doc = "Elasticsearch 클러스터의 Health Check 해주는 함수."]
     async fn get_health_info(&self) -> Result<Value, anyhow::Error> {
         let response: Response = self
@@ -186,45 +266,39 @@ impl EsRepository for EsRepositoryImpl {
         }
     }
 
+    #[doc = "GET /_cluster/stats"]
+    async fn get_cluster_stats(&self) -> anyhow::Result<Value> {
+        let response: Response = self
+            .es_client
+            .cluster()
+            .stats(ClusterStatsParts::None)
+            .send()
+            .await?;
+
+        if response.status_code().is_success() {
+            let resp: Value = response.json().await?;
+            Ok(resp)
+        } else {
+            Err(anyhow!(
+                "[EsRepositoryImpl->get_cluster_stats()] Failed to GET cluster stats: Status Code: {}",
+                response.status_code()
+            ))
+        }
+    }
+
     #[doc = "Elasticsearch 각 노드들이 현재 문제 없이 통신이 되는지 체크해주는 함수."]
     /// # Returns
     /// * Vec<(String, bool)> - 각 호스트별 연결 상태
     async fn get_node_conn_check(&self) -> Vec<(String, bool)> {
-        let mut futures = FuturesUnordered::new();
-
-        let es_id: Option<&str> = self.es_id.as_deref();
-        let es_pw: Option<&str> = self.es_pw.as_deref();
-
-        for host in self.hosts() {
-            let host: String = host.clone();
-
-            let url_result: Result<Url, anyhow::Error> = Self::build_es_url(&host, es_id, es_pw);
-
-            futures.push(async move {
-                match url_result {
-                    Ok(es_cluster_url) => {
-                        let is_connected: bool =
-                            Self::check_single_node_connection(es_cluster_url).await;
-                        (host, is_connected)
-                    }
-                    Err(e) => {
-                        warn!(
-                            "[EsRepositoryImpl::get_node_conn_check] invalid url for host {}: {:?}",
-                            host, e
-                        );
-                        (host, false)
-                    }
-                }
-            });
-        }
-
-        let mut results: Vec<(String, bool)> = Vec::new();
-
-        while let Some(result) = futures.next().await {
-            results.push(result);
-        }
+        self.check_hosts_connection(self.hosts()).await
+    }
 
-        results
+    #[doc = "Function that checks whether the hosts excluded from the connection pool respond again."]
+    /// # Returns
+    /// * Vec<(String, bool)> - 제외된 호스트별 연결 상태
+    async fn get_disabled_node_conn_check(&self) -> Vec<(String, bool)> {
+        let disabled_hosts: Vec<String> = self.get_disabled_hosts();
+        self.check_hosts_connection(&disabled_hosts).await
     }
 
     #[doc = "클러스터 각 노드의 metric value 를 반환해주는 함수."]
@@ -298,6 +372,7 @@ impl EsRepository for EsRepositoryImpl {
             .cat()
             .shards(CatShardsParts::None)
             .h(fields)
+            .bytes(Bytes::B)
             .send()
             .await?;
 
@@ -313,12 +388,163 @@ impl EsRepository for EsRepositoryImpl {
         }
     }
 
+    #[doc = "GET /_cluster/allocation/explain -> explains why a shard is unassigned"]
+    /// # Arguments
+    /// * `index_name`  - 인덱스 이름
+    /// * `shard`       - 샤드 번호
+    /// * `primary`     - primary 샤드 여부
+    ///
+    /// # Returns
+    /// * Result<Value, anyhow::Error>
+    async fn get_allocation_explain(
+        &self,
+        index_name: &str,
+        shard: u32,
+        primary: bool,
+    ) -> anyhow::Result<Value> {
+        let response: Response = self
+            .es_client
+            .cluster()
+            .allocation_explain()
+            .body(json!({
+                "index": index_name,
+                "shard": shard,
+                "primary": primary
+            }))
+            .send()
+            .await?;
+
+        if response.status_code().is_success() {
+            let resp: Value = response.json().await?;
+            Ok(resp)
+        } else {
+            Err(anyhow!(
+                "[EsRepositoryImpl->get_allocation_explain()] Failed to explain {}[{}]: Status Code: {}",
+                index_name,
+                shard,
+                response.status_code()
+            ))
+        }
+    }
+
+    #[doc = "GET /_cluster/pending_tasks"]
+    async fn get_pending_tasks(&self) -> anyhow::Result<Value> {
+        let response: Response = self.es_client.cluster().pending_tasks().send().await?;
+
+        if response.status_code().is_success() {
+            let resp: Value = response.json().await?;
+            Ok(resp)
+        } else {
+            Err(anyhow!(
+                "[EsRepositoryImpl->get_pending_tasks()] Failed to GET pending tasks: Status Code: {}",
+                response.status_code()
+            ))
+        }
+    }
+
+    #[doc = "GET /_tasks?actions=*search*&detailed"]
+    async fn get_search_tasks(&self) -> anyhow::Result<Value> {
+        let response: Response = self
+            .es_client
+            .tasks()
+            .list()
+            .actions(&["*search*"])
+            .detailed(true)
+            .send()
+            .await?;
+
+        if response.status_code().is_success() {
+            let resp: Value = response.json().await?;
+            Ok(resp)
+        } else {
+            Err(anyhow!(
+                "[EsRepositoryImpl->get_search_tasks()] Failed to GET search tasks: Status Code: {}",
+                response.status_code()
+            ))
+        }
+    }
+
+    #[doc = "POST /_tasks/{task_id}/_cancel"]
+    /// # Arguments
+    /// * `task_id` - Task ID (`node_id:task_number`)
+    async fn cancel_task(&self, task_id: &str) -> anyhow::Result<()> {
+        let response: Response = self
+            .es_client
+            .tasks()
+            .cancel(TasksCancelParts::TaskId(task_id))
+            .send()
+            .await?;
+
+        if response.status_code().is_success() {
+            Ok(())
+        } else {
+            Err(anyhow!(
+                "[EsRepositoryImpl->cancel_task()] Failed to cancel task '{}': Status Code: {}",
+                task_id,
+                response.status_code()
+            ))
+        }
+    }
+
+    #[doc = "GET /_nodes/{node}/hot_threads"]
+    /// # Arguments
+    /// * `node_name` - Name (or ID) of the node
+    /// * `threads` - Number of hot threads to report
+    ///
+    /// # Returns
+    /// * anyhow::Result<String>
+    async fn get_hot_threads(&self, node_name: &str, threads: u64) -> anyhow::Result<String> {
+        let response: Response = self
+            .es_client
+            .nodes()
+            .hot_threads(NodesHotThreadsParts::NodeId(&[node_name]))
+            .threads(threads as i64)
+            .ignore_idle_threads(true)
+            .send()
+            .await?;
+
+        if response.status_code().is_success() {
+            let body: String = response.text().await?;
+            Ok(body)
+        } else {
+            Err(anyhow!(
+                "[EsRepositoryImpl->get_hot_threads()] Failed to GET hot threads of '{}': Status Code: {}",
+                node_name,
+                response.status_code()
+            ))
+        }
+    }
+
+    #[doc = "GET /_cat/master -> node ID of the elected master"]
+    /// # Returns
+    /// * Result<String, anyhow::Error>
+    async fn get_cat_master(&self) -> Result<String, anyhow::Error> {
+        let response: Response = self.es_client.cat().master().h(&["id"]).send().await?;
+
+        if response.status_code().is_success() {
+            let body: String = response.text().await?;
+            Ok(body)
+        } else {
+            let msg: String = format!(
+                "[EsRepositoryImpl->get_cat_master()] Failed to GET the elected master: Status Code: {}",
+                response.status_code()
+            );
+            Err(anyhow!(msg))
+        }
+    }
+
     #[doc = "GET /_cat/thread_pool"]
-    async fn get_cat_thread_pool(&self) -> Result<String, anyhow::Error> {
+    /// # Arguments
+    /// * `fields` - 조회할 컬럼 목록
+    ///
+    /// # Returns
+    /// * Result<String, anyhow::Error>
+    async fn get_cat_thread_pool(&self, fields: &[&str]) -> Result<String, anyhow::Error> {
         let response: Response = self
             .es_client
             .cat()
             .thread_pool(CatThreadPoolParts::None)
+            .h(fields)
             .send()
             .await?;
 
@@ -360,6 +586,62 @@ impl EsRepository for EsRepositoryImpl {
         }
     }
 
+    #[doc = "특정 인덱스에 여러 문서를 bulk 로 insert 해주는 함수."]
+    /// # Arguments
+    /// * `index_name`  - 인덱스 이름
+    /// * `documents`   - 색인할 문서들
+    ///
+    /// # Returns
+    /// * Result<(), anyhow::Error>
+    async fn post_bulk_docs(&self, index_name: &str, documents: Vec<Value>) -> anyhow::Result<()> {
+        if documents.is_empty() {
+            return Ok(());
+        }
+
+        let mut body: Vec<JsonBody<Value>> = Vec::with_capacity(documents.len() * 2);
+
+        for document in documents {
+            body.push(json!({ "index": {} }).into());
+            body.push(document.into());
+        }
+
+        let response: Response = self
+            .es_client
+            .bulk(BulkParts::Index(index_name))
+            .body(body)
+            .send()
+            .await?;
+
+        if !response.status_code().is_success() {
+            return Err(anyhow!(
+                "[EsRepositoryImpl->post_bulk_docs()] Failed to bulk index documents: Status Code: {}",
+                response.status_code()
+            ));
+        }
+
+        let resp: Value = response.json().await?;
+
+        if resp["errors"].as_bool().unwrap_or(false) {
+            let failed_cnt: usize = resp["items"]
+                .as_array()
+                .map(|items| {
+                    items
+                        .iter()
+                        .filter(|item| item["index"].get("error").is_some())
+                        .count()
+                })
+                .unwrap_or(0);
+
+            return Err(anyhow!(
+                "[EsRepositoryImpl->post_bulk_docs()] {} document(s) failed to be indexed into {}",
+                failed_cnt,
+                index_name
+            ));
+        }
+
+        Ok(())
+    }
+
     #[doc = "특정 인덱스에서 get 쿼리로 데이터를 가져와주는 함수"]
     /// # Arguments
     /// * `es_query`      - Elasticsearch 쿼리
@@ -524,23 +806,73 @@ impl EsRepository for EsRepositoryImpl {
         self.err_log_index_pattern.clone()
     }
 
-    #[doc = "Function that recreates the connection pool when issues arise with the Elasticsearch connection."]
+    #[doc = "Function that returns the index pattern format of monitoring cycle timings."]
+    fn get_cluster_index_cycle_pattern(&self) -> Option<String> {
+        self.cycle_index_pattern.clone()
+    }
+
+    #[doc = "Pending cluster task 정보를 저장할 인덱스 패턴 형식을 반환"]
+    fn get_cluster_index_pending_task_pattern(&self) -> Option<String> {
+        self.pending_task_index_pattern.clone()
+    }
+
+    #[doc = "Function that returns the index pattern format of the cluster-wide statistics."]
+    fn get_cluster_index_cluster_metric_pattern(&self) -> Option<String> {
+        self.cluster_metric_index_pattern.clone()
+    }
+
+    #[doc = "Function that returns the detected version of the cluster - None if not detected yet"]
+    fn get_cluster_version(&self) -> Option<EsVersionInfo> {
+        self.version_info.clone()
+    }
+
+    #[doc = "Cluster 의 connection pool 에서 제외된 호스트들을 반환해주는 함수."]
+    fn get_disabled_hosts(&self) -> Vec<String> {
+        let mut disabled_hosts: Vec<String> = self.disabled_hosts.iter().cloned().collect();
+        disabled_hosts.sort();
+        disabled_hosts
+    }
+
+    #[doc = "Function that recreates the connection pool when issues arise with the Elasticsearch connection.
+             The configured host list is kept, so that disabled hosts can be re-admitted later."]
+    /// # Arguments
+    /// * `disable_node_list` - Hosts to exclude from the connection pool (hosts not in the list are re-admitted)
+    ///
+    /// # Returns
+    /// * anyhow::Result<()>
     fn change_es_conn_pool(&mut self, disable_node_list: Vec<String>) -> anyhow::Result<()> {
-        let disable_set: HashSet<&String> = disable_node_list.iter().collect();
+        let disable_set: HashSet<String> = disable_node_list.into_iter().collect();
 
-        let enabled_hosts: Vec<String> = self
+        let mut enabled_hosts: Vec<String> = self
             .hosts
             .iter()
             .filter(|h| !disable_set.contains(*h))
             .cloned()
             .collect();
 
+        /*
+            If every host is down, an empty pool cannot serve any request.
+            In that case the pool is built with all configured hosts,
+            so that the cluster is reachable again as soon as any node answers.
+        */
+        if enabled_hosts.is_empty() {
+            warn!(
+                "[EsRepositoryImpl::change_es_conn_pool] All hosts of cluster '{}' are unreachable. The connection pool keeps every configured host.",
+                self.cluster_name
+            );
+            enabled_hosts = self.hosts.clone();
+        }
+
         let es_id: Option<&str> = self.es_id.as_deref();
         let es_pw: Option<&str> = self.es_pw.as_deref();
 
         let es_client: Elasticsearch = Self::create_es_conn_pool(&enabled_hosts, es_id, es_pw)?;
 
         self.set_es_client(es_client);
+        self.set_disabled_hosts(disable_set);
+
+        /* Nodes may have been upgraded (rolling upgrade) -> the version is detected again. */
+        self.set_version_info(None);
 
         Ok(())
     }
@@ -548,33 +880,43 @@ impl EsRepository for EsRepositoryImpl {
 
 #[doc = "Function that initializes the Elasticsearch database being monitored"]
 /// # Returns
-/// * Result<Vec<EsRepositoryPub>, anyhow::Error> - 모니터링 할 대상 Elasticsearch 정보 list
-pub fn initialize_db_clients() -> Result<Vec<EsRepositoryImpl>, anyhow::Error> {
-    let mut elastic_conn_vec: Vec<EsRepositoryImpl> = Vec::new();
+/// * Result<Vec<(ClusterInfo, EsRepositoryImpl)>, anyhow::Error> - 모니터링 할 대상 Elasticsearch 설정 및 연결정보 list
+pub fn initialize_db_clients() -> Result<Vec<(ClusterInfo, EsRepositoryImpl)>, anyhow::Error> {
+    let mut elastic_conn_vec: Vec<(ClusterInfo, EsRepositoryImpl)> = Vec::new();
 
     let cluster_config: ClusterConfig = read_toml_from_file::<ClusterConfig>(&ELASTIC_INFO_PATH)?;
+    cluster_config.validate()?;
 
     for config in &cluster_config.clusters {
-        let es_helper: EsRepositoryImpl = EsRepositoryImpl::new(
-            &config.cluster_name,
-            config.hosts.clone(),
-            &config.es_id,
-            &config.es_pw,
-            config.index_pattern.as_deref(),
-            config.per_index_pattern.as_deref(),
-            config.urgent_index_pattern.as_deref(),
-            config.err_log_index_pattern.as_deref(),
-        )?;
-
-        elastic_conn_vec.push(es_helper);
+        let es_helper: EsRepositoryImpl = create_es_repository(config)?;
+        elastic_conn_vec.push((config.clone(), es_helper));
     }
 
     Ok(elastic_conn_vec)
 }
 
+#[doc = "Function that creates the connection of a cluster being monitored"]
+/// # Arguments
+/// * `config` - Configuration of the cluster
+///
+/// # Returns
+/// * Result<EsRepositoryImpl, anyhow::Error>
+pub fn create_es_repository(config: &ClusterInfo) -> Result<EsRepositoryImpl, anyhow::Error> {
+    EsRepositoryImpl::new(
+        &config.cluster_name,
+        config.hosts.clone(),
+        &config.es_id,
+        &config.es_pw,
+        config.index_pattern.as_deref(),
+        config.per_index_pattern.as_deref(),
+        config.urgent_index_pattern.as_deref(),
+        config.err_log_index_pattern.as_deref(),
+    )
+}
+
 #[doc = "A function that initializes the Elasticsearch client for monitoring purposes."]
 pub fn initialize_mon_db_client() -> anyhow::Result<EsRepositoryImpl> {
-    let mon_es_config: &MonElasticConfig = get_mon_es_config_info();
+    let mon_es_config: MonElasticConfig = get_mon_es_config_info();
 
     let cluster_name: &String = mon_es_config.cluster_name();
     let es_host: &Vec<String> = mon_es_config.hosts();
@@ -586,7 +928,7 @@ pub fn initialize_mon_db_client() -> anyhow::Result<EsRepositoryImpl> {
     let urgent_index_pattern: &String = mon_es_config.urgent_index_pattern();
     let err_log_index_pattern: &String = mon_es_config.err_log_index_pattern();
 
-    let es_repository: EsRepositoryImpl = match EsRepositoryImpl::new(
+    let mut es_repository: EsRepositoryImpl = match EsRepositoryImpl::new(
         cluster_name,
         es_host.clone(),
         es_id,
@@ -603,5 +945,11 @@ pub fn initialize_mon_db_client() -> anyhow::Result<EsRepositoryImpl> {
         }
     };
 
+    es_repository.set_cycle_index_pattern(mon_es_config.cycle_index_pattern().clone());
+    es_repository
+        .set_pending_task_index_pattern(mon_es_config.pending_task_index_pattern().clone());
+    es_repository
+        .set_cluster_metric_index_pattern(mon_es_config.cluster_metric_index_pattern().clone());
+
     Ok(es_repository)
 }
@@ -3,16 +3,12 @@ use crate::common::*;
 use crate::model::configs::config::*;
 use crate::model::configs::telegram_config::*;
 
-/* 전역 Telebot 인스턴스를 선언 */
-static TELEGRAM_REPO: once_lazy<Arc<TelebotRepositoryPub>> =
-    once_lazy::new(initialize_tele_bot_client);
-
 /* Client 를 전역적으로 사용하기 위한 변수 선언 */
 static REQ_CLIENT: once_lazy<Client> = once_lazy::new(Client::new);
 
 #[doc = "Telebot 을 전역적으로 초기화 함."]
 pub fn initialize_tele_bot_client() -> Arc<TelebotRepositoryPub> {
-    let tele_info_config: &TelegramConfig = get_telegram_config_info();
+    let tele_info_config: TelegramConfig = get_telegram_config_info();
     let tele_repo: TelebotRepositoryPub = TelebotRepositoryPub::new(
         tele_info_config.bot_token().to_string(),
         tele_info_config.chat_room_id().to_string(),
@@ -21,9 +17,10 @@ pub fn initialize_tele_bot_client() -> Arc<TelebotRepositoryPub> {
     Arc::new(tele_repo)
 }
 
-#[doc = "TelebotService 를 Thread-safe 하게 이용하는 함수."]
+#[doc = "TelebotService 를 Thread-safe 하게 이용하는 함수.
+         설정 파일이 변경될 수 있으므로 매번 현재 설정으로 생성한다. (가벼운 객체)"]
 pub fn get_telegram_repo() -> Arc<TelebotRepositoryPub> {
-    Arc::clone(&TELEGRAM_REPO)
+    initialize_tele_bot_client()
 }
 
 #[async_trait]
@@ -1,25 +1,59 @@
 use crate::common::*;
 
 use crate::utils_modules::calculate_utils::*;
-use crate::utils_modules::json_utils::*;
 use crate::utils_modules::time_utils::*;
 
-use crate::model::monitoring::{breaker_info::*, metric_info::*, segment_info::*};
+use crate::model::cluster_dto::{
+    cluster_health_info::*, thread_pool_config::*, unassigned_shard_info::*,
+};
+use crate::model::elastic_dto::{metric_field_mapping::*, node_stats_parser::*};
+use crate::model::monitoring::{
+    breaker_info::*, cluster_metric_info::*, index_metric_info::*, metric_info::*,
+    node_counter_sample::*, node_membership_info::*, node_rate_info::*, pending_task_info::*,
+    search_task_info::*, segment_info::*, shard_balance_info::*, thread_pool_info::*,
+};
 use crate::model::search_indicies::*;
 use crate::model::thread_pool_stat::*;
 
+use crate::enums::cluster_health_status::*;
+
 use crate::traits::{repository::es_repository_trait::*, service::metric_service_trait::*};
 
+/* Maximum number of unassigned shards explained per alert -> the explain API is expensive on large clusters */
+const MAX_ALLOCATION_EXPLAIN_SHARDS: usize = 10;
+
 #[derive(Clone, Debug)]
 pub struct MetricServiceImpl<R: EsRepository> {
     elastic_obj: Arc<RwLock<R>>,
+    /* Fields of `_nodes/stats` which could not be collected and have already been reported */
+    reported_field_errors: Arc<RwLock<HashSet<String>>>,
+    /* node ID -> cumulative counters of the previous sample */
+    node_counter_samples: Arc<RwLock<HashMap<String, NodeCounterSample>>>,
+    /* index name -> cumulative counters of the previous sample (index watchlist) */
+    index_counter_samples: Arc<RwLock<HashMap<String, NodeCounterSample>>>,
+    /* node name -> rejected counters of the thread pools of the previous sample */
+    thread_pool_samples: Arc<RwLock<HashMap<String, ThreadPoolSample>>>,
 }
 
 impl<R: EsRepository> MetricServiceImpl<R> {
     pub fn new(elastic_obj: Arc<RwLock<R>>) -> Self {
-        let metric_service: MetricServiceImpl<R> = MetricServiceImpl { elastic_obj };
+        let metric_service: MetricServiceImpl<R> = MetricServiceImpl {
+            elastic_obj,
+            reported_field_errors: Arc::new(RwLock::new(HashSet::new())),
+            node_counter_samples: Arc::new(RwLock::new(HashMap::new())),
+            index_counter_samples: Arc::new(RwLock::new(HashMap::new())),
+            thread_pool_samples: Arc::new(RwLock::new(HashMap::new())),
+        };
         metric_service
     }
+
+    #[doc = "Function that replaces the connection of the cluster (e.g. hosts or credentials changed by a reload).
+             The previous samples of the counters are kept, and the version is detected again on the next collection."]
+    /// # Arguments
+    /// * `es_repository` - Connection created from the reloaded configuration
+    pub async fn replace_es_repository(&self, es_repository: R) {
+        *self.elastic_obj.write().await = es_repository;
+    }
 }
 
 /* private function 선언부 */
@@ -45,62 +79,86 @@ impl<R: EsRepository + Sync + Send> MetricServiceImpl<R> {
     //     index_name
     // }
 
+    #[doc = "Function that returns the field mapping of the monitored cluster.
+             The version is detected once and detected again after the connection pool has been rebuilt.
+             If it cannot be detected, the 7.x field names are used."]
+    async fn get_metric_field_mapping(&self) -> MetricFieldMapping {
+        if let Some(version_info) = self.elastic_obj.read().await.get_cluster_version() {
+            return version_info.get_metric_field_mapping();
+        }
+
+        let mut elastic_guard: tokio::sync::RwLockWriteGuard<'_, R> =
+            self.elastic_obj.write().await;
+
+        match elastic_guard.detect_cluster_version().await {
+            Ok(version_info) => {
+                info!(
+                    "[MetricServiceImpl::get_metric_field_mapping] {} -> {}",
+                    elastic_guard.get_cluster_name(),
+                    version_info.get_version_name()
+                );
+                version_info.get_metric_field_mapping()
+            }
+            Err(e) => {
+                warn!(
+                    "[MetricServiceImpl::get_metric_field_mapping] Failed to detect the version of {}. 7.x field names are used. {:?}",
+                    elastic_guard.get_cluster_name(),
+                    e
+                );
+                MetricFieldMapping::default()
+            }
+        }
+    }
+
     #[doc = "breaker 모니터링 정보를 수집해주기 위한 함수"]
     /// # Arguments
-    /// * `node_info` - 모니터링 정보들
-    /// * `name` - 상세 모니터링 필드 이름
+    /// * `parser`  - 노드 모니터링 정보 파서
+    /// * `name`    - 상세 모니터링 필드 이름
     ///
     /// # Returns
-    /// * Result<BreakerInfo, anyhow::Error>
-    fn get_breaker_info(
-        &self,
-        node_info: &Value,
-        name: &str,
-    ) -> Result<BreakerInfo, anyhow::Error> {
+    /// * BreakerInfo
+    fn get_breaker_info(&self, parser: &mut NodeStatsParser<'_>, name: &str) -> BreakerInfo {
         let prefix: String = format!("breakers.{}", name);
 
-        let limit_size_in_bytes: u64 =
-            get_value_by_path(node_info, &format!("{}.limit_size_in_bytes", prefix))?;
-        let estimated_size_in_bytes: u64 =
-            get_value_by_path(node_info, &format!("{}.estimated_size_in_bytes", prefix))?;
-        let tripped: u64 = get_value_by_path(node_info, &format!("{}.tripped", prefix))?;
-        Ok(BreakerInfo::new(
-            limit_size_in_bytes,
-            estimated_size_in_bytes,
-            tripped,
-        ))
+        let limit_size_in_bytes: Option<u64> =
+            parser.get_value(&format!("{}.limit_size_in_bytes", prefix));
+        let estimated_size_in_bytes: Option<u64> =
+            parser.get_value(&format!("{}.estimated_size_in_bytes", prefix));
+        let tripped: Option<u64> = parser.get_value(&format!("{}.tripped", prefix));
+
+        BreakerInfo::new(limit_size_in_bytes, estimated_size_in_bytes, tripped)
     }
 
-    #[doc = "segment 모니터링 정보를 수집해주기 위한 함수"]
+    #[doc = "segment 모니터링 정보를 수집해주기 위한 함수 (8.x~ 에서는 `*_memory_in_bytes` 일부가 not applicable 로 None)"]
     /// # Arguments
-    /// * `node_info` - 모니터링 정보들
+    /// * `parser` - 노드 모니터링 정보 파서
     ///
     /// # Returns
-    /// * Result<SegmentInfo, anyhow::Error>
-    fn get_segment_info(&self, node_info: &Value) -> Result<SegmentInfo, anyhow::Error> {
-        let segment_count: u64 = get_value_by_path(node_info, "indices.segments.count")?;
-        let segment_memory_in_byte: u64 =
-            get_value_by_path(node_info, "indices.segments.memory_in_bytes")?;
-        let segment_terms_memory_in_bytes: u64 =
-            get_value_by_path(node_info, "indices.segments.terms_memory_in_bytes")?;
-        let segment_stored_fields_memory_in_bytes: u64 =
-            get_value_by_path(node_info, "indices.segments.stored_fields_memory_in_bytes")?;
-        let segment_term_vectors_memory_in_bytes: u64 =
-            get_value_by_path(node_info, "indices.segments.term_vectors_memory_in_bytes")?;
-        let segment_norms_memory_in_byte: u64 =
-            get_value_by_path(node_info, "indices.segments.norms_memory_in_bytes")?;
-        let segment_points_memory_in_bytes: u64 =
-            get_value_by_path(node_info, "indices.segments.points_memory_in_bytes")?;
-        let segment_doc_values_memory_in_bytes: u64 =
-            get_value_by_path(node_info, "indices.segments.doc_values_memory_in_bytes")?;
-        let segment_index_writer_memory_in_bytes: u64 =
-            get_value_by_path(node_info, "indices.segments.index_writer_memory_in_bytes")?;
-        let segment_version_map_memory_in_bytes: u64 =
-            get_value_by_path(node_info, "indices.segments.version_map_memory_in_bytes")?;
-        let segment_fixed_bit_set_memory_in_bytes: u64 =
-            get_value_by_path(node_info, "indices.segments.fixed_bit_set_memory_in_bytes")?;
-
-        Ok(SegmentInfo::new(
+    /// * SegmentInfo
+    fn get_segment_info(&self, parser: &mut NodeStatsParser<'_>) -> SegmentInfo {
+        let segment_count: Option<u64> = parser.get_value("indices.segments.count");
+        let segment_memory_in_byte: Option<u64> =
+            parser.get_value("indices.segments.memory_in_bytes");
+        let segment_terms_memory_in_bytes: Option<u64> =
+            parser.get_value("indices.segments.terms_memory_in_bytes");
+        let segment_stored_fields_memory_in_bytes: Option<u64> =
+            parser.get_value("indices.segments.stored_fields_memory_in_bytes");
+        let segment_term_vectors_memory_in_bytes: Option<u64> =
+            parser.get_value("indices.segments.term_vectors_memory_in_bytes");
+        let segment_norms_memory_in_byte: Option<u64> =
+            parser.get_value("indices.segments.norms_memory_in_bytes");
+        let segment_points_memory_in_bytes: Option<u64> =
+            parser.get_value("indices.segments.points_memory_in_bytes");
+        let segment_doc_values_memory_in_bytes: Option<u64> =
+            parser.get_value("indices.segments.doc_values_memory_in_bytes");
+        let segment_index_writer_memory_in_bytes: Option<u64> =
+            parser.get_value("indices.segments.index_writer_memory_in_bytes");
+        let segment_version_map_memory_in_bytes: Option<u64> =
+            parser.get_value("indices.segments.version_map_memory_in_bytes");
+        let segment_fixed_bit_set_memory_in_bytes: Option<u64> =
+            parser.get_value("indices.segments.fixed_bit_set_memory_in_bytes");
+
+        SegmentInfo::new(
             segment_count,
             segment_memory_in_byte,
             segment_terms_memory_in_bytes,
@@ -112,7 +170,147 @@ impl<R: EsRepository + Sync + Send> MetricServiceImpl<R> {
             segment_index_writer_memory_in_bytes,
             segment_version_map_memory_in_bytes,
             segment_fixed_bit_set_memory_in_bytes,
-        ))
+        )
+    }
+
+    #[doc = "Function that converts the `_stats` of an index into an index metric document"]
+    /// # Arguments
+    /// * `parser`          - Parser of the `_stats` of the index
+    /// * `cluster_name`    - Name of the cluster
+    /// * `index_name`      - Name of the index
+    /// * `cur_utc_time_str`- 현재시간 (문자열)
+    /// * `prev_sample`     - Previous sample of the index
+    ///
+    /// # Returns
+    /// * Result<(IndexMetricInfo, NodeCounterSample), anyhow::Error>
+    fn get_index_metric_info(
+        parser: &mut NodeStatsParser<'_>,
+        cluster_name: &str,
+        index_name: &str,
+        cur_utc_time_str: &str,
+        prev_sample: Option<&NodeCounterSample>,
+    ) -> anyhow::Result<(IndexMetricInfo, NodeCounterSample)> {
+        /*
+            Indexing is counted on the primaries (replicas would count every document twice),
+            search is counted on every copy since any copy can serve a search.
+        */
+        let counter_sample: NodeCounterSample = NodeCounterSample {
+            sampled_at_millis: Utc::now().timestamp_millis(),
+            uptime_in_millis: None,
+            index_total: parser.get_value("primaries.indexing.index_total"),
+            index_time_in_millis: parser.get_value("primaries.indexing.index_time_in_millis"),
+            query_total: parser.get_value("total.search.query_total"),
+            query_time_in_millis: parser.get_value("total.search.query_time_in_millis"),
+            fetch_total: parser.get_value("total.search.fetch_total"),
+            fetch_time_in_millis: parser.get_value("total.search.fetch_time_in_millis"),
+            flush_total: parser.get_value("total.flush.total"),
+            refresh_total: parser.get_value("total.refresh.total"),
+            translog_operation: parser.get_value("total.translog.operations"),
+            breaker_request_tripped: None,
+            breaker_fielddata_tripped: None,
+            breaker_inflight_requests_tripped: None,
+            breaker_parent_tripped: None,
+            gc_young_count: None,
+            gc_young_time_in_millis: None,
+            gc_old_count: None,
+            gc_old_time_in_millis: None,
+        };
+
+        let index_metric_info: IndexMetricInfo = IndexMetricInfoBuilder::default()
+            .timestamp(cur_utc_time_str.to_string())
+            .cluster_name(cluster_name.to_string())
+            .index_name(index_name.to_string())
+            .docs_count(parser.get_value("primaries.docs.count"))
+            .docs_deleted(parser.get_value("primaries.docs.deleted"))
+            .store_size_byte(parser.get_value("total.store.size_in_bytes"))
+            .primary_store_size_byte(parser.get_value("primaries.store.size_in_bytes"))
+            .translog_operation(counter_sample.translog_operation)
+            .translog_operation_size(parser.get_value("total.translog.size_in_bytes"))
+            .translog_uncommited_operation(
+                parser.get_value("total.translog.uncommitted_operations"),
+            )
+            .translog_uncommited_operation_size(
+                parser.get_value("total.translog.uncommitted_size_in_bytes"),
+            )
+            .flush_total(counter_sample.flush_total)
+            .refresh_total(counter_sample.refresh_total)
+            .refresh_listener(parser.get_value("total.refresh.listeners"))
+            .merge_current(parser.get_value("total.merges.current"))
+            .merge_total(parser.get_value("total.merges.total"))
+            .merge_total_time_in_millis(parser.get_value("total.merges.total_time_in_millis"))
+            .merge_total_size_byte(parser.get_value("total.merges.total_size_in_bytes"))
+            .rate_infos(counter_sample.get_rate_info(prev_sample))
+            .missing_fields(parser.get_missing_fields())
+            .build()?;
+
+        Ok((index_metric_info, counter_sample))
+    }
+
+    #[doc = "Function that marks a field as missing on every node"]
+    fn add_missing_field(metric_vec: &mut [MetricInfo], field_name: &str) {
+        for metric_info in metric_vec {
+            metric_info.missing_fields.push(field_name.to_string());
+        }
+    }
+
+    #[doc = "Function that merges the field errors of a node into the field errors of the cluster"]
+    /// # Arguments
+    /// * `field_errors`        - field path -> (number of nodes, reason)
+    /// * `node_field_errors`   - (field path, reason) of a node
+    fn merge_field_errors(
+        field_errors: &mut HashMap<String, (usize, String)>,
+        node_field_errors: Vec<(String, String)>,
+    ) {
+        for (path, reason) in node_field_errors {
+            field_errors
+                .entry(path)
+                .and_modify(|(node_cnt, _)| *node_cnt += 1)
+                .or_insert((1, reason));
+        }
+    }
+
+    #[doc = "Function that reports the fields of `_nodes/stats` which could not be collected.
+             The same set of fields is reported only once, and is reported again when the set changes."]
+    /// # Arguments
+    /// * `cluster_name`    - Name of the cluster
+    /// * `node_cnt`        - Number of nodes in the response
+    /// * `field_errors`    - field path -> (number of nodes, reason)
+    async fn report_field_errors(
+        &self,
+        cluster_name: &str,
+        node_cnt: usize,
+        field_errors: HashMap<String, (usize, String)>,
+    ) {
+        let cur_fields: HashSet<String> = field_errors.keys().cloned().collect();
+        let mut reported_fields = self.reported_field_errors.write().await;
+
+        if *reported_fields == cur_fields {
+            return;
+        }
+
+        if cur_fields.is_empty() {
+            info!(
+                "[MetricServiceImpl::report_field_errors] {} - Every field of _nodes/stats is collected again.",
+                cluster_name
+            );
+        } else {
+            let mut details: Vec<String> = field_errors
+                .iter()
+                .map(|(path, (err_node_cnt, reason))| {
+                    format!("{} ({}/{} nodes): {}", path, err_node_cnt, node_cnt, reason)
+                })
+                .collect();
+            details.sort();
+
+            warn!(
+                "[MetricServiceImpl::report_field_errors] {} - {} field(s) of _nodes/stats could not be collected. The nodes are indexed without them.\n{}",
+                cluster_name,
+                details.len(),
+                details.join("\n")
+            );
+        }
+
+        *reported_fields = cur_fields;
     }
 }
 
@@ -150,18 +348,30 @@ impl<R: EsRepository + Sync + Send + std::fmt::Debug> MetricService for MetricSe
         Ok(conn_fail_hosts)
     }
 
-    #[doc = "Cluster 의 상태를 반환해주는 함수 -> green, yellow, red"]
-    async fn get_cluster_health_check(&self) -> Result<String, anyhow::Error> {
+    #[doc = "Cluster 의 상태를 반환해주는 함수 -> green, yellow, red (+ shard 할당 현황)"]
+    async fn get_cluster_health_check(&self) -> Result<ClusterHealthInfo, anyhow::Error> {
         /* 클러스터 상태 체크 */
         let elastic_guard: tokio::sync::RwLockReadGuard<'_, R> = self.elastic_obj.read().await;
         let cluster_status_json: Value = elastic_guard.get_health_info().await?;
 
-        let cluster_status: String = cluster_status_json.get("status")
+        let cluster_status_str: &str = cluster_status_json.get("status")
             .and_then(Value::as_str)
-            .ok_or_else(|| anyhow!("[Parsing Error][get_cluster_state()] 'status' field is missing in cluster_status_json"))?
-            .to_uppercase();
-
-        Ok(cluster_status)
+            .ok_or_else(|| anyhow!("[Parsing Error][get_cluster_state()] 'status' field is missing in cluster_status_json"))?;
+
+        let cluster_status: ClusterHealthStatus =
+            ClusterHealthStatus::from_status_str(cluster_status_str).ok_or_else(|| {
+                anyhow!(
+                    "[Parsing Error][get_cluster_state()] Unknown cluster status: {}",
+                    cluster_status_str
+                )
+            })?;
+
+        let unassigned_shards: u64 = cluster_status_json
+            .get("unassigned_shards")
+            .and_then(Value::as_u64)
+            .unwrap_or(0);
+
+        Ok(ClusterHealthInfo::new(cluster_status, unassigned_shards))
     }
 
     #[doc = "Elasticsearch Cluster Health 가 불안정한 경우 - 불안정한 인덱스들을 추출하는 함수"]
@@ -205,6 +415,175 @@ impl<R: EsRepository + Sync + Send + std::fmt::Debug> MetricService for MetricSe
         Ok(err_index_detail)
     }
 
+    #[doc = "Function that summarizes the pending cluster tasks -> queue length and the oldest task"]
+    async fn get_pending_task_info(&self) -> anyhow::Result<PendingTaskInfo> {
+        let elastic_guard: tokio::sync::RwLockReadGuard<'_, R> = self.elastic_obj.read().await;
+
+        let pending_tasks: Value = elastic_guard
+            .get_pending_tasks()
+            .await
+            .map_err(|e| anyhow!("[MetricServiceImpl::get_pending_task_info] {:?}", e))?;
+
+        Ok(PendingTaskInfo::from_pending_tasks(
+            &pending_tasks,
+            &elastic_guard.get_cluster_name(),
+            &convert_date_to_str_full(Utc::now(), Utc),
+        ))
+    }
+
+    #[doc = "Function that returns the search tasks running longer than the limit"]
+    /// # Arguments
+    /// * `min_running_time_ms` - Tasks running shorter than this are ignored
+    ///
+    /// # Returns
+    /// * Result<Vec<SearchTaskInfo>, anyhow::Error>
+    async fn get_long_running_search_tasks(
+        &self,
+        min_running_time_ms: u64,
+    ) -> anyhow::Result<Vec<SearchTaskInfo>> {
+        let elastic_guard: tokio::sync::RwLockReadGuard<'_, R> = self.elastic_obj.read().await;
+
+        let tasks: Value = elastic_guard
+            .get_search_tasks()
+            .await
+            .map_err(|e| anyhow!("[MetricServiceImpl::get_long_running_search_tasks] {:?}", e))?;
+
+        Ok(SearchTaskInfo::from_tasks_response(
+            &tasks,
+            min_running_time_ms,
+        ))
+    }
+
+    #[doc = "Function that returns the shard balance of every data node.
+             Shards are counted by node name since several nodes can share a host."]
+    /// # Arguments
+    /// * `top_indices` - Number of the heaviest indices kept per node
+    ///
+    /// # Returns
+    /// * Result<Vec<ShardBalanceInfo>, anyhow::Error>
+    async fn get_shard_balance_infos(
+        &self,
+        top_indices: usize,
+    ) -> anyhow::Result<Vec<ShardBalanceInfo>> {
+        let query_fields: [&str; 4] = ["index", "state", "store", "node"];
+
+        let elastic_guard: tokio::sync::RwLockReadGuard<'_, R> = self.elastic_obj.read().await;
+
+        let (node_stats, cat_shards) = tokio::try_join!(
+            elastic_guard.get_node_stats(&["fs"]),
+            elastic_guard.get_cat_shards(&query_fields)
+        )
+        .map_err(|e| anyhow!("[MetricServiceImpl::get_shard_balance_infos] {:?}", e))?;
+
+        Ok(ShardBalanceInfo::from_node_shards(
+            &node_stats,
+            &cat_shards,
+            top_indices,
+        ))
+    }
+
+    #[doc = "Function that cancels a search task"]
+    /// # Arguments
+    /// * `task_id` - Task ID (`node_id:task_number`)
+    async fn cancel_search_task(&self, task_id: &str) -> anyhow::Result<()> {
+        self.elastic_obj
+            .read()
+            .await
+            .cancel_task(task_id)
+            .await
+            .map_err(|e| anyhow!("[MetricServiceImpl::cancel_search_task] {:?}", e))
+    }
+
+    #[doc = "Function that captures the hot threads of a node"]
+    /// # Arguments
+    /// * `node_name` - Name of the node
+    /// * `threads` - Number of hot threads to report
+    ///
+    /// # Returns
+    /// * Result<String, anyhow::Error>
+    async fn get_node_hot_threads(&self, node_name: &str, threads: u64) -> anyhow::Result<String> {
+        let elastic_guard: tokio::sync::RwLockReadGuard<'_, R> = self.elastic_obj.read().await;
+
+        elastic_guard
+            .get_hot_threads(node_name, threads)
+            .await
+            .map_err(|e| anyhow!("[MetricServiceImpl::get_node_hot_threads] {:?}", e))
+    }
+
+    #[doc = "Function that collects the cluster-wide statistics from `_cluster/health` and `_cluster/stats`"]
+    async fn get_cluster_metric_info(&self) -> anyhow::Result<ClusterMetricInfo> {
+        let elastic_guard: tokio::sync::RwLockReadGuard<'_, R> = self.elastic_obj.read().await;
+
+        let (health, stats) = tokio::try_join!(
+            elastic_guard.get_health_info(),
+            elastic_guard.get_cluster_stats()
+        )
+        .map_err(|e| anyhow!("[MetricServiceImpl::get_cluster_metric_info] {:?}", e))?;
+
+        Ok(ClusterMetricInfo::from_cluster_infos(
+            &health,
+            &stats,
+            &elastic_guard.get_cluster_name(),
+            &convert_date_to_str_full(Utc::now(), Utc),
+        ))
+    }
+
+    #[doc = "Function that returns the member nodes (ID, name, JVM uptime) and the elected master of the cluster"]
+    /// # Returns
+    /// * Result<NodeMembershipInfo, anyhow::Error>
+    async fn get_node_membership_info(&self) -> anyhow::Result<NodeMembershipInfo> {
+        let elastic_guard: tokio::sync::RwLockReadGuard<'_, R> = self.elastic_obj.read().await;
+
+        let (node_stats, cat_master) = tokio::try_join!(
+            elastic_guard.get_node_stats(&["jvm"]),
+            elastic_guard.get_cat_master()
+        )
+        .map_err(|e| anyhow!("[MetricServiceImpl::get_node_membership_info] {:?}", e))?;
+
+        Ok(NodeMembershipInfo::from_node_stats(
+            &node_stats,
+            &cat_master,
+        ))
+    }
+
+    #[doc = "Function that explains why shards are unassigned.
+             Primaries are explained first since they are the cause of a RED cluster."]
+    /// # Returns
+    /// * Result<Vec<UnassignedShardInfo>, anyhow::Error>
+    async fn get_unassigned_shard_infos(&self) -> anyhow::Result<Vec<UnassignedShardInfo>> {
+        let query_fields: [&str; 5] = ["index", "shard", "prirep", "state", "unassigned.reason"];
+
+        let elastic_guard: tokio::sync::RwLockReadGuard<'_, R> = self.elastic_obj.read().await;
+        let cat_shards: String = elastic_guard
+            .get_cat_shards(&query_fields)
+            .await
+            .map_err(|e| anyhow!("[MetricServiceImpl::get_unassigned_shard_infos] {:?}", e))?;
+
+        let mut unassigned_shards: Vec<UnassignedShardInfo> = cat_shards
+            .lines()
+            .filter_map(UnassignedShardInfo::from_cat_shards_line)
+            .collect();
+
+        unassigned_shards
+            .sort_by_key(|shard| (!shard.primary, shard.index_name.clone(), shard.shard));
+        unassigned_shards.truncate(MAX_ALLOCATION_EXPLAIN_SHARDS);
+
+        for shard in &mut unassigned_shards {
+            match elastic_guard
+                .get_allocation_explain(&shard.index_name, shard.shard, shard.primary)
+                .await
+            {
+                Ok(explain) => shard.apply_allocation_explain(&explain),
+                Err(e) => {
+                    error!("[MetricServiceImpl::get_unassigned_shard_infos] {:?}", e);
+                    shard.explanation = format!("Failed to explain the allocation: {}", e);
+                }
+            }
+        }
+
+        Ok(unassigned_shards)
+    }
+
     #[doc = "GET /_nodes/stats 정보들을 핸들링 해주는 함수"]
     /// # Arguments
     /// * `metric_vec`          - Elasticsearch 수집 대상 지표 리스트
@@ -219,136 +598,210 @@ impl<R: EsRepository + Sync + Send + std::fmt::Debug> MetricService for MetricSe
     ) -> Result<(), anyhow::Error> {
         let query_fields: [&str; 6] = ["fs", "jvm", "indices", "os", "http", "breaker"];
 
+        let field_mapping: MetricFieldMapping = self.get_metric_field_mapping().await;
+
         /* GET /_nodes/stats */
         let elastic_guard: tokio::sync::RwLockReadGuard<'_, R> = self.elastic_obj.read().await;
         let get_nodes_stats: Value = elastic_guard.get_node_stats(&query_fields).await?;
 
+        let cluster_name: String = elastic_guard.get_cluster_name();
+        let mut node_cnt: usize = 0;
+        /* field path -> (number of nodes, reason) */
+        let mut field_errors: HashMap<String, (usize, String)> = HashMap::new();
+
+        /* Samples of nodes which left the cluster are dropped with the previous samples. */
+        let prev_samples: HashMap<String, NodeCounterSample> =
+            std::mem::take(&mut *self.node_counter_samples.write().await);
+        let mut cur_samples: HashMap<String, NodeCounterSample> = HashMap::new();
+
         if let Some(nodes) = get_nodes_stats["nodes"].as_object() {
-            for (_node_id, node_info) in nodes {
-                let host: String = get_value_by_path(node_info, "host")?;
-                let name: String = get_value_by_path(node_info, "name")?;
-
-                let cpu_usage: i64 = get_value_by_path(node_info, "os.cpu.percent")?;
-                let jvm_usage: i64 = get_value_by_path(node_info, "jvm.mem.heap_used_percent")?;
-
-                let disk_total: i64 = get_value_by_path(node_info, "fs.total.total_in_bytes")?;
-                let disk_available: i64 =
-                    get_value_by_path(node_info, "fs.total.available_in_bytes")?;
-
-                let disk_usage: f64 =
-                    get_percentage_round_conversion(disk_total - disk_available, disk_total, 2)?;
-
-                let jvm_young_usage: i64 =
-                    get_value_by_path(node_info, "jvm.mem.pools.young.used_in_bytes")?;
-                let jvm_old_usage: i64 =
-                    get_value_by_path(node_info, "jvm.mem.pools.old.used_in_bytes")?;
-                let jvm_survivor_usage: i64 =
-                    get_value_by_path(node_info, "jvm.mem.pools.survivor.used_in_bytes")?;
-
-                let jvm_buffer_pool_mapped_count: u64 =
-                    get_value_by_path(node_info, "jvm.buffer_pools.mapped.count")?;
-                let jvm_buffer_pool_mapped_use_byte: u64 =
-                    get_value_by_path(node_info, "jvm.buffer_pools.mapped.used_in_bytes")?;
-                let jvm_buffer_pool_mapped_total_byte: u64 = get_value_by_path(
-                    node_info,
-                    "jvm.buffer_pools.mapped.total_capacity_in_bytes",
-                )?;
-
-                let jvm_buffer_pool_direct_count: u64 =
-                    get_value_by_path(node_info, "jvm.buffer_pools.direct.count")?;
-                let jvm_buffer_pool_direct_use_byte: u64 =
-                    get_value_by_path(node_info, "jvm.buffer_pools.direct.used_in_bytes")?;
-                let jvm_buffer_pool_direct_total_byte: u64 = get_value_by_path(
-                    node_info,
-                    "jvm.buffer_pools.direct.total_capacity_in_bytes",
-                )?;
-
-                let query_cache_total_cnt: i64 =
-                    get_value_by_path(node_info, "indices.query_cache.total_count")?;
-                let query_cache_hit_cnt: i64 =
-                    get_value_by_path(node_info, "indices.query_cache.hit_count")?;
-                let query_cache_hit: f64 =
-                    get_percentage_round_conversion(query_cache_hit_cnt, query_cache_total_cnt, 2)?;
-
-                let cache_memory_size: i64 =
-                    get_value_by_path(node_info, "indices.query_cache.memory_size_in_bytes")?;
-
-                let os_swap_total_in_bytes: i64 =
-                    get_value_by_path(node_info, "os.swap.total_in_bytes")?;
-                let os_swap_used_in_bytes: i64 =
-                    get_value_by_path(node_info, "os.swap.used_in_bytes")?;
-
-                let os_swap_usage: f64 = get_percentage_round_conversion(
-                    os_swap_used_in_bytes,
-                    os_swap_total_in_bytes,
-                    2,
-                )?;
-
-                let http_current_open: i64 = get_value_by_path(node_info, "http.current_open")?;
-
-                let indexing_total: i64 =
-                    get_value_by_path(node_info, "indices.indexing.index_total")?;
-                let index_time_in_millis: i64 =
-                    get_value_by_path(node_info, "indices.indexing.index_time_in_millis")?;
-                let indexing_latency: f64 = get_decimal_round_conversion(
-                    index_time_in_millis as f64 / indexing_total as f64,
-                    5,
-                )?;
-
-                let query_total: i64 = get_value_by_path(node_info, "indices.search.query_total")?;
-                let query_time_in_millis: i64 =
-                    get_value_by_path(node_info, "indices.search.query_time_in_millis")?;
-                let query_latency: f64 = query_time_in_millis as f64 / query_total as f64;
-
-                let fetch_total: i64 = get_value_by_path(node_info, "indices.search.fetch_total")?;
-                let fetch_time_in_millis: i64 =
-                    get_value_by_path(node_info, "indices.search.fetch_time_in_millis")?;
-                let fetch_latency: f64 = fetch_time_in_millis as f64 / fetch_total as f64;
-
-                let translog_operation: i64 =
-                    get_value_by_path(node_info, "indices.translog.operations")?;
-                let translog_operation_size: i64 =
-                    get_value_by_path(node_info, "indices.translog.size_in_bytes")?;
-                let translog_uncommitted_operation: i64 =
-                    get_value_by_path(node_info, "indices.translog.uncommitted_operations")?;
-                let translog_uncommitted_operation_size: i64 =
-                    get_value_by_path(node_info, "indices.translog.uncommitted_size_in_bytes")?;
-
-                let flush_total: i64 = get_value_by_path(node_info, "indices.flush.total")?;
-
-                let refresh_total: i64 = get_value_by_path(node_info, "indices.refresh.total")?;
-                let refresh_listener: i64 =
-                    get_value_by_path(node_info, "indices.refresh.listeners")?;
+            for (node_id, node_info) in nodes {
+                node_cnt += 1;
+
+                let mut parser: NodeStatsParser<'_> =
+                    NodeStatsParser::new(node_info, &field_mapping);
+
+                /* A node which cannot be identified cannot be indexed. */
+                let (host, name) = match (
+                    parser.get_value::<String>("host"),
+                    parser.get_value::<String>("name"),
+                ) {
+                    (Some(host), Some(name)) => (host, name),
+                    _ => {
+                        Self::merge_field_errors(&mut field_errors, parser.into_field_errors());
+                        continue;
+                    }
+                };
+
+                let cpu_usage: Option<i64> = parser.get_value("os.cpu.percent");
+                let jvm_usage: Option<i64> = parser.get_value("jvm.mem.heap_used_percent");
+
+                let disk_total: Option<i64> = parser.get_value("fs.total.total_in_bytes");
+                let disk_available: Option<i64> = parser.get_value("fs.total.available_in_bytes");
+
+                let disk_usage: Option<f64> = disk_total
+                    .zip(disk_available)
+                    .map(|(disk_total, disk_available)| {
+                        get_percentage_round_conversion(disk_total - disk_available, disk_total, 2)
+                    })
+                    .transpose()?;
+
+                let jvm_young_usage: Option<i64> =
+                    parser.get_value("jvm.mem.pools.young.used_in_bytes");
+                let jvm_old_usage: Option<i64> =
+                    parser.get_value("jvm.mem.pools.old.used_in_bytes");
+                let jvm_survivor_usage: Option<i64> =
+                    parser.get_value("jvm.mem.pools.survivor.used_in_bytes");
+
+                /* Cumulative GC counts/times since the node started */
+                let jvm_gc_young_count: Option<i64> =
+                    parser.get_value("jvm.gc.collectors.young.collection_count");
+                let jvm_gc_young_time_ms: Option<i64> =
+                    parser.get_value("jvm.gc.collectors.young.collection_time_in_millis");
+                let jvm_gc_old_count: Option<i64> =
+                    parser.get_value("jvm.gc.collectors.old.collection_count");
+                let jvm_gc_old_time_ms: Option<i64> =
+                    parser.get_value("jvm.gc.collectors.old.collection_time_in_millis");
+
+                let jvm_buffer_pool_mapped_count: Option<u64> =
+                    parser.get_value("jvm.buffer_pools.mapped.count");
+                let jvm_buffer_pool_mapped_use_byte: Option<u64> =
+                    parser.get_value("jvm.buffer_pools.mapped.used_in_bytes");
+                let jvm_buffer_pool_mapped_total_byte: Option<u64> =
+                    parser.get_value("jvm.buffer_pools.mapped.total_capacity_in_bytes");
+
+                let jvm_buffer_pool_direct_count: Option<u64> =
+                    parser.get_value("jvm.buffer_pools.direct.count");
+                let jvm_buffer_pool_direct_use_byte: Option<u64> =
+                    parser.get_value("jvm.buffer_pools.direct.used_in_bytes");
+                let jvm_buffer_pool_direct_total_byte: Option<u64> =
+                    parser.get_value("jvm.buffer_pools.direct.total_capacity_in_bytes");
+
+                let query_cache_total_cnt: Option<i64> =
+                    parser.get_value("indices.query_cache.total_count");
+                let query_cache_hit_cnt: Option<i64> =
+                    parser.get_value("indices.query_cache.hit_count");
+                let query_cache_hit: Option<f64> = query_cache_hit_cnt
+                    .zip(query_cache_total_cnt)
+                    .map(|(hit_cnt, total_cnt)| {
+                        get_percentage_round_conversion(hit_cnt, total_cnt, 2)
+                    })
+                    .transpose()?;
+
+                let cache_memory_size: Option<i64> =
+                    parser.get_value("indices.query_cache.memory_size_in_bytes");
+
+                let os_swap_total_in_bytes: Option<i64> =
+                    parser.get_value("os.swap.total_in_bytes");
+                let os_swap_used_in_bytes: Option<i64> = parser.get_value("os.swap.used_in_bytes");
+
+                let os_swap_usage: Option<f64> = os_swap_used_in_bytes
+                    .zip(os_swap_total_in_bytes)
+                    .map(|(used, total)| get_percentage_round_conversion(used, total, 2))
+                    .transpose()?;
+
+                let http_current_open: Option<i64> = parser.get_value("http.current_open");
+
+                let indexing_total: Option<i64> = parser.get_value("indices.indexing.index_total");
+                let index_time_in_millis: Option<i64> =
+                    parser.get_value("indices.indexing.index_time_in_millis");
+                let indexing_latency: Option<f64> = index_time_in_millis
+                    .zip(indexing_total)
+                    .map(|(time_in_millis, total)| {
+                        get_decimal_round_conversion(time_in_millis as f64 / total as f64, 5)
+                    })
+                    .transpose()?;
+
+                let query_total: Option<i64> = parser.get_value("indices.search.query_total");
+                let query_time_in_millis: Option<i64> =
+                    parser.get_value("indices.search.query_time_in_millis");
+                let query_latency: Option<f64> = query_time_in_millis
+                    .zip(query_total)
+                    .map(|(time_in_millis, total)| time_in_millis as f64 / total as f64);
+
+                let fetch_total: Option<i64> = parser.get_value("indices.search.fetch_total");
+                let fetch_time_in_millis: Option<i64> =
+                    parser.get_value("indices.search.fetch_time_in_millis");
+                let fetch_latency: Option<f64> = fetch_time_in_millis
+                    .zip(fetch_total)
+                    .map(|(time_in_millis, total)| time_in_millis as f64 / total as f64);
+
+                let translog_operation: Option<i64> =
+                    parser.get_value("indices.translog.operations");
+                let translog_operation_size: Option<i64> =
+                    parser.get_value("indices.translog.size_in_bytes");
+                let translog_uncommitted_operation: Option<i64> =
+                    parser.get_value("indices.translog.uncommitted_operations");
+                let translog_uncommitted_operation_size: Option<i64> =
+                    parser.get_value("indices.translog.uncommitted_size_in_bytes");
+
+                let flush_total: Option<i64> = parser.get_value("indices.flush.total");
+
+                let refresh_total: Option<i64> = parser.get_value("indices.refresh.total");
+                let refresh_listener: Option<i64> = parser.get_value("indices.refresh.listeners");
 
                 /* off-heap 메모리 관리를 위한 모니터링 코드 추가 */
-                let segment_infos: SegmentInfo = self.get_segment_info(node_info)?;
+                let segment_infos: SegmentInfo = self.get_segment_info(&mut parser);
 
                 /* breaker 지표 관련 */
-                let breaker_request: BreakerInfo = self.get_breaker_info(node_info, "request")?;
+                let breaker_request: BreakerInfo = self.get_breaker_info(&mut parser, "request");
                 let breaker_fielddata: BreakerInfo =
-                    self.get_breaker_info(node_info, "fielddata")?;
+                    self.get_breaker_info(&mut parser, "fielddata");
 
-                /* 8.x 버전 */
-                // let breaker_inflight_requests: BreakerInfo =
-                //     self.get_breaker_info(node_info, "inflight_requests")?;
-
-                /* 7.x 버전 */
+                /* 7.x: in_flight_requests, 8.x~: inflight_requests -> converted by the field mapping */
                 let breaker_inflight_requests: BreakerInfo =
-                    self.get_breaker_info(node_info, "in_flight_requests")?;
-
-                let breaker_parent: BreakerInfo = self.get_breaker_info(node_info, "parent")?;
-
-                /* 이후에 값이 들어가야 하는 필드인 경우에는 지금 해당 소스에서 0으로 초기화 한 후에 데이터를 넣어준다. */
+                    self.get_breaker_info(&mut parser, "in_flight_requests");
+
+                let breaker_parent: BreakerInfo = self.get_breaker_info(&mut parser, "parent");
+
+                /* Per-interval rates -> computed against the previous sample of the same node ID */
+                let counter_sample: NodeCounterSample = NodeCounterSample {
+                    sampled_at_millis: parser
+                        .get_value("timestamp")
+                        .unwrap_or_else(|| Utc::now().timestamp_millis()),
+                    uptime_in_millis: parser.get_value("jvm.uptime_in_millis"),
+                    index_total: indexing_total,
+                    index_time_in_millis,
+                    query_total,
+                    query_time_in_millis,
+                    fetch_total,
+                    fetch_time_in_millis,
+                    flush_total,
+                    refresh_total,
+                    translog_operation,
+                    breaker_request_tripped: *breaker_request.tripped(),
+                    breaker_fielddata_tripped: *breaker_fielddata.tripped(),
+                    breaker_inflight_requests_tripped: *breaker_inflight_requests.tripped(),
+                    breaker_parent_tripped: *breaker_parent.tripped(),
+                    gc_young_count: jvm_gc_young_count,
+                    gc_young_time_in_millis: jvm_gc_young_time_ms,
+                    gc_old_count: jvm_gc_old_count,
+                    gc_old_time_in_millis: jvm_gc_old_time_ms,
+                };
+
+                let rate_infos: NodeRateInfo =
+                    counter_sample.get_rate_info(prev_samples.get(node_id));
+                cur_samples.insert(node_id.clone(), counter_sample);
+
+                let missing_fields: Vec<String> = parser.get_missing_fields();
+                let not_applicable_fields: Vec<String> = parser.get_not_applicable_fields();
+                Self::merge_field_errors(&mut field_errors, parser.into_field_errors());
+
+                /* 이후에 값이 들어가야 하는 필드인 경우에는 지금 해당 소스에서 None 으로 초기화 한 후에 데이터를 넣어준다. */
                 let metric_info: MetricInfo = MetricInfoBuilder::default()
                     .timestamp(cur_utc_time_str.to_string())
-                    .host(host.to_string())
+                    .host(host)
                     .name(name)
                     .jvm_usage(jvm_usage)
                     .cpu_usage(cpu_usage)
-                    .disk_usage(disk_usage.round() as i64)
+                    .disk_usage(disk_usage.map(|disk_usage| disk_usage.round() as i64))
                     .jvm_young_usage_byte(jvm_young_usage)
                     .jvm_old_usage_byte(jvm_old_usage)
                     .jvm_survivor_usage_byte(jvm_survivor_usage)
+                    .jvm_gc_young_count(jvm_gc_young_count)
+                    .jvm_gc_young_time_ms(jvm_gc_young_time_ms)
+                    .jvm_gc_old_count(jvm_gc_old_count)
+                    .jvm_gc_old_time_ms(jvm_gc_old_time_ms)
                     .jvm_buffer_pool_mapped_count(jvm_buffer_pool_mapped_count)
                     .jvm_buffer_pool_mapped_use_byte(jvm_buffer_pool_mapped_use_byte)
                     .jvm_buffer_pool_mapped_total_byte(jvm_buffer_pool_mapped_total_byte)
@@ -360,7 +813,7 @@ impl<R: EsRepository + Sync + Send + std::fmt::Debug> MetricService for MetricSe
                     .os_swap_total_in_bytes(os_swap_total_in_bytes)
                     .os_swap_usage(os_swap_usage)
                     .http_current_open(http_current_open)
-                    .node_shard_cnt(0)
+                    .node_shard_cnt(None)
                     .indexing_latency(indexing_latency)
                     .query_latency(query_latency)
                     .fetch_latency(fetch_latency)
@@ -371,35 +824,26 @@ impl<R: EsRepository + Sync + Send + std::fmt::Debug> MetricService for MetricSe
                     .flush_total(flush_total)
                     .refresh_total(refresh_total)
                     .refresh_listener(refresh_listener)
-                    .search_active_thread(0)
-                    .search_thread_queue(0)
-                    .search_rejected_thread(0)
-                    .write_active_thread(0)
-                    .write_thread_queue(0)
-                    .write_rejected_thread(0)
-                    .bulk_active_thread(0)
-                    .bulk_thread_queue(0)
-                    .bulk_rejected_thread(0)
-                    .get_active_thread(0)
-                    .get_thread_queue(0)
-                    .get_rejected_thread(0)
-                    .management_active_thread(0)
-                    .management_thread_queue(0)
-                    .management_rejected_thread(0)
-                    .generic_active_thread(0)
-                    .generic_thread_queue(0)
-                    .generic_rejected_thread(0)
+                    .thread_pools(BTreeMap::new())
                     .segment_infos(segment_infos)
                     .breaker_request(breaker_request)
                     .breaker_fielddata(breaker_fielddata)
                     .breaker_inflight_requests(breaker_inflight_requests)
                     .breaker_parent(breaker_parent)
+                    .rate_infos(rate_infos)
+                    .missing_fields(missing_fields)
+                    .not_applicable_fields(not_applicable_fields)
                     .build()?;
 
                 metric_vec.push(metric_info);
             }
         }
 
+        *self.node_counter_samples.write().await = cur_samples;
+
+        self.report_field_errors(&cluster_name, node_cnt, field_errors)
+            .await;
+
         Ok(())
     }
 
@@ -437,7 +881,7 @@ impl<R: EsRepository + Sync + Send + std::fmt::Debug> MetricService for MetricSe
             let host_ip: String = metric_info.host().clone();
             let shard_cnt: &mut i64 = host_map.entry(host_ip).or_insert(0);
 
-            metric_info.node_shard_cnt = *shard_cnt;
+            metric_info.node_shard_cnt = Some(*shard_cnt);
         }
 
         Ok(())
@@ -446,35 +890,34 @@ impl<R: EsRepository + Sync + Send + std::fmt::Debug> MetricService for MetricSe
     #[doc = "GET /_cat/thread_pool 정보들을 핸들링 해주는 함수"]
     /// # Arguments
     /// * `metric_vec`          - 모니터링 지표 리스트
+    /// * `thread_pool_config`  - Thread pools to be collected
     ///
     /// # Returns
     /// * Result<(), anyhow::Error>
     async fn get_cat_thread_pool_handle(
         &self,
         metric_vec: &mut Vec<MetricInfo>,
+        thread_pool_config: &ThreadPoolConfig,
     ) -> Result<(), anyhow::Error> {
-        let query_fields: [&str; 6] = ["search", "write", "bulk", "get", "management", "generic"];
+        let query_fields: [&str; 8] = [
+            "node_name",
+            "name",
+            "active",
+            "queue",
+            "rejected",
+            "queue_size",
+            "largest",
+            "completed",
+        ];
 
         let elastic_guard: tokio::sync::RwLockReadGuard<'_, R> = self.elastic_obj.read().await;
-        let get_cat_thread_pool: String = elastic_guard.get_cat_thread_pool().await?;
+        let get_cat_thread_pool: String = elastic_guard.get_cat_thread_pool(&query_fields).await?;
+        let sampled_at_millis: i64 = Utc::now().timestamp_millis();
 
         let thread_pool_stats: Vec<ThreadPoolStat> = get_cat_thread_pool
             .lines()
-            .filter_map(|line| {
-                let parts: Vec<&str> = line.split_whitespace().collect();
-
-                if parts.len() >= 5 && query_fields.contains(&parts[1]) {
-                    Some(ThreadPoolStat::new(
-                        parts[0].to_string(),
-                        parts[1].to_string(),
-                        parts[2].parse().unwrap_or(0),
-                        parts[3].parse().unwrap_or(0),
-                        parts[4].parse().unwrap_or(0),
-                    ))
-                } else {
-                    None
-                }
-            })
+            .filter_map(ThreadPoolStat::from_cat_thread_pool_line)
+            .filter(|stat| thread_pool_config.is_included(stat.name()))
             .collect();
 
         let mut map: HashMap<String, Vec<ThreadPoolStat>> = HashMap::new();
@@ -483,58 +926,60 @@ impl<R: EsRepository + Sync + Send + std::fmt::Debug> MetricService for MetricSe
             map.entry(stat.node_name().clone()).or_default().push(stat);
         }
 
+        /* Samples of nodes which left the cluster are dropped with the previous samples. */
+        let prev_samples: HashMap<String, ThreadPoolSample> =
+            std::mem::take(&mut *self.thread_pool_samples.write().await);
+        let mut cur_samples: HashMap<String, ThreadPoolSample> = HashMap::new();
+
         for metric in metric_vec {
-            let node_name = metric.name();
-            let thread_pool_stat: &Vec<ThreadPoolStat> = match map.get(node_name) {
+            let node_name: String = metric.name().clone();
+            let thread_pool_stat: &Vec<ThreadPoolStat> = match map.get(&node_name) {
                 Some(thread_pool_stat) => thread_pool_stat,
                 None => {
                     error!("[Error][MetricService->get_cat_thread_pool_handle] The information corresponding to {} does not exist.", node_name);
+                    metric.missing_fields.push(String::from("thread_pool"));
                     continue;
                 }
             };
 
+            let cur_sample: ThreadPoolSample = ThreadPoolSample::new(
+                sampled_at_millis,
+                thread_pool_stat
+                    .iter()
+                    .map(|stat| (stat.name().clone(), *stat.rejected()))
+                    .collect(),
+            );
+
             for stat in thread_pool_stat {
-                match stat.name().as_str() {
-                    "search" => {
-                        metric.search_active_thread = *stat.active();
-                        metric.search_thread_queue = *stat.queue();
-                        metric.search_rejected_thread = *stat.rejected();
-                    }
-                    "write" => {
-                        metric.write_active_thread = *stat.active();
-                        metric.write_thread_queue = *stat.queue();
-                        metric.write_rejected_thread = *stat.rejected();
-                    }
-                    "bulk" => {
-                        metric.bulk_active_thread = *stat.active();
-                        metric.bulk_thread_queue = *stat.queue();
-                        metric.bulk_rejected_thread = *stat.rejected();
-                    }
-                    "get" => {
-                        metric.get_active_thread = *stat.active();
-                        metric.get_thread_queue = *stat.queue();
-                        metric.get_rejected_thread = *stat.rejected();
-                    }
-                    "management" => {
-                        metric.management_active_thread = *stat.active();
-                        metric.management_thread_queue = *stat.queue();
-                        metric.management_rejected_thread = *stat.rejected();
-                    }
-                    "generic" => {
-                        metric.generic_active_thread = *stat.active();
-                        metric.generic_thread_queue = *stat.queue();
-                        metric.generic_rejected_thread = *stat.rejected();
-                    }
-                    _ => {}
-                }
+                let mut thread_pool_info: ThreadPoolInfo = ThreadPoolInfo::new(
+                    *stat.active(),
+                    *stat.queue(),
+                    *stat.queue_size(),
+                    *stat.largest(),
+                    *stat.completed(),
+                    *stat.rejected(),
+                );
+                thread_pool_info.rejected_per_sec =
+                    cur_sample.get_rejected_per_sec(prev_samples.get(&node_name), stat.name());
+
+                metric
+                    .thread_pools
+                    .insert(stat.name().clone(), thread_pool_info);
             }
+
+            cur_samples.insert(node_name, cur_sample);
         }
 
+        *self.thread_pool_samples.write().await = cur_samples;
+
         Ok(())
     }
 
     #[doc = "Function that collects metric information from each node in an Elasticsearch cluster."]
-    async fn get_cluster_nodes_infos(&self) -> anyhow::Result<Vec<MetricInfo>> {
+    async fn get_cluster_nodes_infos(
+        &self,
+        thread_pool_config: &ThreadPoolConfig,
+    ) -> anyhow::Result<Vec<MetricInfo>> {
         /* An instance vector to store the indicators. */
         let mut metric_vec: Vec<MetricInfo> = Vec::new();
 
@@ -545,15 +990,101 @@ impl<R: EsRepository + Sync + Send + std::fmt::Debug> MetricService for MetricSe
         self.get_nodes_stats_handle(&mut metric_vec, &now_str)
             .await?;
 
-        /* 2. GET /_cat/shards */
-        self.get_cat_shards_handle(&mut metric_vec).await?;
+        /* 2. GET /_cat/shards -> on failure, the nodes are indexed without the shard count. */
+        if let Err(e) = self.get_cat_shards_handle(&mut metric_vec).await {
+            error!("[MetricServiceImpl::get_cluster_nodes_infos] {:?}", e);
+            Self::add_missing_field(&mut metric_vec, "node_shard_cnt");
+        }
 
-        /* 3. GET /_cat/thread_pool */
-        self.get_cat_thread_pool_handle(&mut metric_vec).await?;
+        /* 3. GET /_cat/thread_pool -> on failure, the nodes are indexed without the thread pool stats. */
+        if let Err(e) = self
+            .get_cat_thread_pool_handle(&mut metric_vec, thread_pool_config)
+            .await
+        {
+            error!("[MetricServiceImpl::get_cluster_nodes_infos] {:?}", e);
+            Self::add_missing_field(&mut metric_vec, "thread_pool");
+        }
 
         Ok(metric_vec)
     }
 
+    #[doc = "Function that collects the `_stats` of the indices of the watchlist"]
+    /// # Arguments
+    /// * `index_watch_list` - Index names or wildcard patterns
+    ///
+    /// # Returns
+    /// * Result<Vec<IndexMetricInfo>, anyhow::Error>
+    async fn get_index_watch_infos(
+        &self,
+        index_watch_list: &[String],
+    ) -> anyhow::Result<Vec<IndexMetricInfo>> {
+        let now_str: String = convert_date_to_str_full(Utc::now(), Utc);
+
+        let elastic_guard: tokio::sync::RwLockReadGuard<'_, R> = self.elastic_obj.read().await;
+        let cluster_name: String = elastic_guard.get_cluster_name();
+
+        /* Each entry is queried separately -> an index which does not exist does not fail the other entries. */
+        let mut index_stats: HashMap<String, Value> = HashMap::new();
+        let mut last_err: Option<anyhow::Error> = None;
+
+        for watch_index in index_watch_list {
+            match elastic_guard.get_specific_index_info(watch_index).await {
+                Ok(resp) => {
+                    if let Some(indices) = resp["indices"].as_object() {
+                        for (index_name, stats) in indices {
+                            index_stats.insert(index_name.clone(), stats.clone());
+                        }
+                    }
+                }
+                Err(e) => {
+                    warn!(
+                        "[MetricServiceImpl::get_index_watch_infos] {} - Failed to collect '{}': {:?}",
+                        cluster_name, watch_index, e
+                    );
+                    last_err = Some(e);
+                }
+            }
+        }
+
+        drop(elastic_guard);
+
+        if index_stats.is_empty() {
+            if let Some(e) = last_err {
+                return Err(anyhow!(
+                    "[MetricServiceImpl::get_index_watch_infos] {:?}",
+                    e
+                ));
+            }
+        }
+
+        /* Samples of indices which were deleted are dropped with the previous samples. */
+        let prev_samples: HashMap<String, NodeCounterSample> =
+            std::mem::take(&mut *self.index_counter_samples.write().await);
+        let mut cur_samples: HashMap<String, NodeCounterSample> = HashMap::new();
+
+        let field_mapping: MetricFieldMapping = MetricFieldMapping::default();
+        let mut index_metric_infos: Vec<IndexMetricInfo> = Vec::new();
+
+        for (index_name, stats) in &index_stats {
+            let mut parser: NodeStatsParser<'_> = NodeStatsParser::new(stats, &field_mapping);
+
+            let (index_metric_info, counter_sample) = Self::get_index_metric_info(
+                &mut parser,
+                &cluster_name,
+                index_name,
+                &now_str,
+                prev_samples.get(index_name),
+            )?;
+
+            index_metric_infos.push(index_metric_info);
+            cur_samples.insert(index_name.clone(), counter_sample);
+        }
+
+        *self.index_counter_samples.write().await = cur_samples;
+
+        Ok(index_metric_infos)
+    }
+
     #[doc = "클러스터의 host 정보만 리턴해주는 함수 -> 포트정보는 제외."]
     async fn extract_host_ips(&self) -> Vec<String> {
         self.elastic_obj
@@ -572,7 +1103,7 @@ impl<R: EsRepository + Sync + Send + std::fmt::Debug> MetricService for MetricSe
 
     #[doc = "Function that modified the Elasticsearch connection pool,
         which is dependent injection,
-        when a specific node connection is lost."]
+        when the set of unreachable nodes changes. (lost nodes are excluded, recovered nodes are re-admitted)"]
     async fn refresh_es_connection_pool(
         &self,
         disable_node_list: Vec<String>,
@@ -580,12 +1111,83 @@ impl<R: EsRepository + Sync + Send + std::fmt::Debug> MetricService for MetricSe
         let mut elastic_guard: tokio::sync::RwLockWriteGuard<'_, R> =
             self.elastic_obj.write().await;
 
+        let cur_disabled: HashSet<String> =
+            elastic_guard.get_disabled_hosts().into_iter().collect();
+        let new_disabled: HashSet<String> = disable_node_list.iter().cloned().collect();
+
+        /* The pool only needs to be rebuilt when the set of unreachable nodes has changed. */
+        if cur_disabled == new_disabled {
+            return Ok(());
+        }
+
         elastic_guard
             .change_es_conn_pool(disable_node_list)
             .map_err(|e| anyhow!("[MetricServiceImpl::refresh_es_connection_pool] {:?}", e))?;
 
-        info!("[MetricServiceImpl::refresh_es_connection_pool] Elasticsearch connection pool regeneration complete.");
+        info!(
+            "[MetricServiceImpl::refresh_es_connection_pool] Elasticsearch connection pool regeneration complete. disabled hosts: {:?}",
+            elastic_guard.get_disabled_hosts()
+        );
 
         Ok(())
     }
+
+    #[doc = "Function that probes the nodes excluded from the connection pool
+        and re-admits the ones that answer again."]
+    /// # Returns
+    /// * anyhow::Result<Vec<String>> - Hosts that have been re-admitted into the connection pool
+    async fn recover_es_connection_pool(&self) -> anyhow::Result<Vec<String>> {
+        let conn_stats: Vec<(String, bool)> = {
+            let elastic_guard: tokio::sync::RwLockReadGuard<'_, R> = self.elastic_obj.read().await;
+            elastic_guard.get_disabled_node_conn_check().await
+        };
+
+        let recovered_hosts: HashSet<String> = conn_stats
+            .into_iter()
+            .filter_map(|(es_host, is_success)| is_success.then_some(es_host))
+            .collect();
+
+        if recovered_hosts.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        /*
+            The disabled hosts are read and the pool is rebuilt under a single write guard,
+            so that a host disabled by `cluster_nodes_check` in the meantime is not re-admitted.
+        */
+        let mut elastic_guard: tokio::sync::RwLockWriteGuard<'_, R> =
+            self.elastic_obj.write().await;
+
+        let disabled_hosts: Vec<String> = elastic_guard.get_disabled_hosts();
+
+        let mut recovered_hosts: Vec<String> = disabled_hosts
+            .iter()
+            .filter(|host| recovered_hosts.contains(*host))
+            .cloned()
+            .collect();
+
+        if recovered_hosts.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let still_disabled: Vec<String> = disabled_hosts
+            .into_iter()
+            .filter(|host| !recovered_hosts.contains(host))
+            .collect();
+
+        elastic_guard
+            .change_es_conn_pool(still_disabled)
+            .map_err(|e| anyhow!("[MetricServiceImpl::recover_es_connection_pool] {:?}", e))?;
+
+        drop(elastic_guard);
+
+        recovered_hosts.sort();
+
+        info!(
+            "[MetricServiceImpl::recover_es_connection_pool] Hosts re-admitted into the connection pool: {:?}",
+            recovered_hosts
+        );
+
+        Ok(recovered_hosts)
+    }
 }
@@ -2,17 +2,28 @@ use crate::common::*;
 
 use crate::traits::{repository::es_repository_trait::*, service::mon_es_service_trait::*};
 
+use crate::model::alert_dto::alert_transition::*;
+use crate::model::cluster_dto::{disk_forecast_config::*, unassigned_shard_info::*};
 use crate::model::elastic_dto::dummy_data::*;
 use crate::model::elastic_dto::elastic_source_parser::*;
 use crate::model::message_formatter_dto::message_formatter_urgent::*;
-use crate::model::monitoring::metric_info::*;
-use crate::model::reports::{err_agg_history_bucket::*, err_log_info::*};
+use crate::model::monitoring::{
+    anomaly_baseline::*, cluster_metric_info::*, hot_threads_info::*, index_metric_info::*,
+    metric_info::*, monitor_cycle_info::*, node_membership_info::*, pending_task_info::*,
+};
+use crate::model::reports::{disk_forecast_info::*, err_agg_history_bucket::*, err_log_info::*};
 use crate::model::search_indicies::*;
-use crate::model::urgent_dto::{urgent_config::*, urgent_info::*};
+use crate::model::urgent_dto::urgent_info::*;
 
-use crate::utils_modules::{io_utils::*, time_utils::*};
+use crate::enums::anomaly_source::*;
 
-use crate::env_configuration::env_config::*;
+use crate::utils_modules::time_utils::*;
+
+/* Maximum number of urgent samples searched per cycle (every host, newest first -> older samples are dropped) */
+const MAX_URGENT_SAMPLES: usize = 10000;
+
+/* Maximum number of targets (node names or hosts) of an anomaly baseline bootstrap */
+const MAX_ANOMALY_BOOTSTRAP_TARGETS: usize = 1000;
 
 #[derive(Clone, Debug, new)]
 pub struct MonEsServiceImpl<R: EsRepository> {
@@ -72,6 +83,38 @@ impl<R: EsRepository> MonEsServiceImpl<R> {
         index_name
     }
 
+    #[doc = "Function that returns the daily indices covering a time range -> comma separated.
+             If the range crosses midnight (UTC), every day is searched with a wildcard so that a missing daily index is not an error."]
+    /// # Arguments
+    /// * `index_name`  - Index pattern (prefix of the daily indices)
+    /// * `start_at`    - Start of the range
+    /// * `end_at`      - End of the range
+    ///
+    /// # Returns
+    /// * String
+    fn get_daily_index_names(
+        &self,
+        index_name: &str,
+        start_at: DateTime<Utc>,
+        end_at: DateTime<Utc>,
+    ) -> String {
+        let end_date: chrono::NaiveDate = end_at.date_naive();
+
+        if start_at.date_naive() >= end_date {
+            return self.get_today_index_name(index_name, end_at);
+        }
+
+        let mut index_names: Vec<String> = Vec::new();
+        let mut day: DateTime<Utc> = start_at;
+
+        while day.date_naive() <= end_date {
+            index_names.push(format!("{}*", self.get_today_index_name(index_name, day)));
+            day += ChronoDuration::days(1);
+        }
+
+        index_names.join(",")
+    }
+
     #[doc = "Function that generated queries related to emergency indicators."]
     /// # Arguments
     /// * `host_ips` - 클러스터 내부 노드 아이피주소
@@ -87,8 +130,13 @@ impl<R: EsRepository> MonEsServiceImpl<R> {
             .map(|ip| json!({ "term": { "host": ip } }))
             .collect();
 
-        /* 엘라스틱 서치 쿼리를 통해서 최근 20초동안 urgent 지표를 확인해준다. */
+        /*
+            엘라스틱 서치 쿼리를 통해서 최근 urgent 지표를 확인해준다. (최신 순)
+            If the range holds more than `MAX_URGENT_SAMPLES` samples, the oldest ones are dropped, not the latest ones.
+        */
         json!({
+            "size": MAX_URGENT_SAMPLES,
+            "sort": [{ "timestamp": { "order": "desc" } }],
             "query": {
                 "bool": {
                     "must": [
@@ -205,6 +253,137 @@ where
         .await
     }
 
+    #[doc = "Function that logs the allocation explanation of the unassigned shards into the error log index"]
+    async fn put_unassigned_shard_infos(
+        &self,
+        cluster_name: &str,
+        unassigned_shards: &[UnassignedShardInfo],
+    ) -> anyhow::Result<()> {
+        let now_utc: DateTime<Utc> = Utc::now();
+
+        let err_log_index: String = self
+            .elastic_obj
+            .get_cluster_index_error_pattern()
+            .ok_or_else(|| {
+                anyhow!("[MonEsServiceImpl->put_unassigned_shard_infos] err_log_index is empty")
+            })?;
+
+        let err_log_list: Vec<Value> = unassigned_shards
+            .iter()
+            .filter_map(|shard| {
+                let err_log_info: ErrorLogInfo = ErrorLogInfo::new(
+                    cluster_name.to_string(),
+                    String::from(""),
+                    shard.index_name().to_string(),
+                    convert_date_to_str_full(now_utc, Utc),
+                    "Unassigned shard".into(),
+                    format!(
+                        "{} is unassigned (reason: {}, can_allocate: {})\n{}",
+                        shard.get_shard_name(),
+                        shard.unassigned_reason(),
+                        shard.can_allocate(),
+                        shard.explanation()
+                    ),
+                );
+
+                serde_json::to_value(&err_log_info).ok()
+            })
+            .collect();
+
+        self.bulk_post_error_logs(
+            err_log_list,
+            &err_log_index,
+            now_utc,
+            "put_unassigned_shard_infos",
+        )
+        .await
+    }
+
+    #[doc = "Function that logs the captured hot threads of the spiking nodes into the error log index"]
+    async fn put_hot_threads_infos(
+        &self,
+        cluster_name: &str,
+        hot_threads_infos: &[HotThreadsInfo],
+    ) -> anyhow::Result<()> {
+        let now_utc: DateTime<Utc> = Utc::now();
+
+        let err_log_index: String = self
+            .elastic_obj
+            .get_cluster_index_error_pattern()
+            .ok_or_else(|| {
+                anyhow!("[MonEsServiceImpl->put_hot_threads_infos] err_log_index is empty")
+            })?;
+
+        let err_log_list: Vec<Value> = hot_threads_infos
+            .iter()
+            .filter_map(|hot_threads_info| {
+                let err_log_info: ErrorLogInfo = ErrorLogInfo::new(
+                    cluster_name.to_string(),
+                    hot_threads_info.host().to_string(),
+                    String::from(""),
+                    hot_threads_info.timestamp().to_string(),
+                    "Hot threads".into(),
+                    format!(
+                        "{} ({})\n{}",
+                        hot_threads_info.node_name(),
+                        hot_threads_info.trigger(),
+                        hot_threads_info.hot_threads()
+                    ),
+                );
+
+                serde_json::to_value(&err_log_info).ok()
+            })
+            .collect();
+
+        self.bulk_post_error_logs(
+            err_log_list,
+            &err_log_index,
+            now_utc,
+            "put_hot_threads_infos",
+        )
+        .await
+    }
+
+    #[doc = "Function that logs the changes of the cluster membership (join/leave/restart/master change) into the error log index"]
+    async fn put_node_membership_changes(
+        &self,
+        cluster_name: &str,
+        changes: &[NodeMembershipChange],
+    ) -> anyhow::Result<()> {
+        let now_utc: DateTime<Utc> = Utc::now();
+
+        let err_log_index: String = self
+            .elastic_obj
+            .get_cluster_index_error_pattern()
+            .ok_or_else(|| {
+                anyhow!("[MonEsServiceImpl::put_node_membership_changes] err_log_index is empty")
+            })?;
+
+        let err_log_list: Vec<Value> = changes
+            .iter()
+            .filter_map(|change| {
+                let err_log_info: ErrorLogInfo = ErrorLogInfo::new(
+                    cluster_name.to_string(),
+                    change.host().to_string(),
+                    String::from(""),
+                    convert_date_to_str_full(now_utc, Utc),
+                    "Node membership change".into(),
+                    format!("[{}] {}", change.change_type().get_name(), change.detail()),
+                );
+
+                serde_json::to_value(&err_log_info).ok()
+            })
+            .collect();
+
+        self.bulk_post_error_logs(
+            err_log_list,
+            &err_log_index,
+            now_utc,
+            "put_node_membership_changes",
+        )
+        .await
+    }
+
     #[doc = "Function that logs which metric is problematic when an emergency metric alert occurs"]
     async fn put_urgent_infos(
         &self,
@@ -230,8 +409,11 @@ where
                     convert_date_to_str_full(now_utc, Utc),
                     "Emergency indicator alarm dispatch".into(),
                     format!(
-                        "{} metric has exceeded the threshold\nMetric value:{}",
+                        "[{}] {} metric of {} matched the urgent rule ({})\nMetric value:{}",
+                        urgent_index.severity().get_name(),
                         urgent_index.metric_name(),
+                        urgent_index.get_target_name(),
+                        urgent_index.condition(),
                         urgent_index.metric_value_str()
                     ),
                 );
@@ -249,6 +431,55 @@ where
         .await
     }
 
+    #[doc = "Function that logs the FIRING / REMINDER / RESOLVED transitions of alerts (including silenced ones)"]
+    async fn put_alert_transition_infos(
+        &self,
+        cluster_name: &str,
+        transitions: &[AlertTransition],
+    ) -> anyhow::Result<()> {
+        let now_utc: DateTime<Utc> = Utc::now();
+
+        let err_log_index: String = self
+            .elastic_obj
+            .get_cluster_index_error_pattern()
+            .ok_or_else(|| {
+                anyhow!("[MonEsServiceImpl::put_alert_transition_infos] err_log_index is empty")
+            })?;
+
+        let err_log_list: Vec<Value> = transitions
+            .iter()
+            .filter_map(|transition| {
+                let mut err_log_info: ErrorLogInfo = ErrorLogInfo::new(
+                    cluster_name.to_string(),
+                    transition.key().host().to_string(),
+                    String::from(""),
+                    convert_date_to_str_full(*transition.occurred_at(), Utc),
+                    format!("Alert {}", transition.transition_type().get_name()),
+                    format!(
+                        "[{}] {} - {} (firing since {})",
+                        transition.key().check_type().get_name(),
+                        transition.key().get_target_name(),
+                        transition.detail(),
+                        convert_date_to_str_full(*transition.fired_at(), Utc)
+                    ),
+                );
+
+                /* Silenced transitions are logged but not notified. */
+                err_log_info.silenced = *transition.silenced();
+
+                serde_json::to_value(&err_log_info).ok()
+            })
+            .collect();
+
+        self.bulk_post_error_logs(
+            err_log_list,
+            &err_log_index,
+            now_utc,
+            "put_alert_transition_infos",
+        )
+        .await
+    }
+
     #[doc = "Function for loading information from each cluster node into Monitoring Elasticsearch"]
     async fn post_cluster_nodes_infos(&self, metric_infos: Vec<MetricInfo>) -> anyhow::Result<()> {
         /* metric_info_log_ ... */
@@ -275,75 +506,140 @@ where
         Ok(())
     }
 
-    #[doc = "Function that monitors critical metrics and returns the result."]
-    async fn get_alarm_urgent_infos(
+    #[doc = "Function for bulk loading the statistics of the watched indices into Monitoring Elasticsearch"]
+    async fn post_index_metric_infos(
+        &self,
+        index_metric_infos: Vec<IndexMetricInfo>,
+    ) -> anyhow::Result<()> {
+        let per_index_pattern: String = self
+            .elastic_obj
+            .get_cluster_index_monitoring_pattern()
+            .ok_or_else(|| {
+                anyhow!("[MonEsServiceImpl::post_index_metric_infos] per_index_pattern is empty")
+            })?;
+
+        let index_name: String = self.get_today_index_name(&per_index_pattern, Utc::now());
+
+        let documents: Vec<Value> = index_metric_infos
+            .into_iter()
+            .map(serde_json::to_value)
+            .collect::<Result<Vec<Value>, serde_json::Error>>()?;
+
+        self.elastic_obj
+            .post_bulk_docs(&index_name, documents)
+            .await
+            .map_err(|e| anyhow!("[MonEsServiceImpl::post_index_metric_infos] {:?}", e))
+    }
+
+    #[doc = "Function that loads the pending cluster task summary into Monitoring Elasticsearch"]
+    async fn post_pending_task_info(
         &self,
-        host_ips: Vec<String>,
-    ) -> anyhow::Result<Vec<UrgentAlarmInfo>> {
-        let (now, _past, now_str, past_str) = make_time_range(20);
+        pending_task_info: PendingTaskInfo,
+    ) -> anyhow::Result<()> {
+        /* The summary is only stored when the index pattern is configured. */
+        let pending_task_index_pattern: String =
+            match self.elastic_obj.get_cluster_index_pending_task_pattern() {
+                Some(pending_task_index_pattern) => pending_task_index_pattern,
+                None => return Ok(()),
+            };
+
+        let index_name: String = self.get_today_index_name(&pending_task_index_pattern, Utc::now());
+        let document: Value = serde_json::to_value(pending_task_info)?;
+
+        self.elastic_obj
+            .post_doc(&index_name, document)
+            .await
+            .map_err(|e| anyhow!("[MonEsServiceImpl::post_pending_task_info] {:?}", e))
+    }
+
+    #[doc = "Function that loads the cluster-wide statistics of a cycle into Monitoring Elasticsearch"]
+    async fn post_cluster_metric_info(
+        &self,
+        cluster_metric_info: ClusterMetricInfo,
+    ) -> anyhow::Result<()> {
+        /* The statistics are only stored when the index pattern is configured. */
+        let cluster_metric_index_pattern: String =
+            match self.elastic_obj.get_cluster_index_cluster_metric_pattern() {
+                Some(cluster_metric_index_pattern) => cluster_metric_index_pattern,
+                None => return Ok(()),
+            };
+
+        let index_name: String =
+            self.get_today_index_name(&cluster_metric_index_pattern, Utc::now());
+        let document: Value = serde_json::to_value(cluster_metric_info)?;
+
+        self.elastic_obj
+            .post_doc(&index_name, document)
+            .await
+            .map_err(|e| anyhow!("[MonEsServiceImpl::post_cluster_metric_info] {:?}", e))
+    }
+
+    #[doc = "Function that loads the timing information of a monitoring cycle into Monitoring Elasticsearch"]
+    async fn post_monitor_cycle_info(&self, cycle_info: MonitorCycleInfo) -> anyhow::Result<()> {
+        /* Cycle timings are only stored when the index pattern is configured. */
+        let cycle_index_pattern: String = match self.elastic_obj.get_cluster_index_cycle_pattern() {
+            Some(cycle_index_pattern) => cycle_index_pattern,
+            None => return Ok(()),
+        };
+
+        let index_name: String = self.get_today_index_name(&cycle_index_pattern, Utc::now());
+        let document: Value = serde_json::to_value(cycle_info)?;
+
+        self.elastic_obj
+            .post_doc(&index_name, document)
+            .await
+            .map_err(|e| anyhow!("[MonEsServiceImpl::post_monitor_cycle_info] {:?}", e))
+    }
+
+    #[doc = "Function that returns the latest samples of the urgent index, oldest first"]
+    /// # Arguments
+    /// * `host_ips`            - Hosts of the cluster
+    /// * `search_range_sec`    - Time range searched
+    ///
+    /// # Returns
+    /// * anyhow::Result<Vec<UrgentInfo>>
+    async fn get_urgent_infos(
+        &self,
+        host_ips: &[String],
+        search_range_sec: u64,
+    ) -> anyhow::Result<Vec<UrgentInfo>> {
+        let (now, past, now_str, past_str) = make_time_range(search_range_sec as i64);
 
         let cluster_index_urgent_pattern: String = self
             .elastic_obj
             .get_cluster_index_urgent_pattern()
-            .ok_or_else(|| anyhow!("[MonEsServiceImpl::get_alarm_urgent_infos] cluster_index_monitor_pattern is empty"))?;
-
-        /* Generate name of index */
-        let index_name: String = self.get_today_index_name(&cluster_index_urgent_pattern, now);
-
-        let urgent_configs: UrgentConfigList =
-            read_toml_from_file::<UrgentConfigList>(&URGENT_CONFIG_PATH).map_err(|e| {
+            .ok_or_else(|| {
                 anyhow!(
-                    "[MonEsServiceImpl::get_alarm_urgent_infos][urgent_configs] {:?}",
-                    e
+                    "[MonEsServiceImpl::get_urgent_infos] cluster_index_monitor_pattern is empty"
                 )
             })?;
 
-        let query: Value = self.build_urgent_query(&host_ips, &past_str, &now_str);
-        let urgent_infos: Vec<UrgentInfo> = self
+        /* Generate name of index -> the range can start in the index of the previous day */
+        let index_name: String =
+            self.get_daily_index_names(&cluster_index_urgent_pattern, past, now);
+
+        let query: Value = self.build_urgent_query(host_ips, &past_str, &now_str);
+        let mut urgent_infos: Vec<UrgentInfo> = self
             .elastic_obj
             .get_search_query::<UrgentInfo>(&query, &index_name)
             .await
-            .map_err(|e| {
-                anyhow!(
-                    "[MonEsServiceImpl::get_alarm_urgent_infos][urgent_infos] {:?}",
-                    e
-                )
-            })?;
+            .map_err(|e| anyhow!("[MonEsServiceImpl::get_urgent_infos] {:?}", e))?;
 
         if urgent_infos.is_empty() {
-            warn!("[MonEsServiceImpl::get_alarm_urgent_infos] The `urgent_infos` vector is empty.");
+            warn!("[MonEsServiceImpl::get_urgent_infos] The `urgent_infos` vector is empty.");
         }
 
-        let urgent_alarm_infos: Vec<UrgentAlarmInfo> = urgent_infos
-            .iter()
-            .flat_map(|info| {
-                urgent_configs
-                    .urgent()
-                    .iter()
-                    .filter_map(|cfg| {
-                        let metric: &String = cfg.metric_name();
-                        let limit: f64 = *cfg.limit();
-
-                        match info.get_field_value(metric) {
-                            Some(val) if val > limit => Some(UrgentAlarmInfo::new(
-                                info.host().to_string(),
-                                metric.clone(),
-                                val.to_string(),
-                            )),
-                            Some(_) => None,
-                            None => {
-                                error!(
-                                    "[MonEsServiceImpl::get_alarm_urgent_infos] Missing value for metric '{}'",
-                                    metric
-                                );
-                                None
-                            }
-                        }
-                    })
-            })
-            .collect();
+        if urgent_infos.len() >= MAX_URGENT_SAMPLES {
+            warn!(
+                "[MonEsServiceImpl::get_urgent_infos] More than {} samples in the last {} seconds. Only the latest {} samples are evaluated.",
+                MAX_URGENT_SAMPLES, search_range_sec, MAX_URGENT_SAMPLES
+            );
+        }
 
-        Ok(urgent_alarm_infos)
+        /* Searched newest first -> the rules expect the samples oldest first */
+        urgent_infos.reverse();
+
+        Ok(urgent_infos)
     }
 
     #[doc = "Function that returns error log information related to node failures in Elasticsearch"]
@@ -494,4 +790,200 @@ where
 
         Ok(agg_convert_result)
     }
+
+    #[doc = "Function that forecasts when the disk of each host reaches the watermarks
+             from the hourly average `disk_usage` stored in the metric index"]
+    /// # Arguments
+    /// * `host_ips` - Hosts of the cluster
+    /// * `disk_forecast` - Disk forecast rule of the cluster
+    ///
+    /// # Returns
+    /// * anyhow::Result<Vec<DiskForecastInfo>>
+    async fn get_disk_forecast_infos(
+        &self,
+        host_ips: &[String],
+        disk_forecast: &DiskForecastConfig,
+    ) -> anyhow::Result<Vec<DiskForecastInfo>> {
+        let metric_index: String = match self.elastic_obj.get_cluster_index_pattern() {
+            Some(metric_index) => metric_index,
+            None => {
+                return Err(anyhow!(
+                    "[MonEsServiceImpl::get_disk_forecast_infos] cluster_index_pattern is empty"
+                ))
+            }
+        };
+
+        let now: DateTime<Utc> = Utc::now();
+        let start_at: DateTime<Utc> =
+            now - ChronoDuration::days(*disk_forecast.lookback_days() as i64);
+
+        let search_query: Value = json!({
+            "query": {
+                "bool": {
+                    "filter": [
+                        {
+                            "range": {
+                                "timestamp": {
+                                    "gte": convert_date_to_str_full(start_at, Utc),
+                                    "lte": convert_date_to_str_full(now, Utc)
+                                }
+                            }
+                        },
+                        {
+                            "terms": {
+                                "host.keyword": host_ips
+                            }
+                        }
+                    ]
+                }
+            },
+            "aggs": {
+                "disk_usage_per_host": {
+                    "terms": {
+                        "field": "host.keyword",
+                        "size": host_ips.len().max(1)
+                    },
+                    "aggs": {
+                        "disk_usage_per_time": {
+                            "date_histogram": {
+                                "field": "timestamp",
+                                "fixed_interval": "1h"
+                            },
+                            "aggs": {
+                                "avg_disk_usage": {
+                                    "avg": {
+                                        "field": "disk_usage"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            },
+            "size": 0
+        });
+
+        let agg_response: DiskUsageAggregation = self
+            .elastic_obj
+            .get_agg_query::<DiskUsageAggregation>(&search_query, &format!("{}*", metric_index))
+            .await
+            .map_err(|e| anyhow!("[MonEsServiceImpl::get_disk_forecast_infos] {:?}", e))?;
+
+        let disk_forecast_infos: Vec<DiskForecastInfo> = agg_response
+            .disk_usage_per_host
+            .buckets
+            .iter()
+            .filter_map(|host_bucket| {
+                /* Hours without a sample (e.g. node down) are skipped rather than counted as 0% */
+                let samples: Vec<(i64, f64)> = host_bucket
+                    .disk_usage_per_time
+                    .buckets
+                    .iter()
+                    .filter_map(|time_bucket| {
+                        time_bucket
+                            .avg_disk_usage
+                            .value
+                            .map(|disk_usage| (time_bucket.key, disk_usage))
+                    })
+                    .collect();
+
+                DiskForecastInfo::from_samples(&host_bucket.key, &samples, disk_forecast, now)
+            })
+            .collect();
+
+        Ok(disk_forecast_infos)
+    }
+
+    #[doc = "Function that builds the anomaly baselines of the metrics from the history stored in the monitoring cluster
+             -> mean and standard deviation of every target over the last `bootstrap_hours`"]
+    /// # Arguments
+    /// * `source`          - Source of the metrics (node metric index or urgent index)
+    /// * `metric_names`    - Metric names (field names or dotted paths of the documents)
+    /// * `host_ips`        - Hosts of the cluster
+    /// * `bootstrap_hours` - History used
+    /// * `end_at`          - End of the history (exclusive) -> the oldest sample not yet added to the baselines
+    ///
+    /// # Returns
+    /// * anyhow::Result<Vec<(String, String, AnomalyBaseline)>> - (target, metric name, baseline)
+    async fn get_anomaly_baselines(
+        &self,
+        source: AnomalySource,
+        metric_names: &[String],
+        host_ips: &[String],
+        bootstrap_hours: u64,
+        end_at: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<(String, String, AnomalyBaseline)>> {
+        let index_pattern: Option<String> = match source {
+            AnomalySource::EsMetric => self.elastic_obj.get_cluster_index_pattern(),
+            AnomalySource::Urgent => self.elastic_obj.get_cluster_index_urgent_pattern(),
+        };
+
+        let index_pattern: String = index_pattern.ok_or_else(|| {
+            anyhow!(
+                "[MonEsServiceImpl::get_anomaly_baselines] index pattern of '{}' is empty",
+                source.get_name()
+            )
+        })?;
+
+        let start_at: DateTime<Utc> = end_at - ChronoDuration::hours(bootstrap_hours as i64);
+
+        let metric_aggs: serde_json::Map<String, Value> = metric_names
+            .iter()
+            .enumerate()
+            .map(|(idx, metric_name)| {
+                (
+                    format!("metric_{}", idx),
+                    json!({ "extended_stats": { "field": metric_name } }),
+                )
+            })
+            .collect();
+
+        let search_query: Value = json!({
+            "query": {
+                "bool": {
+                    "filter": [
+                        {
+                            "range": {
+                                "timestamp": {
+                                    "gte": convert_date_to_str_full(start_at, Utc),
+                                    "lt": convert_date_to_str_full(end_at, Utc)
+                                }
+                            }
+                        },
+                        {
+                            "terms": {
+                                source.get_host_field(): host_ips
+                            }
+                        }
+                    ]
+                }
+            },
+            "aggs": {
+                "per_target": {
+                    "terms": {
+                        "field": source.get_target_field(),
+                        "size": MAX_ANOMALY_BOOTSTRAP_TARGETS
+                    },
+                    "aggs": metric_aggs
+                }
+            },
+            "size": 0
+        });
+
+        let aggregations: Value = self
+            .elastic_obj
+            .get_agg_query::<Value>(&search_query, &format!("{}*", index_pattern))
+            .await
+            .map_err(|e| anyhow!("[MonEsServiceImpl::get_anomaly_baselines] {:?}", e))?;
+
+        /*
+            The timestamps are stored in seconds -> the history stops at the start of the second of `end_at`,
+            so that the samples from there on are still added to the baselines.
+        */
+        Ok(AnomalyBaseline::from_bootstrap_aggregation(
+            &aggregations,
+            metric_names,
+            end_at.timestamp() * 1000 - 1,
+        ))
+    }
 }
@@ -6,18 +6,75 @@ use crate::traits::service::{
 };
 
 use crate::model::{
+    alert_dto::{alert_key::*, alert_state_store::*, alert_transition::*},
+    cluster_dto::{
+        cluster_health_info::*, cluster_info::*, disk_forecast_config::*, gc_rule_config::*,
+        health_rule_config::*, hot_threads_config::*, index_watch_config::*,
+        pending_task_rule_config::*, search_task_rule_config::*, shard_balance_config::*,
+        step_timeout_config::*, thread_pool_config::*, unassigned_shard_info::*,
+    },
+    configs::config::*,
     message_formatter_dto::{
-        message_formatter_index::*, message_formatter_node::*, message_formatter_urgent::*,
+        message_formatter_hot_threads::*, message_formatter_index::*, message_formatter_node::*,
+        message_formatter_resolved::*, message_formatter_urgent::*,
+    },
+    monitoring::{
+        anomaly_baseline::*, cluster_metric_info::*, hot_threads_info::*, index_metric_info::*,
+        metric_info::*, monitor_cycle_info::*, node_membership_info::*, pending_task_info::*,
+        search_task_info::*, shard_balance_info::*,
     },
-    monitoring::metric_info::*,
+    reports::disk_forecast_info::*,
     search_indicies::*,
+    silence_dto::silence_rule::*,
+    urgent_dto::{anomaly_config::*, urgent_config::*, urgent_info::*},
+};
+
+use crate::enums::{
+    alert_check_type::*, alert_transition_type::*, anomaly_source::*, cluster_health_status::*,
 };
 
+use crate::utils_modules::{io_utils::*, json_utils::*, time_utils::*};
+
+use crate::env_configuration::env_config::*;
+
+/* Interval at which the hosts excluded from the connection pool are probed again */
+const POOL_RECOVERY_INTERVAL_SEC: u64 = 30;
+
+/* Interval at which the anomaly baselines are written into `ANOMALY_BASELINE_PATH` (also written when the monitoring stops) */
+const ANOMALY_BASELINE_SAVE_INTERVAL_SEC: u64 = 300;
+
+/* Interval at which a disabled loop checks whether it has been enabled by a reloaded cluster list */
+const CLUSTER_CONFIG_RECHECK_SEC: u64 = 60;
+
+/* Node name -> (sampled at, node metric document), oldest first */
+type EsMetricSamples = HashMap<String, Vec<(DateTime<Utc>, Value)>>;
+
 #[derive(Debug, new)]
 pub struct MonitoringServiceImpl<M: MetricService, N: NotificationService, ME: MonEsService> {
     metric_service: Arc<M>,
     notification_service: Arc<N>,
     mon_es_service: Arc<ME>,
+    /* Replaced in place when the cluster list is reloaded -> the alert state below is kept */
+    cluster_info: RwLock<ClusterInfo>,
+    #[new(default)]
+    alert_state_store: RwLock<AlertStateStore>,
+    #[new(default)]
+    health_status_since: RwLock<HashMap<ClusterHealthStatus, DateTime<Utc>>>,
+    /* Number of consecutive cycles in which the pending tasks exceeded each limit (by metric name) */
+    #[new(default)]
+    pending_task_violation_cycles: RwLock<HashMap<String, u64>>,
+    /* Last time the hot threads of each node (by node name) were captured -> cooldown */
+    #[new(default)]
+    hot_threads_captured_at: RwLock<HashMap<String, DateTime<Utc>>>,
+    /* Membership of the previous cycle -> None until the first successful check */
+    #[new(default)]
+    node_membership: RwLock<Option<NodeMembershipInfo>>,
+    /* Recent node metrics (by node name) evaluated by the `es_metric` urgent rules, oldest first */
+    #[new(default)]
+    es_metric_samples: RwLock<EsMetricSamples>,
+    /* Anomaly baselines -> None until loaded from `ANOMALY_BASELINE_PATH` on the first evaluation */
+    #[new(default)]
+    anomaly_baselines: RwLock<Option<AnomalyBaselineStore>>,
 }
 
 impl<M, N, ME> MonitoringServiceImpl<M, N, ME>
@@ -26,64 +83,915 @@ where
     N: NotificationService,
     ME: MonEsService,
 {
-    #[doc = "Function that checks whether each node in the cluster has connectivity issues 
+    #[doc = "Function that replaces the configuration of the cluster without restarting its tasks.
+             The alert state, the samples and the baselines are kept, and the loops pick up the change on their next cycle."]
+    /// # Arguments
+    /// * `cluster_info` - Reloaded configuration of the cluster
+    pub async fn update_cluster_info(&self, cluster_info: ClusterInfo) {
+        *self.cluster_info.write().await = cluster_info;
+    }
+
+    #[doc = "Function that returns the ticker of a loop, recreated when the configured interval has changed.
+             The first ticker fires immediately, a recreated one after the new interval. Missed ticks are skipped."]
+    /// # Arguments
+    /// * `ticker`      - Ticker of the previous cycle -> None on the first cycle or after the loop was disabled
+    /// * `interval`    - Configured interval
+    ///
+    /// # Returns
+    /// * &mut tokio::time::Interval
+    fn get_ticker(
+        ticker: &mut Option<tokio::time::Interval>,
+        interval: Duration,
+    ) -> &mut tokio::time::Interval {
+        if ticker
+            .as_ref()
+            .is_some_and(|ticker| ticker.period() != interval)
+        {
+            /* The cycle that has just finished counts as the first tick of the new interval */
+            let mut new_ticker: tokio::time::Interval =
+                tokio::time::interval_at(tokio::time::Instant::now() + interval, interval);
+            new_ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+            *ticker = Some(new_ticker);
+        }
+
+        ticker.get_or_insert_with(|| {
+            let mut new_ticker: tokio::time::Interval = tokio::time::interval(interval);
+            new_ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+            new_ticker
+        })
+    }
+
+    #[doc = "Function that loads the silence rules (maintenance windows).
+             The file is read on every call so that silences can be added without a restart."]
+    fn get_silence_rule_list() -> SilenceRuleList {
+        let silence_config_path: &str = match SILENCE_CONFIG_PATH.as_deref() {
+            Some(silence_config_path) => silence_config_path,
+            None => return SilenceRuleList::default(),
+        };
+
+        read_toml_from_file::<SilenceRuleList>(silence_config_path).unwrap_or_else(|e| {
+            error!(
+                "[MonitoringServiceImpl::get_silence_rule_list] Failed to load silence rules: {:?}",
+                e
+            );
+            SilenceRuleList::default()
+        })
+    }
+
+    #[doc = "Function that loads and validates the urgent rules.
+             The file is read on every call so that rules can be changed without a restart."]
+    fn get_urgent_config_list() -> anyhow::Result<UrgentConfigList> {
+        let urgent_configs: UrgentConfigList =
+            read_toml_from_file::<UrgentConfigList>(&URGENT_CONFIG_PATH)
+                .map_err(|e| anyhow!("[MonitoringServiceImpl::get_urgent_config_list] {:?}", e))?;
+
+        urgent_configs
+            .validate()
+            .map_err(|e| anyhow!("[MonitoringServiceImpl::get_urgent_config_list] {:?}", e))?;
+
+        Ok(urgent_configs)
+    }
+
+    #[doc = "Function that loads the anomaly baselines of the cluster persisted by the previous run.
+             An empty store is returned if the path is not set, the file does not exist yet or cannot be read."]
+    /// # Arguments
+    /// * `cluster_name` - Name of the cluster -> every cluster has its own file
+    fn load_anomaly_baselines(cluster_name: &str) -> AnomalyBaselineStore {
+        let baseline_path: String = match ANOMALY_BASELINE_PATH.as_deref() {
+            Some(baseline_path) => {
+                AnomalyBaselineStore::get_cluster_file_path(baseline_path, cluster_name)
+            }
+            None => return AnomalyBaselineStore::default(),
+        };
+
+        if !Path::new(&baseline_path).exists() {
+            return AnomalyBaselineStore::default();
+        }
+
+        read_json_from_file::<AnomalyBaselineStore>(&baseline_path).unwrap_or_else(|e| {
+            error!(
+                "[MonitoringServiceImpl::load_anomaly_baselines] Failed to load anomaly baselines: {:?}",
+                e
+            );
+            AnomalyBaselineStore::default()
+        })
+    }
+
+    #[doc = "Function that adds the samples of this cycle to the anomaly baselines and returns the anomalies.
+             The baseline of a rule missing from the store is first built from the history of the monitoring cluster.
+             The store is written back to the file of the cluster by `save_anomaly_baselines`, not on every evaluation."]
+    /// # Arguments
+    /// * `anomaly_config`  - Anomaly mode of the urgent rules
+    /// * `source`          - Source of the samples
+    /// * `target_samples`  - Samples of every target collected in this cycle
+    ///
+    /// # Returns
+    /// * Vec<UrgentAlarmInfo>
+    async fn detect_anomalies(
+        &self,
+        anomaly_config: &AnomalyConfig,
+        source: AnomalySource,
+        target_samples: Vec<AnomalyTargetSamples<'_>>,
+    ) -> Vec<UrgentAlarmInfo> {
+        let rules: Vec<&AnomalyRule> = anomaly_config.get_rules(source);
+
+        if rules.is_empty() {
+            return Vec::new();
+        }
+
+        let cluster_name: String = self.cluster_info.read().await.cluster_name.clone();
+
+        let mut anomaly_baselines = self.anomaly_baselines.write().await;
+        let baseline_store: &mut AnomalyBaselineStore =
+            anomaly_baselines.get_or_insert_with(|| Self::load_anomaly_baselines(&cluster_name));
+
+        /* The history ends right before the oldest sample of this cycle -> the samples are added live */
+        let history_end_at: DateTime<Utc> = target_samples
+            .iter()
+            .flat_map(|target_sample| target_sample.samples.iter())
+            .map(|(sampled_at, _)| *sampled_at)
+            .min()
+            .unwrap_or_else(Utc::now);
+
+        let mut bootstrap_metrics: Vec<String> = rules
+            .iter()
+            .map(|rule| rule.metric_name.clone())
+            .filter(|metric_name| {
+                !baseline_store
+                    .bootstrapped
+                    .contains(&AnomalyBaselineStore::get_bootstrap_key(
+                        source,
+                        metric_name,
+                    ))
+            })
+            .collect();
+        bootstrap_metrics.sort();
+        bootstrap_metrics.dedup();
+
+        if anomaly_config.bootstrap_hours > 0 && !bootstrap_metrics.is_empty() {
+            let host_ips: Vec<String> = self.metric_service.extract_host_ips().await;
+
+            match self
+                .mon_es_service
+                .get_anomaly_baselines(
+                    source,
+                    &bootstrap_metrics,
+                    &host_ips,
+                    anomaly_config.bootstrap_hours,
+                    history_end_at,
+                )
+                .await
+            {
+                Ok(baselines) => baseline_store.insert_bootstrapped(source, baselines),
+                Err(e) => error!(
+                    "[MonitoringServiceImpl::detect_anomalies] Baselines of '{}' are built from live samples only: {:?}",
+                    source.get_name(),
+                    e
+                ),
+            }
+        }
+
+        /* The history is searched once per rule and process -> later baselines are built from live samples */
+        for metric_name in bootstrap_metrics {
+            baseline_store
+                .bootstrapped
+                .insert(AnomalyBaselineStore::get_bootstrap_key(
+                    source,
+                    &metric_name,
+                ));
+        }
+
+        let mut anomalies: Vec<UrgentAlarmInfo> = Vec::new();
+
+        for target_sample in target_samples.iter() {
+            for rule in rules.iter() {
+                let values: Vec<(i64, f64)> = target_sample
+                    .samples
+                    .iter()
+                    .filter_map(|(sampled_at, sample)| {
+                        get_numeric_value(sample, &rule.metric_name)
+                            .map(|value| (sampled_at.timestamp_millis(), value))
+                    })
+                    .collect();
+
+                if values.is_empty() {
+                    continue;
+                }
+
+                let observation: AnomalyObservation = match baseline_store.observe(
+                    &AnomalyBaselineStore::get_key(
+                        source,
+                        &target_sample.target,
+                        &rule.metric_name,
+                    ),
+                    &values,
+                    anomaly_config.alpha,
+                    anomaly_config.min_samples,
+                ) {
+                    Some(observation) => observation,
+                    None => continue,
+                };
+
+                let sigma: f64 = rule.get_sigma(anomaly_config);
+
+                if !rule.direction.is_anomaly(observation.z_score, sigma) {
+                    continue;
+                }
+
+                let mut anomaly: UrgentAlarmInfo = UrgentAlarmInfo::new(
+                    target_sample.host.clone(),
+                    format!("{} (anomaly)", rule.metric_name),
+                    observation.value.to_string(),
+                    rule.get_severity(anomaly_config),
+                    format!(
+                        "{:+.1} sigma from the baseline {:.2} ± {:.2}, limit: {} sigma {}",
+                        observation.z_score,
+                        observation.mean,
+                        observation.std_deviation,
+                        sigma,
+                        rule.direction.get_name()
+                    ),
+                );
+
+                /* The node metrics are kept per node name */
+                if target_sample.target != target_sample.host {
+                    anomaly.node_name = Some(target_sample.target.clone());
+                }
+
+                anomalies.push(anomaly);
+            }
+        }
+
+        baseline_store.remove_stale(Utc::now());
+
+        anomalies
+    }
+
+    #[doc = "Function that writes the anomaly baselines into the file of the cluster next to `ANOMALY_BASELINE_PATH`.
+             A copy of the store is written on a blocking thread so that the lock is not held during the file I/O."]
+    async fn save_anomaly_baselines(&self) {
+        let baseline_path: String = match ANOMALY_BASELINE_PATH.as_deref() {
+            Some(baseline_path) => AnomalyBaselineStore::get_cluster_file_path(
+                baseline_path,
+                &self.cluster_info.read().await.cluster_name,
+            ),
+            None => return,
+        };
+
+        /* Nothing has been evaluated (or loaded) yet */
+        let baseline_store: AnomalyBaselineStore =
+            match self.anomaly_baselines.read().await.as_ref() {
+                Some(baseline_store) => baseline_store.clone(),
+                None => return,
+            };
+
+        match tokio::task::spawn_blocking(move || {
+            write_json_to_file(&baseline_path, &baseline_store)
+        })
+        .await
+        {
+            Ok(Ok(_)) => (),
+            Ok(Err(e)) => error!("[MonitoringServiceImpl::save_anomaly_baselines] {:?}", e),
+            Err(e) => error!("[MonitoringServiceImpl::save_anomaly_baselines] {:?}", e),
+        }
+    }
+
+    #[doc = "Function that updates the alert state of a check and returns the transitions to be notified.
+             Transitions matching an active silence rule are flagged as silenced.
+             The transitions are also logged into the error log index (a silenced FIRING only once)."]
+    /// # Arguments
+    /// * `cluster_name`    - Cluster being monitored
+    /// * `check_type`      - Type of check
+    /// * `active_alerts`   - Alerts that are currently firing -> (key, detail)
+    ///
+    /// # Returns
+    /// * Vec<AlertTransition>
+    async fn evaluate_alert_states(
+        &self,
+        cluster_name: &str,
+        check_type: AlertCheckType,
+        active_alerts: Vec<(AlertKey, String)>,
+    ) -> Vec<AlertTransition> {
+        let reminder_interval: Option<ChronoDuration> =
+            get_alert_config_info().get_reminder_interval();
+
+        let now: DateTime<Utc> = Utc::now();
+
+        let mut transitions: Vec<AlertTransition> = self.alert_state_store.write().await.evaluate(
+            cluster_name,
+            check_type,
+            active_alerts,
+            now,
+            reminder_interval,
+        );
+
+        if !transitions.is_empty() {
+            let silence_rules: SilenceRuleList = Self::get_silence_rule_list();
+
+            for transition in transitions.iter_mut() {
+                if silence_rules.is_silenced(transition.key(), now) {
+                    if !transition.refired {
+                        info!(
+                            "[MonitoringServiceImpl::evaluate_alert_states] Alert silenced: [{}] {} - {}",
+                            transition.transition_type().get_name(),
+                            transition.key().get_target_name(),
+                            transition.detail()
+                        );
+                    }
+                    transition.silenced = true;
+                }
+            }
+        }
+
+        /*
+            A silenced alert is not recorded as notified -> it fires again every cycle
+            and is announced as soon as the silence ends while the condition still holds.
+            Only its first silenced FIRING is logged into the error log index.
+        */
+        let logged_transitions: Vec<AlertTransition> = transitions
+            .iter()
+            .filter(|transition| !(transition.silenced && transition.refired))
+            .cloned()
+            .collect();
+
+        if !logged_transitions.is_empty() {
+            if let Err(e) = self
+                .mon_es_service
+                .put_alert_transition_infos(cluster_name, &logged_transitions)
+                .await
+            {
+                error!("[MonitoringServiceImpl::evaluate_alert_states] {:?}", e);
+            }
+        }
+
+        transitions
+    }
+
+    #[doc = "Function that sends a RESOLVED notification for the alerts that have been cleared"]
+    async fn send_resolved_infos(
+        &self,
+        cluster_name: &str,
+        transitions: &[AlertTransition],
+    ) -> Result<(), anyhow::Error> {
+        let resolved_infos: Vec<AlertTransition> = transitions
+            .iter()
+            .filter(|transition| {
+                *transition.transition_type() == AlertTransitionType::Resolved
+                    && !transition.silenced()
+                    && !transition.unannounced()
+            })
+            .cloned()
+            .collect();
+
+        if resolved_infos.is_empty() {
+            return Ok(());
+        }
+
+        let msg_fmt: MessageFormatterResolved =
+            MessageFormatterResolved::new(cluster_name.to_string(), resolved_infos);
+
+        self.notification_service.send_alarm_infos(&msg_fmt).await
+    }
+
+    #[doc = "Function that records the alerts as notified in the alert state store.
+             Called only after the alarm has been sent, so that an alarm which failed is fired again in the next cycle."]
+    async fn mark_alerts_notified(&self, alarm_keys: &HashSet<AlertKey>) {
+        if alarm_keys.is_empty() {
+            return;
+        }
+
+        self.alert_state_store
+            .write()
+            .await
+            .mark_notified(alarm_keys, Utc::now());
+    }
+
+    #[doc = "Function that returns the alert keys of transitions which require an error alarm (silenced ones excluded)"]
+    fn get_alarm_keys(transitions: &[AlertTransition]) -> HashSet<AlertKey> {
+        transitions
+            .iter()
+            .filter(|transition| transition.transition_type().is_alarm() && !transition.silenced())
+            .map(|transition| transition.key().clone())
+            .collect()
+    }
+
+    #[doc = "Function that sends a node alarm for the alert keys to be notified -> one detail line per key"]
+    /// # Arguments
+    /// * `cluster_name`    - Cluster being monitored
+    /// * `err_subject`     - Subject of the alarm
+    /// * `alarm_keys`      - Keys to be notified (firing or reminder)
+    /// * `alarm_details`   - Detail of every active alert
+    async fn send_alarm_details(
+        &self,
+        cluster_name: &str,
+        err_subject: String,
+        alarm_keys: &HashSet<AlertKey>,
+        alarm_details: &HashMap<AlertKey, String>,
+    ) -> Result<(), anyhow::Error> {
+        if alarm_keys.is_empty() {
+            return Ok(());
+        }
+
+        let mut alarm_hosts: Vec<String> = alarm_keys
+            .iter()
+            .map(|key| key.host().to_string())
+            .collect::<HashSet<String>>()
+            .into_iter()
+            .collect();
+        alarm_hosts.sort();
+
+        let mut details: Vec<String> = alarm_keys
+            .iter()
+            .filter_map(|key| alarm_details.get(key).cloned())
+            .collect();
+        details.sort();
+
+        let msg_fmt: MessageFormatterNode = MessageFormatterNode::new(
+            cluster_name.to_string(),
+            alarm_hosts,
+            err_subject,
+            details.join("\n"),
+        );
+
+        self.notification_service.send_alarm_infos(&msg_fmt).await?;
+        self.mark_alerts_notified(alarm_keys).await;
+
+        Ok(())
+    }
+
+    #[doc = "Function that runs a single step of the monitoring cycle with a timeout and measures its duration"]
+    /// # Arguments
+    /// * `step_name`   - Name of the step (for logging)
+    /// * `timeout_sec` - Timeout of the step in seconds
+    /// * `step`        - Step to run
+    ///
+    /// # Returns
+    /// * MonitorStepInfo
+    async fn run_monitor_step<F>(step_name: &str, timeout_sec: u64, step: F) -> MonitorStepInfo
+    where
+        F: Future<Output = Result<(), anyhow::Error>>,
+    {
+        let step_start: Instant = Instant::now();
+
+        let status: &str = match tokio::time::timeout(Duration::from_secs(timeout_sec), step).await
+        {
+            Ok(Ok(_)) => "ok",
+            Ok(Err(e)) => {
+                error!(
+                    "[MonitoringServiceImpl::monitoring_loop] {}() error: {:?}",
+                    step_name, e
+                );
+                "error"
+            }
+            Err(_) => {
+                error!(
+                    "[MonitoringServiceImpl::monitoring_loop] {}() timed out after {} seconds",
+                    step_name, timeout_sec
+                );
+                "timeout"
+            }
+        };
+
+        MonitorStepInfo::new(
+            step_name.to_string(),
+            step_start.elapsed().as_millis() as u64,
+            status.to_string(),
+        )
+    }
+
+    #[doc = "Function that checks whether each node in the cluster has connectivity issues
              and sends an alarm if problems are detected"]
     async fn cluster_nodes_check(&self) -> Result<(), anyhow::Error> {
         let fail_hosts: Vec<String> = self.metric_service.get_cluster_node_check().await?;
+        let cluster_name: String = self.metric_service.get_cluster_name().await;
 
-        if !fail_hosts.is_empty() {
-            let cluster_name: String = self.metric_service.get_cluster_name().await;
+        let err_subject: String = String::from("Elasticsearch Connection Failed");
+        let err_detail: String = String::from("The connection of these hosts has been LOST.");
 
+        if !fail_hosts.is_empty() {
             /* Add code that logs errors. */
             self.mon_es_service
                 .put_node_conn_err_infos(&cluster_name, &fail_hosts)
                 .await
                 .map_err(|e| anyhow!("[MonitoringServiceImpl::cluster_nodes_check] {:?}", e))?;
+        }
+
+        let active_alerts: Vec<(AlertKey, String)> = fail_hosts
+            .iter()
+            .map(|host| {
+                (
+                    AlertKey::new(
+                        cluster_name.clone(),
+                        AlertCheckType::NodeConnection,
+                        host.clone(),
+                        String::from(""),
+                    ),
+                    err_detail.clone(),
+                )
+            })
+            .collect();
+
+        let transitions: Vec<AlertTransition> = self
+            .evaluate_alert_states(&cluster_name, AlertCheckType::NodeConnection, active_alerts)
+            .await;
+
+        let alarm_keys: HashSet<AlertKey> = Self::get_alarm_keys(&transitions);
 
+        let mut alarm_hosts: Vec<String> = alarm_keys
+            .iter()
+            .map(|key| key.host().to_string())
+            .collect();
+        alarm_hosts.sort();
+
+        if !alarm_hosts.is_empty() {
             let msg_fmt: MessageFormatterNode = MessageFormatterNode::new(
-                cluster_name,
-                fail_hosts.clone(),
-                String::from("Elasticsearch Connection Failed"),
-                String::from("The connection of these hosts has been LOST."),
+                cluster_name.clone(),
+                alarm_hosts,
+                err_subject,
+                err_detail,
             );
 
             self.notification_service.send_alarm_infos(&msg_fmt).await?;
-
-            /* elasticsearch connection pool rebuild. */
-            self.metric_service
-                .refresh_es_connection_pool(fail_hosts)
-                .await
-                .map_err(|e| anyhow!("[MonitoringServiceImpl::cluster_nodes_check] {:?}", e))?;
+            self.mark_alerts_notified(&alarm_keys).await;
         }
 
+        self.send_resolved_infos(&cluster_name, &transitions)
+            .await?;
+
+        /*
+            elasticsearch connection pool rebuild.
+            Lost hosts are excluded and recovered hosts are re-admitted. (no-op if nothing changed)
+        */
+        self.metric_service
+            .refresh_es_connection_pool(fail_hosts)
+            .await
+            .map_err(|e| anyhow!("[MonitoringServiceImpl::cluster_nodes_check] {:?}", e))?;
+
         Ok(())
     }
 
-    #[doc = "Function that monityors the cluster's status -> GREEN, YELLOW, RED"]
+    #[doc = "Function that updates since when the cluster has been at each health status (or worse)"]
+    /// # Arguments
+    /// * `health_status`   - Current health status of the cluster
+    /// * `now`             - Current time
+    ///
+    /// # Returns
+    /// * HashMap<ClusterHealthStatus, DateTime<Utc>>
+    async fn update_health_status_since(
+        &self,
+        health_status: ClusterHealthStatus,
+        now: DateTime<Utc>,
+    ) -> HashMap<ClusterHealthStatus, DateTime<Utc>> {
+        let mut health_status_since = self.health_status_since.write().await;
+
+        for status in [ClusterHealthStatus::Yellow, ClusterHealthStatus::Red] {
+            if health_status >= status {
+                health_status_since.entry(status).or_insert(now);
+            } else {
+                health_status_since.remove(&status);
+            }
+        }
+
+        health_status_since.clone()
+    }
+
+    #[doc = "Function that explains the unassigned shards and logs the explanation into the error log index.
+             A failure does not block the health alarm -> an empty list is returned."]
+    async fn get_unassigned_shard_diagnostics(
+        &self,
+        cluster_name: &str,
+    ) -> Vec<UnassignedShardInfo> {
+        let unassigned_shard_infos: Vec<UnassignedShardInfo> =
+            match self.metric_service.get_unassigned_shard_infos().await {
+                Ok(unassigned_shard_infos) => unassigned_shard_infos,
+                Err(e) => {
+                    error!(
+                        "[MonitoringServiceImpl::get_unassigned_shard_diagnostics] {:?}",
+                        e
+                    );
+                    return Vec::new();
+                }
+            };
+
+        if let Err(e) = self
+            .mon_es_service
+            .put_unassigned_shard_infos(cluster_name, &unassigned_shard_infos)
+            .await
+        {
+            error!(
+                "[MonitoringServiceImpl::get_unassigned_shard_diagnostics] {:?}",
+                e
+            );
+        }
+
+        unassigned_shard_infos
+    }
+
+    #[doc = "Function that monityors the cluster's status -> GREEN, YELLOW, RED.
+             An alert fires when the cluster stays at the status of a rule for longer than its duration."]
     async fn cluster_health_check(&self) -> Result<(), anyhow::Error> {
-        let health_status: String = self.metric_service.get_cluster_health_check().await?;
+        let health_info: ClusterHealthInfo = self.metric_service.get_cluster_health_check().await?;
+        let cluster_name: String = self.metric_service.get_cluster_name().await;
+
+        let now: DateTime<Utc> = Utc::now();
+        let health_status_since: HashMap<ClusterHealthStatus, DateTime<Utc>> = self
+            .update_health_status_since(*health_info.status(), now)
+            .await;
+
+        /* Rules whose status has lasted longer than the configured duration */
+        let health_rules: Vec<HealthRuleConfig> =
+            self.cluster_info.read().await.health_rules.clone();
+
+        let mut matched_rules: Vec<(&HealthRuleConfig, ChronoDuration)> = health_rules
+            .iter()
+            .filter_map(|rule| {
+                let since: &DateTime<Utc> = health_status_since.get(rule.status())?;
+                let elapsed: ChronoDuration = now - *since;
+
+                (elapsed >= ChronoDuration::seconds(*rule.duration_sec() as i64))
+                    .then_some((rule, elapsed))
+            })
+            .collect();
+
+        matched_rules.sort_by_key(|(rule, _)| std::cmp::Reverse(*rule.status()));
+
+        let err_subject: String = match matched_rules.first() {
+            Some((rule, elapsed)) => format!(
+                "Elasticsearch Cluster health is [{}] for {} seconds (rule: {} for {} seconds)",
+                health_info.status().get_name(),
+                elapsed.num_seconds(),
+                rule.status().get_name(),
+                rule.duration_sec()
+            ),
+            None => format!(
+                "Elasticsearch Cluster health is [{}]",
+                health_info.status().get_name()
+            ),
+        };
+
+        let mut danger_indicies: Vec<SearchIndicies> = Vec::new();
 
         /* If problems occur with the Elasticsearch cluster */
-        if health_status == "RED" {
-            //if health_status == "GREEN" {
-            let cluster_name: String = self.metric_service.get_cluster_name().await;
-            let danger_indicies: Vec<SearchIndicies> = self
+        if !matched_rules.is_empty() {
+            danger_indicies = self
                 .metric_service
                 .get_cluster_unstable_index_infos(&cluster_name)
                 .await?;
-            let all_host: Vec<String> = self.metric_service.get_cluster_all_host_infos().await;
 
             /* Add code that logs errors. */
             self.mon_es_service
                 .put_cluster_health_unstable_infos(&cluster_name, &danger_indicies)
                 .await
                 .map_err(|e| anyhow!("[MonitoringServiceImpl::cluster_health_check] {:?}", e))?;
+        }
+
+        /* Each rule is a separate alert, so that an escalation (YELLOW -> RED) is notified again. */
+        let active_alerts: Vec<(AlertKey, String)> = matched_rules
+            .iter()
+            .map(|(rule, _)| {
+                (
+                    AlertKey::new(
+                        cluster_name.clone(),
+                        AlertCheckType::ClusterHealth,
+                        String::from(""),
+                        format!("cluster_health_{}", rule.status().get_name().to_lowercase()),
+                    ),
+                    format!(
+                        "{} (unassigned shards: {})",
+                        err_subject,
+                        health_info.unassigned_shards()
+                    ),
+                )
+            })
+            .collect();
+
+        let transitions: Vec<AlertTransition> = self
+            .evaluate_alert_states(&cluster_name, AlertCheckType::ClusterHealth, active_alerts)
+            .await;
+
+        let alarm_keys: HashSet<AlertKey> = Self::get_alarm_keys(&transitions);
+
+        if !alarm_keys.is_empty() {
+            let all_host: Vec<String> = self.metric_service.get_cluster_all_host_infos().await;
+
+            /* The allocation explanation is only fetched when an alarm is sent -> the explain API is expensive. */
+            let unassigned_shard_infos: Vec<UnassignedShardInfo> =
+                if *health_info.unassigned_shards() > 0 {
+                    self.get_unassigned_shard_diagnostics(&cluster_name).await
+                } else {
+                    Vec::new()
+                };
 
             let msg_fmt: MessageFormatterIndex = MessageFormatterIndex::new(
-                cluster_name,
+                cluster_name.clone(),
                 all_host,
-                format!("Elasticsearch Cluster health is [{}]", health_status),
+                err_subject,
+                *health_info.unassigned_shards(),
                 danger_indicies,
+                unassigned_shard_infos,
+            );
+
+            self.notification_service.send_alarm_infos(&msg_fmt).await?;
+            self.mark_alerts_notified(&alarm_keys).await;
+        }
+
+        self.send_resolved_infos(&cluster_name, &transitions)
+            .await?;
+
+        Ok(())
+    }
+
+    #[doc = "Function that monitors the pending cluster tasks.
+             An alert fires when the queue length or the age of the oldest task exceeds its limit
+             for several consecutive cycles."]
+    async fn pending_tasks_check(&self) -> Result<(), anyhow::Error> {
+        let pending_task_info: PendingTaskInfo =
+            self.metric_service.get_pending_task_info().await?;
+        let cluster_name: String = self.metric_service.get_cluster_name().await;
+
+        let rule: PendingTaskRuleConfig = self.cluster_info.read().await.pending_task_rule.clone();
+        let violations: Vec<(String, String)> = rule.get_violations(&pending_task_info);
+
+        if let Err(e) = self
+            .mon_es_service
+            .post_pending_task_info(pending_task_info)
+            .await
+        {
+            error!("[MonitoringServiceImpl::pending_tasks_check] {:?}", e);
+        }
+
+        /* Each limit is counted separately -> a limit which is not exceeded in this cycle starts over. */
+        let violation_cycles: HashMap<String, u64> = {
+            let mut violation_cycles = self.pending_task_violation_cycles.write().await;
+            *violation_cycles = violations
+                .iter()
+                .map(|(metric_name, _)| {
+                    let cycles: u64 = violation_cycles.get(metric_name).copied().unwrap_or(0);
+                    (metric_name.clone(), cycles + 1)
+                })
+                .collect();
+            violation_cycles.clone()
+        };
+
+        /* A short spike is ignored -> the alert fires only after the configured number of cycles. */
+        let active_alerts: Vec<(AlertKey, String)> = violations
+            .into_iter()
+            .filter(|(metric_name, _)| violation_cycles[metric_name] >= *rule.consecutive_cycles())
+            .map(|(metric_name, detail)| {
+                let detail: String =
+                    format!("{} - {} cycles", detail, violation_cycles[&metric_name]);
+
+                (
+                    AlertKey::new(
+                        cluster_name.clone(),
+                        AlertCheckType::PendingTasks,
+                        String::from(""),
+                        metric_name,
+                    ),
+                    detail,
+                )
+            })
+            .collect();
+
+        let alarm_details: Vec<String> = active_alerts
+            .iter()
+            .map(|(_, detail)| detail.clone())
+            .collect();
+
+        let transitions: Vec<AlertTransition> = self
+            .evaluate_alert_states(&cluster_name, AlertCheckType::PendingTasks, active_alerts)
+            .await;
+
+        let alarm_keys: HashSet<AlertKey> = Self::get_alarm_keys(&transitions);
+
+        if !alarm_keys.is_empty() {
+            let all_host: Vec<String> = self.metric_service.get_cluster_all_host_infos().await;
+
+            let msg_fmt: MessageFormatterNode = MessageFormatterNode::new(
+                cluster_name.clone(),
+                all_host,
+                String::from("Pending cluster tasks are backed up"),
+                alarm_details.join("\n"),
+            );
+
+            self.notification_service.send_alarm_infos(&msg_fmt).await?;
+            self.mark_alerts_notified(&alarm_keys).await;
+        }
+
+        self.send_resolved_infos(&cluster_name, &transitions)
+            .await?;
+
+        Ok(())
+    }
+
+    #[doc = "Function that captures the hot threads of the nodes whose CPU or heap usage spiked.
+             A node is captured at most once per cooldown so that a long spike does not flood the error log."]
+    /// # Arguments
+    /// * `spike_nodes` - (host, node name, trigger) of the spiking nodes
+    async fn capture_hot_threads(
+        &self,
+        spike_nodes: Vec<(String, String, String)>,
+    ) -> Result<(), anyhow::Error> {
+        let hot_threads_config: HotThreadsConfig =
+            self.cluster_info.read().await.hot_threads.clone();
+        let now: DateTime<Utc> = Utc::now();
+        let cooldown: ChronoDuration =
+            ChronoDuration::seconds(*hot_threads_config.cooldown_sec() as i64);
+
+        /* The node is marked before the capture so that a failing node is not retried on every cycle. */
+        let capture_nodes: Vec<(String, String, String)> = {
+            let mut captured_at = self.hot_threads_captured_at.write().await;
+
+            spike_nodes
+                .into_iter()
+                .filter(|(_, node_name, _)| {
+                    let in_cooldown: bool = captured_at
+                        .get(node_name)
+                        .is_some_and(|captured_at| now < *captured_at + cooldown);
+
+                    if !in_cooldown {
+                        captured_at.insert(node_name.clone(), now);
+                    }
+
+                    !in_cooldown
+                })
+                .collect()
+        };
+
+        if capture_nodes.is_empty() {
+            return Ok(());
+        }
+
+        let timestamp: String = convert_date_to_str_full(now, Utc);
+
+        let captures = capture_nodes.into_iter().map(|(host, node_name, trigger)| {
+            let timestamp: String = timestamp.clone();
+            let threads: u64 = *hot_threads_config.threads();
+
+            async move {
+                let hot_threads: String = self
+                    .metric_service
+                    .get_node_hot_threads(&node_name, threads)
+                    .await?;
+
+                Ok::<HotThreadsInfo, anyhow::Error>(HotThreadsInfo::new(
+                    timestamp,
+                    host,
+                    node_name,
+                    trigger,
+                    hot_threads,
+                ))
+            }
+        });
+
+        let hot_threads_infos: Vec<HotThreadsInfo> = join_all(captures)
+            .await
+            .into_iter()
+            .filter_map(|result| match result {
+                Ok(hot_threads_info) => Some(hot_threads_info),
+                Err(e) => {
+                    error!("[MonitoringServiceImpl::capture_hot_threads] {:?}", e);
+                    None
+                }
+            })
+            .collect();
+
+        if hot_threads_infos.is_empty() {
+            return Ok(());
+        }
+
+        let cluster_name: String = self.metric_service.get_cluster_name().await;
+
+        if let Err(e) = self
+            .mon_es_service
+            .put_hot_threads_infos(&cluster_name, &hot_threads_infos)
+            .await
+        {
+            error!("[MonitoringServiceImpl::capture_hot_threads] {:?}", e);
+        }
+
+        /* The capture is always kept in the error log; only the alert follows the silence rules. */
+        let silence_rules: SilenceRuleList = Self::get_silence_rule_list();
+
+        let alarm_hot_threads_infos: Vec<HotThreadsInfo> = hot_threads_infos
+            .into_iter()
+            .filter(|hot_threads_info| {
+                let key: AlertKey = AlertKey::new(
+                    cluster_name.clone(),
+                    AlertCheckType::HotThreads,
+                    hot_threads_info.host().to_string(),
+                    String::from("hot_threads"),
+                );
+
+                !silence_rules.is_silenced(&key, now)
+            })
+            .collect();
+
+        if !alarm_hot_threads_infos.is_empty() {
+            let msg_fmt: MessageFormatterHotThreads = MessageFormatterHotThreads::new(
+                cluster_name,
+                alarm_hot_threads_infos,
+                *hot_threads_config.excerpt_max_chars(),
             );
 
             self.notification_service.send_alarm_infos(&msg_fmt).await?;
@@ -92,39 +1000,609 @@ where
         Ok(())
     }
 
-    #[doc = "Function that indexes observation metrics into a specific index 
-             within an Elasticsearch cluster responsible for monitoring"]
+    #[doc = "Function that alerts on the nodes which spent too long in old-gen collections during the last interval"]
+    /// # Arguments
+    /// * `metric_infos` - Metrics of the nodes collected in this cycle
+    async fn long_gc_check(&self, metric_infos: &[MetricInfo]) -> Result<(), anyhow::Error> {
+        let gc_rule: GcRuleConfig = self.cluster_info.read().await.gc_rule.clone();
+        let cluster_name: String = self.metric_service.get_cluster_name().await;
+
+        /* Keyed by node name -> several nodes can share a host */
+        let to_alert_key = |metric_info: &MetricInfo| -> AlertKey {
+            AlertKey::new(
+                cluster_name.clone(),
+                AlertCheckType::LongGc,
+                metric_info.host.clone(),
+                format!("{}/old_gc_time_ratio", metric_info.name),
+            )
+        };
+
+        let active_alerts: Vec<(AlertKey, String)> = metric_infos
+            .iter()
+            .filter_map(|metric_info| {
+                gc_rule
+                    .get_violation(metric_info)
+                    .map(|detail| (to_alert_key(metric_info), detail))
+            })
+            .collect();
+
+        let alarm_details: HashMap<AlertKey, String> = active_alerts.iter().cloned().collect();
+
+        let transitions: Vec<AlertTransition> = self
+            .evaluate_alert_states(&cluster_name, AlertCheckType::LongGc, active_alerts)
+            .await;
+
+        self.send_alarm_details(
+            &cluster_name,
+            String::from("Long old-gen garbage collection (possible heap death spiral)"),
+            &Self::get_alarm_keys(&transitions),
+            &alarm_details,
+        )
+        .await?;
+
+        self.send_resolved_infos(&cluster_name, &transitions)
+            .await?;
+
+        Ok(())
+    }
+
+    #[doc = "Function that alerts on the nodes which joined, left or restarted since the previous cycle and on elected-master changes.
+             Unlike the connection check, the membership is read from the cluster itself,
+             so a node restarting within a cycle or leaving while its HTTP port still answers is caught."]
+    async fn node_membership_check(&self) -> Result<(), anyhow::Error> {
+        let membership: NodeMembershipInfo =
+            self.metric_service
+                .get_node_membership_info()
+                .await
+                .map_err(|e| anyhow!("[MonitoringServiceImpl::node_membership_check] {:?}", e))?;
+
+        let prev_membership: Option<NodeMembershipInfo> = self
+            .node_membership
+            .write()
+            .await
+            .replace(membership.clone());
+
+        /* The first membership is only the baseline. */
+        let changes: Vec<NodeMembershipChange> = match prev_membership {
+            Some(prev_membership) => membership.get_changes(&prev_membership),
+            None => return Ok(()),
+        };
+
+        if changes.is_empty() {
+            return Ok(());
+        }
+
+        let cluster_name: String = self.metric_service.get_cluster_name().await;
+
+        for change in changes.iter() {
+            warn!(
+                "[MonitoringServiceImpl::node_membership_check] [{}] {}: {}",
+                change.change_type().get_name(),
+                cluster_name,
+                change.detail()
+            );
+        }
+
+        if let Err(e) = self
+            .mon_es_service
+            .put_node_membership_changes(&cluster_name, &changes)
+            .await
+        {
+            error!("[MonitoringServiceImpl::node_membership_check] {:?}", e);
+        }
+
+        /* The change is always kept in the error log; only the alert follows the silence rules. */
+        let silence_rules: SilenceRuleList = Self::get_silence_rule_list();
+        let now: DateTime<Utc> = Utc::now();
+
+        let alarm_changes: Vec<&NodeMembershipChange> = changes
+            .iter()
+            .filter(|change| {
+                let key: AlertKey = AlertKey::new(
+                    cluster_name.clone(),
+                    AlertCheckType::NodeMembership,
+                    change.host().to_string(),
+                    change.node_name().to_string(),
+                );
+
+                !silence_rules.is_silenced(&key, now)
+            })
+            .collect();
+
+        if alarm_changes.is_empty() {
+            return Ok(());
+        }
+
+        let mut alarm_hosts: Vec<String> = alarm_changes
+            .iter()
+            .map(|change| change.host().to_string())
+            .collect::<HashSet<String>>()
+            .into_iter()
+            .collect();
+        alarm_hosts.sort();
+
+        let details: Vec<String> = alarm_changes
+            .iter()
+            .map(|change| format!("[{}] {}", change.change_type().get_name(), change.detail()))
+            .collect();
+
+        let msg_fmt: MessageFormatterNode = MessageFormatterNode::new(
+            cluster_name,
+            alarm_hosts,
+            String::from("Cluster membership changed"),
+            details.join("\n"),
+        );
+
+        self.notification_service.send_alarm_infos(&msg_fmt).await
+    }
+
+    #[doc = "Function that alerts on the thread pools rejecting more tasks per second than their rule allows"]
+    /// # Arguments
+    /// * `metric_infos` - Metrics of the nodes collected in this cycle
+    async fn thread_pool_rejection_check(
+        &self,
+        metric_infos: &[MetricInfo],
+    ) -> Result<(), anyhow::Error> {
+        let thread_pool: ThreadPoolConfig = self.cluster_info.read().await.thread_pool.clone();
+
+        if thread_pool.rejection_rules.is_empty() {
+            return Ok(());
+        }
+
+        let cluster_name: String = self.metric_service.get_cluster_name().await;
+
+        let active_alerts: Vec<(AlertKey, String)> = metric_infos
+            .iter()
+            .flat_map(|metric_info| {
+                thread_pool
+                    .get_violations(metric_info)
+                    .into_iter()
+                    .map(|(pool_name, detail)| {
+                        (
+                            AlertKey::new(
+                                cluster_name.clone(),
+                                AlertCheckType::ThreadPoolRejection,
+                                metric_info.host.clone(),
+                                format!("{}/{}", metric_info.name, pool_name),
+                            ),
+                            detail,
+                        )
+                    })
+            })
+            .collect();
+
+        let alarm_details: HashMap<AlertKey, String> = active_alerts.iter().cloned().collect();
+
+        let transitions: Vec<AlertTransition> = self
+            .evaluate_alert_states(
+                &cluster_name,
+                AlertCheckType::ThreadPoolRejection,
+                active_alerts,
+            )
+            .await;
+
+        self.send_alarm_details(
+            &cluster_name,
+            String::from("Thread pool rejections"),
+            &Self::get_alarm_keys(&transitions),
+            &alarm_details,
+        )
+        .await?;
+
+        self.send_resolved_infos(&cluster_name, &transitions)
+            .await?;
+
+        Ok(())
+    }
+
+    #[doc = "Function that alerts on the search tasks running longer than the limit.
+             If auto-cancel is enabled, the cancellable tasks over the cancel limit are cancelled first."]
+    async fn long_search_tasks_check(&self) -> Result<(), anyhow::Error> {
+        let rule: SearchTaskRuleConfig = self.cluster_info.read().await.search_task_rule.clone();
+
+        if !rule.enabled {
+            return Ok(());
+        }
+
+        let search_tasks: Vec<SearchTaskInfo> = self
+            .metric_service
+            .get_long_running_search_tasks(rule.max_running_time_sec * 1000)
+            .await?;
+
+        let cluster_name: String = self.metric_service.get_cluster_name().await;
+        let mut cancelled_task_ids: HashSet<String> = HashSet::new();
+
+        if rule.auto_cancel {
+            for search_task in search_tasks.iter().filter(|search_task| {
+                search_task.cancellable
+                    && search_task.running_time_ms >= rule.auto_cancel_running_time_sec * 1000
+            }) {
+                match self
+                    .metric_service
+                    .cancel_search_task(search_task.task_id())
+                    .await
+                {
+                    Ok(_) => {
+                        warn!(
+                            "[MonitoringServiceImpl::long_search_tasks_check] Search task '{}' of '{}' cancelled after {} ms: {}",
+                            search_task.task_id(),
+                            cluster_name,
+                            search_task.running_time_ms(),
+                            search_task.get_description_excerpt(rule.description_max_chars)
+                        );
+                        cancelled_task_ids.insert(search_task.task_id().to_string());
+                    }
+                    Err(e) => error!("[MonitoringServiceImpl::long_search_tasks_check] {:?}", e),
+                }
+            }
+        }
+
+        let active_alerts: Vec<(AlertKey, String)> = search_tasks
+            .iter()
+            .map(|search_task| {
+                (
+                    AlertKey::new(
+                        cluster_name.clone(),
+                        AlertCheckType::LongSearchTask,
+                        search_task.host().to_string(),
+                        search_task.task_id().to_string(),
+                    ),
+                    format!(
+                        "[{}] {} running for {:.1} sec{}\n{}",
+                        search_task.node_name(),
+                        search_task.action(),
+                        *search_task.running_time_ms() as f64 / 1000.0,
+                        if cancelled_task_ids.contains(search_task.task_id()) {
+                            " (cancelled)"
+                        } else {
+                            ""
+                        },
+                        search_task.get_description_excerpt(rule.description_max_chars)
+                    ),
+                )
+            })
+            .collect();
+
+        let alarm_details: HashMap<AlertKey, String> = active_alerts.iter().cloned().collect();
+
+        let transitions: Vec<AlertTransition> = self
+            .evaluate_alert_states(&cluster_name, AlertCheckType::LongSearchTask, active_alerts)
+            .await;
+
+        self.send_alarm_details(
+            &cluster_name,
+            format!(
+                "Search tasks running longer than {} sec",
+                rule.max_running_time_sec
+            ),
+            &Self::get_alarm_keys(&transitions),
+            &alarm_details,
+        )
+        .await?;
+
+        self.send_resolved_infos(&cluster_name, &transitions)
+            .await?;
+
+        Ok(())
+    }
+
+    #[doc = "Function that alerts on the data nodes holding noticeably more shards or data than the other nodes of the same tier"]
+    async fn shard_balance_check(&self) -> Result<(), anyhow::Error> {
+        let shard_balance: ShardBalanceConfig =
+            self.cluster_info.read().await.shard_balance.clone();
+
+        if !shard_balance.enabled {
+            return Ok(());
+        }
+
+        let balance_infos: Vec<ShardBalanceInfo> = self
+            .metric_service
+            .get_shard_balance_infos(shard_balance.top_indices)
+            .await?;
+
+        let cluster_name: String = self.metric_service.get_cluster_name().await;
+
+        let active_alerts: Vec<(AlertKey, String)> = balance_infos
+            .iter()
+            .filter_map(|balance_info| {
+                shard_balance.get_violation(balance_info).map(|detail| {
+                    (
+                        AlertKey::new(
+                            cluster_name.clone(),
+                            AlertCheckType::ShardSkew,
+                            balance_info.host().to_string(),
+                            balance_info.node_name().to_string(),
+                        ),
+                        detail,
+                    )
+                })
+            })
+            .collect();
+
+        let alarm_details: HashMap<AlertKey, String> = active_alerts.iter().cloned().collect();
+
+        let transitions: Vec<AlertTransition> = self
+            .evaluate_alert_states(&cluster_name, AlertCheckType::ShardSkew, active_alerts)
+            .await;
+
+        self.send_alarm_details(
+            &cluster_name,
+            String::from("Shards are not evenly balanced across the data nodes"),
+            &Self::get_alarm_keys(&transitions),
+            &alarm_details,
+        )
+        .await?;
+
+        self.send_resolved_infos(&cluster_name, &transitions)
+            .await?;
+
+        Ok(())
+    }
+
+    #[doc = "Function that indexes observation metrics into a specific index
+             within an Elasticsearch cluster responsible for monitoring.
+             The GC times are checked and the hot threads of the nodes whose CPU or heap usage spiked are captured as well."]
     async fn input_es_metric_infos(&self) -> Result<(), anyhow::Error> {
+        let (thread_pool, hot_threads): (ThreadPoolConfig, HotThreadsConfig) = {
+            let cluster_info = self.cluster_info.read().await;
+            (
+                cluster_info.thread_pool.clone(),
+                cluster_info.hot_threads.clone(),
+            )
+        };
+
         let metric_infos: Vec<MetricInfo> = self
             .metric_service
-            .get_cluster_nodes_infos()
+            .get_cluster_nodes_infos(&thread_pool)
             .await
             .map_err(|e| anyhow!("[MonitoringServiceImpl::input_es_metric_infos] {:?}", e))?;
 
-        self.mon_es_service
+        let spike_nodes: Vec<(String, String, String)> = metric_infos
+            .iter()
+            .filter_map(|metric_info| {
+                hot_threads
+                    .get_trigger(metric_info)
+                    .map(|trigger| (metric_info.host.clone(), metric_info.name.clone(), trigger))
+            })
+            .collect();
+
+        if let Err(e) = self.long_gc_check(&metric_infos).await {
+            error!("[MonitoringServiceImpl::input_es_metric_infos] {:?}", e);
+        }
+
+        if let Err(e) = self.thread_pool_rejection_check(&metric_infos).await {
+            error!("[MonitoringServiceImpl::input_es_metric_infos] {:?}", e);
+        }
+
+        if let Err(e) = self.es_metric_urgent_check(&metric_infos).await {
+            error!("[MonitoringServiceImpl::input_es_metric_infos] {:?}", e);
+        }
+
+        let post_result: anyhow::Result<()> = self
+            .mon_es_service
             .post_cluster_nodes_infos(metric_infos)
-            .await
+            .await;
+
+        if !spike_nodes.is_empty() {
+            if let Err(e) = self.capture_hot_threads(spike_nodes).await {
+                error!("[MonitoringServiceImpl::input_es_metric_infos] {:?}", e);
+            }
+        }
+
+        post_result
             .map_err(|e| anyhow!("[MonitoringServiceImpl::input_es_metric_infos] {:?}", e))?;
 
-        /* Pending... */
-        /* 모니터링 할 인덱스 metric value 를 서버로 Post -> 당분간 안쓰는 기능 -> 특정 인덱스별로 모니터링 진행함 */
-        // match self.metric_service.post_cluster_index_infos().await {
-        //     Ok(_) => (),
-        //     Err(e) => {
-        //         error!("[MainHandler->post_cluster_index_infos] {:?}", e);
-        //     }
-        // }
+        Ok(())
+    }
+
+    #[doc = "Function that evaluates the `es_metric` urgent rules and anomaly rules against the node metrics of this cycle
+             and notifies them as the `es_metric` check, keyed by node name.
+             It is only called after the node metrics were collected, so a failed collection never resolves these alarms."]
+    /// # Arguments
+    /// * `metric_infos` - Node metrics collected in this cycle
+    ///
+    /// # Returns
+    /// * Result<(), anyhow::Error>
+    async fn es_metric_urgent_check(&self, metric_infos: &[MetricInfo]) -> anyhow::Result<()> {
+        let urgent_configs: UrgentConfigList = Self::get_urgent_config_list()
+            .map_err(|e| anyhow!("[MonitoringServiceImpl::es_metric_urgent_check] {:?}", e))?;
+
+        let cluster_name: String = self.metric_service.get_cluster_name().await;
+        let now: DateTime<Utc> = Utc::now();
+        let oldest: DateTime<Utc> =
+            now - ChronoDuration::seconds(urgent_configs.get_search_range_sec() as i64);
+
+        let mut es_metric_samples = self.es_metric_samples.write().await;
+
+        /* Nodes which left the cluster are forgotten */
+        es_metric_samples.retain(|node_name, _| {
+            metric_infos
+                .iter()
+                .any(|metric_info| &metric_info.name == node_name)
+        });
+
+        let mut urgent_infos: Vec<UrgentAlarmInfo> = Vec::new();
+        let mut latest_samples: Vec<(&MetricInfo, Value)> = Vec::new();
+
+        for metric_info in metric_infos {
+            let sample: Value = serde_json::to_value(metric_info)
+                .map_err(|e| anyhow!("[MonitoringServiceImpl::es_metric_urgent_check] {:?}", e))?;
+            latest_samples.push((metric_info, sample.clone()));
+
+            let node_samples: &mut Vec<(DateTime<Utc>, Value)> = es_metric_samples
+                .entry(metric_info.name.clone())
+                .or_default();
+            node_samples.push((now, sample));
+            node_samples.retain(|(sampled_at, _)| *sampled_at >= oldest);
+
+            let samples: Vec<(DateTime<Utc>, &Value)> = node_samples
+                .iter()
+                .map(|(sampled_at, sample)| (*sampled_at, sample))
+                .collect();
+
+            urgent_infos.extend(
+                urgent_configs
+                    .es_metric()
+                    .iter()
+                    .filter_map(|cfg| cfg.evaluate(&cluster_name, &metric_info.host, &samples))
+                    .map(|mut urgent_info| {
+                        urgent_info.node_name = Some(metric_info.name.clone());
+                        urgent_info
+                    }),
+            );
+        }
+
+        /* The lock is released before the anomaly detection, which can query Elasticsearch */
+        drop(es_metric_samples);
+
+        /* Only the sample of this cycle is added to the baseline of each node */
+        let anomaly_samples: Vec<AnomalyTargetSamples<'_>> = latest_samples
+            .iter()
+            .map(|(metric_info, sample)| {
+                AnomalyTargetSamples::new(
+                    metric_info.name.clone(),
+                    metric_info.host.clone(),
+                    vec![(now, sample)],
+                )
+            })
+            .collect();
+
+        urgent_infos.extend(
+            self.detect_anomalies(
+                urgent_configs.anomaly(),
+                AnomalySource::EsMetric,
+                anomaly_samples,
+            )
+            .await,
+        );
+
+        self.notify_urgent_alarms(&cluster_name, AlertCheckType::EsMetric, urgent_infos)
+            .await
+            .map_err(|e| anyhow!("[MonitoringServiceImpl::es_metric_urgent_check] {:?}", e))
+    }
+
+    #[doc = "Function that indexes the cluster-wide statistics next to the node metrics"]
+    async fn input_es_cluster_metric_info(&self) -> Result<(), anyhow::Error> {
+        let cluster_metric_info: ClusterMetricInfo = self
+            .metric_service
+            .get_cluster_metric_info()
+            .await
+            .map_err(|e| {
+                anyhow!(
+                    "[MonitoringServiceImpl::input_es_cluster_metric_info] {:?}",
+                    e
+                )
+            })?;
+
+        self.mon_es_service
+            .post_cluster_metric_info(cluster_metric_info)
+            .await
+            .map_err(|e| {
+                anyhow!(
+                    "[MonitoringServiceImpl::input_es_cluster_metric_info] {:?}",
+                    e
+                )
+            })
+    }
+
+    #[doc = "Function that indexes the statistics of the watched indices
+             into the per-index pattern of the monitoring cluster"]
+    async fn input_es_index_metric_infos(&self) -> Result<(), anyhow::Error> {
+        let indices: Vec<String> = self.cluster_info.read().await.index_watch.indices.clone();
+
+        let index_metric_infos: Vec<IndexMetricInfo> = self
+            .metric_service
+            .get_index_watch_infos(&indices)
+            .await
+            .map_err(|e| {
+                anyhow!(
+                    "[MonitoringServiceImpl::input_es_index_metric_infos] {:?}",
+                    e
+                )
+            })?;
+
+        self.mon_es_service
+            .post_index_metric_infos(index_metric_infos)
+            .await
+            .map_err(|e| {
+                anyhow!(
+                    "[MonitoringServiceImpl::input_es_index_metric_infos] {:?}",
+                    e
+                )
+            })
+    }
+
+    #[doc = "Function that alerts when the disk of a node is expected to reach a watermark within the horizon.
+             The forecast is drawn from the `disk_usage` history stored in the monitoring cluster."]
+    async fn disk_forecast_check(&self) -> Result<(), anyhow::Error> {
+        let disk_forecast: DiskForecastConfig =
+            self.cluster_info.read().await.disk_forecast.clone();
+        let host_ips: Vec<String> = self.metric_service.extract_host_ips().await;
+        let cluster_name: String = self.metric_service.get_cluster_name().await;
+
+        let disk_forecast_infos: Vec<DiskForecastInfo> = self
+            .mon_es_service
+            .get_disk_forecast_infos(&host_ips, &disk_forecast)
+            .await
+            .map_err(|e| anyhow!("[MonitoringServiceImpl::disk_forecast_check] {:?}", e))?;
+
+        let now: DateTime<Utc> = Utc::now();
+
+        let active_alerts: Vec<(AlertKey, String)> = disk_forecast_infos
+            .iter()
+            .flat_map(|disk_forecast_info| {
+                disk_forecast_info
+                    .get_violations(&disk_forecast, now)
+                    .into_iter()
+                    .map(|(metric_name, detail)| {
+                        (
+                            AlertKey::new(
+                                cluster_name.clone(),
+                                AlertCheckType::DiskForecast,
+                                disk_forecast_info.host().to_string(),
+                                metric_name,
+                            ),
+                            detail,
+                        )
+                    })
+            })
+            .collect();
+
+        let alarm_details: HashMap<AlertKey, String> = active_alerts.iter().cloned().collect();
+
+        let transitions: Vec<AlertTransition> = self
+            .evaluate_alert_states(&cluster_name, AlertCheckType::DiskForecast, active_alerts)
+            .await;
+
+        self.send_alarm_details(
+            &cluster_name,
+            format!(
+                "Disk is expected to reach a watermark within {} days",
+                disk_forecast.horizon_days()
+            ),
+            &Self::get_alarm_keys(&transitions),
+            &alarm_details,
+        )
+        .await?;
+
+        self.send_resolved_infos(&cluster_name, &transitions)
+            .await?;
 
         Ok(())
     }
 
-    #[doc = "Emergency Alarm service for critical indicators"]
+    #[doc = "Emergency Alarm service for critical indicators.
+             The threshold rules and the anomaly rules are evaluated on the samples of the urgent index of every host."]
     async fn send_alarm_urgent_infos(&self) -> Result<(), anyhow::Error> {
         let host_ips: Vec<String> = self.metric_service.extract_host_ips().await;
+        let cluster_name: String = self.metric_service.get_cluster_name().await;
+
+        let urgent_configs: UrgentConfigList = Self::get_urgent_config_list()
+            .map_err(|e| anyhow!("[MonitoringServiceImpl::send_alarm_urgent_infos] {:?}", e))?;
 
-        let urgent_infos: Vec<UrgentAlarmInfo> = self
+        let urgent_samples: Vec<UrgentInfo> = self
             .mon_es_service
-            .get_alarm_urgent_infos(host_ips)
+            .get_urgent_infos(&host_ips, urgent_configs.get_search_range_sec())
             .await
             .map_err(|e| {
                 error!(
@@ -134,21 +1612,123 @@ where
                 e
             })?;
 
-        if !urgent_infos.is_empty() {
-            let cluster_name: String = self.metric_service.get_cluster_name().await;
+        let host_samples: HashMap<String, Vec<(DateTime<Utc>, &UrgentInfo)>> =
+            UrgentInfo::group_by_host(&urgent_samples);
+
+        let mut hosts: Vec<&String> = host_samples.keys().collect();
+        hosts.sort();
+
+        let mut urgent_infos: Vec<UrgentAlarmInfo> = Vec::new();
+        let mut anomaly_samples: Vec<AnomalyTargetSamples<'_>> = Vec::new();
+
+        for host in hosts {
+            let samples: Vec<(DateTime<Utc>, &Value)> = host_samples[host]
+                .iter()
+                .map(|(timestamp, info)| (*timestamp, info.fields()))
+                .collect();
+
+            urgent_infos.extend(
+                urgent_configs
+                    .urgent()
+                    .iter()
+                    .filter_map(|cfg| cfg.evaluate(&cluster_name, host, &samples)),
+            );
+
+            anomaly_samples.push(AnomalyTargetSamples::new(
+                host.clone(),
+                host.clone(),
+                samples,
+            ));
+        }
+
+        urgent_infos.extend(
+            self.detect_anomalies(
+                urgent_configs.anomaly(),
+                AnomalySource::Urgent,
+                anomaly_samples,
+            )
+            .await,
+        );
 
+        self.notify_urgent_alarms(&cluster_name, AlertCheckType::UrgentMetric, urgent_infos)
+            .await
+            .map_err(|e| anyhow!("[MonitoringServiceImpl::send_alarm_urgent_infos] {:?}", e))
+    }
+
+    #[doc = "Function that logs the urgent alarms and notifies the ones the alert state store lets through.
+             The node metric alarms are keyed by `{node name}/{metric}` since several nodes can share a host."]
+    /// # Arguments
+    /// * `cluster_name`    - Name of the cluster
+    /// * `check_type`      - `UrgentMetric` (urgent index) or `EsMetric` (node metrics)
+    /// * `urgent_infos`    - Alarms active in this cycle
+    ///
+    /// # Returns
+    /// * Result<(), anyhow::Error>
+    async fn notify_urgent_alarms(
+        &self,
+        cluster_name: &str,
+        check_type: AlertCheckType,
+        urgent_infos: Vec<UrgentAlarmInfo>,
+    ) -> Result<(), anyhow::Error> {
+        if !urgent_infos.is_empty() {
             /* Add code that logs errors. */
             self.mon_es_service
-                .put_urgent_infos(&cluster_name, &urgent_infos)
+                .put_urgent_infos(cluster_name, &urgent_infos)
                 .await
-                .map_err(|e| anyhow!("[MonitoringServiceImpl::send_alarm_urgent_infos] {:?}", e))?;
+                .map_err(|e| anyhow!("[MonitoringServiceImpl::notify_urgent_alarms] {:?}", e))?;
+        }
+
+        let to_alert_key = |urgent_info: &UrgentAlarmInfo| -> AlertKey {
+            let target: String = match urgent_info.node_name() {
+                Some(node_name) => format!("{}/{}", node_name, urgent_info.metric_name()),
+                None => urgent_info.metric_name().to_string(),
+            };
+
+            AlertKey::new(
+                cluster_name.to_string(),
+                check_type,
+                urgent_info.host().to_string(),
+                target,
+            )
+        };
+
+        let active_alerts: Vec<(AlertKey, String)> = urgent_infos
+            .iter()
+            .map(|urgent_info| {
+                (
+                    to_alert_key(urgent_info),
+                    format!(
+                        "[{}] {}: {} ({})",
+                        urgent_info.severity().get_name(),
+                        urgent_info.metric_name(),
+                        urgent_info.metric_value_str(),
+                        urgent_info.condition()
+                    ),
+                )
+            })
+            .collect();
+
+        let transitions: Vec<AlertTransition> = self
+            .evaluate_alert_states(cluster_name, check_type, active_alerts)
+            .await;
+
+        let alarm_keys: HashSet<AlertKey> = Self::get_alarm_keys(&transitions);
+
+        let alarm_urgent_infos: Vec<UrgentAlarmInfo> = urgent_infos
+            .into_iter()
+            .filter(|urgent_info| alarm_keys.contains(&to_alert_key(urgent_info)))
+            .collect();
 
+        if !alarm_urgent_infos.is_empty() {
             let msg: MessageFormatterUrgent =
-                MessageFormatterUrgent::new(cluster_name, urgent_infos);
+                MessageFormatterUrgent::new(cluster_name.to_string(), alarm_urgent_infos);
 
             self.notification_service.send_alarm_infos(&msg).await?;
+            self.mark_alerts_notified(&alarm_keys).await;
         }
 
+        self.send_resolved_infos(cluster_name, &transitions).await?;
+
         Ok(())
     }
 }
@@ -161,38 +1741,277 @@ where
     ME: MonEsService + Sync + Send,
 {
     #[doc = "Function that monitors the Elasticsearch cluster status."]
-    async fn monitoring_loop(&self) -> anyhow::Result<()> {
+    async fn monitoring_loop(&self, cancel_token: CancellationToken) -> anyhow::Result<()> {
+        let cluster_name: String = self.cluster_info.read().await.cluster_name.clone();
+
+        /*
+            The loop is driven by an async interval so that no tokio worker thread is blocked.
+            If a cycle overruns the interval, the missed ticks are skipped instead of being fired in a burst.
+        */
+        let mut ticker: Option<tokio::time::Interval> = None;
+        let mut baselines_saved_at: Instant = Instant::now();
+
         loop {
-            if let Err(e) = self.cluster_nodes_check().await {
-                error!(
-                    "[MonitoringServiceImpl::monitoring_loop] cluster_nodes_check() error: {:?}",
-                    e
+            /* The configuration is read on every cycle so that a reloaded cluster list is applied. */
+            let (interval, step_timeout): (Duration, StepTimeoutConfig) = {
+                let cluster_info = self.cluster_info.read().await;
+                (
+                    Duration::from_secs(cluster_info.monitor_interval_sec.max(1)),
+                    cluster_info.step_timeout.clone(),
+                )
+            };
+
+            let ticker: &mut tokio::time::Interval = Self::get_ticker(&mut ticker, interval);
+
+            /*
+                Cancellation is only observed between cycles,
+                so that an in-flight cycle (and its notifications) is always completed before stopping.
+            */
+            tokio::select! {
+                _ = cancel_token.cancelled() => {
+                    info!(
+                        "[MonitoringServiceImpl::monitoring_loop] Monitoring of '{}' stopped.",
+                        cluster_name
+                    );
+                    self.save_anomaly_baselines().await;
+                    return Ok(());
+                }
+                _ = ticker.tick() => {}
+            }
+
+            let cycle_start: Instant = Instant::now();
+            let cycle_start_utc: DateTime<Utc> = Utc::now();
+            let mut steps: Vec<MonitorStepInfo> = Vec::new();
+
+            steps.push(
+                Self::run_monitor_step(
+                    "cluster_nodes_check",
+                    *step_timeout.node_check_sec(),
+                    self.cluster_nodes_check(),
+                )
+                .await,
+            );
+
+            steps.push(
+                Self::run_monitor_step(
+                    "node_membership_check",
+                    *step_timeout.node_check_sec(),
+                    self.node_membership_check(),
+                )
+                .await,
+            );
+
+            steps.push(
+                Self::run_monitor_step(
+                    "cluster_health_check",
+                    *step_timeout.health_check_sec(),
+                    self.cluster_health_check(),
+                )
+                .await,
+            );
+
+            /*
+                Partial failures are tolerated to ensure
+                that metrics from remaining nodes are still collected even when a specific node becomes unreachable.
+            */
+            steps.push(
+                Self::run_monitor_step(
+                    "pending_tasks_check",
+                    *step_timeout.pending_task_check_sec(),
+                    self.pending_tasks_check(),
+                )
+                .await,
+            );
+
+            steps.push(
+                Self::run_monitor_step(
+                    "long_search_tasks_check",
+                    *step_timeout.search_task_check_sec(),
+                    self.long_search_tasks_check(),
+                )
+                .await,
+            );
+
+            steps.push(
+                Self::run_monitor_step(
+                    "input_es_metric_infos",
+                    *step_timeout.metric_collect_sec(),
+                    self.input_es_metric_infos(),
+                )
+                .await,
+            );
+
+            steps.push(
+                Self::run_monitor_step(
+                    "input_es_cluster_metric_info",
+                    *step_timeout.metric_collect_sec(),
+                    self.input_es_cluster_metric_info(),
+                )
+                .await,
+            );
+
+            steps.push(
+                Self::run_monitor_step(
+                    "shard_balance_check",
+                    *step_timeout.shard_balance_check_sec(),
+                    self.shard_balance_check(),
+                )
+                .await,
+            );
+
+            steps.push(
+                Self::run_monitor_step(
+                    "send_alarm_urgent_infos",
+                    *step_timeout.urgent_check_sec(),
+                    self.send_alarm_urgent_infos(),
+                )
+                .await,
+            );
+
+            let cycle_duration: Duration = cycle_start.elapsed();
+            let overrun: bool = cycle_duration > interval;
+            let skipped_cycles: u64 = (cycle_duration.as_millis() / interval.as_millis()) as u64;
+
+            if overrun {
+                warn!(
+                    "[MonitoringServiceImpl::monitoring_loop] The monitoring cycle of '{}' took {:?}, which exceeds the interval {:?}. {} cycle(s) skipped.",
+                    cluster_name, cycle_duration, interval, skipped_cycles
                 );
             }
 
-            if let Err(e) = self.cluster_health_check().await {
+            let cycle_info: MonitorCycleInfo = MonitorCycleInfo::new(
+                convert_date_to_str_full(cycle_start_utc, Utc),
+                cluster_name.clone(),
+                interval.as_millis() as u64,
+                cycle_duration.as_millis() as u64,
+                overrun,
+                skipped_cycles,
+                steps,
+            );
+
+            if let Err(e) = self
+                .mon_es_service
+                .post_monitor_cycle_info(cycle_info)
+                .await
+            {
                 error!(
-                    "[MonitoringServiceImpl::monitoring_loop] cluster_health_check() error: {:?}",
+                    "[MonitoringServiceImpl::monitoring_loop] post_monitor_cycle_info() error: {:?}",
                     e
                 );
             }
 
-            /*
-                Partial failures are tolerated to ensure
-                that metrics from remaining nodes are still collected even when a specific node becomes unreachable.
-            */
-            if let Err(e) = self.input_es_metric_infos().await {
+            if baselines_saved_at.elapsed()
+                >= Duration::from_secs(ANOMALY_BASELINE_SAVE_INTERVAL_SEC)
+            {
+                self.save_anomaly_baselines().await;
+                baselines_saved_at = Instant::now();
+            }
+        }
+    }
+
+    #[doc = "Function that periodically probes the hosts excluded from the connection pool
+             and re-admits them once they answer again."]
+    async fn pool_recovery_loop(&self, cancel_token: CancellationToken) -> anyhow::Result<()> {
+        loop {
+            tokio::select! {
+                _ = cancel_token.cancelled() => return Ok(()),
+                _ = sleep(Duration::from_secs(POOL_RECOVERY_INTERVAL_SEC)) => {}
+            }
+
+            if let Err(e) = self.metric_service.recover_es_connection_pool().await {
                 error!(
-                    "[MonitoringServiceImpl::monitoring_loop] input_es_metric_infos() error: {:?}",
+                    "[MonitoringServiceImpl::pool_recovery_loop] recover_es_connection_pool() error: {:?}",
                     e
                 );
             }
+        }
+    }
+
+    #[doc = "Function that periodically collects the statistics of the index watchlist.
+             While the watchlist of the cluster is empty, the task only waits for a reloaded cluster list."]
+    /// # Arguments
+    /// * `cancel_token` - Token that stops the loop
+    async fn index_watch_loop(&self, cancel_token: CancellationToken) -> anyhow::Result<()> {
+        let mut ticker: Option<tokio::time::Interval> = None;
+
+        loop {
+            let (index_watch, step_timeout): (IndexWatchConfig, StepTimeoutConfig) = {
+                let cluster_info = self.cluster_info.read().await;
+                (
+                    cluster_info.index_watch.clone(),
+                    cluster_info.step_timeout.clone(),
+                )
+            };
+
+            if index_watch.indices.is_empty() {
+                ticker = None;
+
+                tokio::select! {
+                    _ = cancel_token.cancelled() => return Ok(()),
+                    _ = sleep(Duration::from_secs(CLUSTER_CONFIG_RECHECK_SEC)) => continue,
+                }
+            }
+
+            let ticker: &mut tokio::time::Interval = Self::get_ticker(
+                &mut ticker,
+                Duration::from_secs(index_watch.interval_sec.max(1)),
+            );
+
+            tokio::select! {
+                _ = cancel_token.cancelled() => return Ok(()),
+                _ = ticker.tick() => {}
+            }
+
+            Self::run_monitor_step(
+                "input_es_index_metric_infos",
+                *step_timeout.metric_collect_sec(),
+                self.input_es_index_metric_infos(),
+            )
+            .await;
+        }
+    }
+
+    #[doc = "Function that periodically forecasts when the disks of the nodes will be full.
+             While the forecast is disabled for the cluster, the task only waits for a reloaded cluster list."]
+    /// # Arguments
+    /// * `cancel_token` - Token that stops the loop
+    async fn disk_forecast_loop(&self, cancel_token: CancellationToken) -> anyhow::Result<()> {
+        let mut ticker: Option<tokio::time::Interval> = None;
+
+        loop {
+            let (disk_forecast, step_timeout): (DiskForecastConfig, StepTimeoutConfig) = {
+                let cluster_info = self.cluster_info.read().await;
+                (
+                    cluster_info.disk_forecast.clone(),
+                    cluster_info.step_timeout.clone(),
+                )
+            };
+
+            if !disk_forecast.enabled {
+                ticker = None;
+
+                tokio::select! {
+                    _ = cancel_token.cancelled() => return Ok(()),
+                    _ = sleep(Duration::from_secs(CLUSTER_CONFIG_RECHECK_SEC)) => continue,
+                }
+            }
+
+            let ticker: &mut tokio::time::Interval = Self::get_ticker(
+                &mut ticker,
+                Duration::from_secs(disk_forecast.interval_sec.max(1)),
+            );
 
-            if let Err(e) = self.send_alarm_urgent_infos().await {
-                error!("[MonitoringServiceImpl->monitoring_loop] send_alarm_urgent_infos() error: {:?}", e);
+            tokio::select! {
+                _ = cancel_token.cancelled() => return Ok(()),
+                _ = ticker.tick() => {}
             }
 
-            std_sleep(Duration::from_secs(10));
+            Self::run_monitor_step(
+                "disk_forecast_check",
+                *step_timeout.metric_collect_sec(),
+                self.disk_forecast_check(),
+            )
+            .await;
         }
     }
 
@@ -17,32 +17,60 @@ use crate::model::{
     receiver_email_list::*,
 };
 
-#[derive(Clone, Debug, Getters)]
-#[getset(get = "pub")]
+#[derive(Debug)]
 pub struct NotificationServiceImpl {
-    pub email_list: ReceiverEmailList,
+    /* Swapped as a whole when the receiver file is reloaded */
+    email_list: std::sync::RwLock<Arc<ReceiverEmailList>>,
 }
 
 impl NotificationServiceImpl {
     pub fn new() -> Self {
+        let receiver_email_list: ReceiverEmailList = match Self::load_receiver_email_list() {
+            Ok(receiver_email_list) => receiver_email_list,
+            Err(e) => {
+                error!("[initialize_smtp_clients()] {:?}", e);
+                panic!("{:?}", e)
+            }
+        };
+
+        NotificationServiceImpl {
+            email_list: std::sync::RwLock::new(Arc::new(receiver_email_list)),
+        }
+    }
+
+    #[doc = "Function that reads and validates the receiver list file"]
+    fn load_receiver_email_list() -> anyhow::Result<ReceiverEmailList> {
         let email_receiver_info: &once_lazy<String> = &EMAIL_RECEIVER_PATH;
 
-        let receiver_email_list: ReceiverEmailList =
-            match read_toml_from_file::<ReceiverEmailList>(email_receiver_info) {
-                Ok(receiver_email_list) => receiver_email_list,
-                Err(e) => {
-                    error!(
-                        "[initialize_smtp_clients()] Failed to object '{}' {:?}",
-                        email_receiver_info.to_string(),
-                        e
-                    );
-                    panic!("{:?}", e)
-                }
-            };
+        let receiver_email_list: ReceiverEmailList = read_toml_from_file::<ReceiverEmailList>(
+            email_receiver_info,
+        )
+        .map_err(|e| {
+            anyhow!(
+                "[NotificationServiceImpl::load_receiver_email_list] Failed to object '{}' {:?}",
+                email_receiver_info.to_string(),
+                e
+            )
+        })?;
 
-        NotificationServiceImpl {
-            email_list: receiver_email_list,
+        if receiver_email_list.receivers().is_empty() {
+            return Err(anyhow!(
+                "[NotificationServiceImpl::load_receiver_email_list] '{}' has no receivers",
+                email_receiver_info.to_string()
+            ));
         }
+
+        Ok(receiver_email_list)
+    }
+
+    #[doc = "Function that returns a snapshot of the current receiver list"]
+    pub fn email_list(&self) -> Arc<ReceiverEmailList> {
+        let email_list = self
+            .email_list
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        Arc::clone(&email_list)
     }
 
     #[doc = "Telegram 을 통해서 문제를 전파해주는 함수"]
@@ -94,7 +122,7 @@ impl NotificationServiceImpl {
         html_content: &str,
         receiver_email_list: &ReceiverEmailList,
     ) -> anyhow::Result<()> {
-        let smtp_config: &SmtpConfig = get_smtp_config_info();
+        let smtp_config: SmtpConfig = get_smtp_config_info();
 
         let tasks = receiver_email_list.receivers.iter().map(|receiver| {
             let email_id: &String = receiver.email_id();
@@ -187,11 +215,11 @@ impl NotificationServiceImpl {
         html_content: &str,
         receiver_email_list: &ReceiverEmailList,
     ) -> anyhow::Result<()> {
-        let smtp_config: &SmtpConfig = get_smtp_config_info();
+        let smtp_config: SmtpConfig = get_smtp_config_info();
 
         let tasks = receiver_email_list.receivers().iter().map(|receiver| {
             let email_id: &String = receiver.email_id();
-            self.send_message_to_receiver_html(smtp_config, email_id, email_subject, html_content)
+            self.send_message_to_receiver_html(&smtp_config, email_id, email_subject, html_content)
         });
 
         let results: Vec<Result<String, anyhow::Error>> = join_all(tasks).await;
@@ -290,15 +318,15 @@ impl NotificationService for NotificationServiceImpl {
             html_template = html_template.replace(&format!("{{{}}}", key), value)
         }
 
-        let mail_subject: &str = "[Elasticsearch] Error Alert";
-        let receivers: &ReceiverEmailList = self.email_list();
+        let mail_subject: String = msg_fmt.get_email_subject();
+        let receivers: Arc<ReceiverEmailList> = self.email_list();
 
         /* Send message using iMailer */
-        self.send_alarm_to_imailer(mail_subject, &html_template, receivers)
+        self.send_alarm_to_imailer(&mail_subject, &html_template, &receivers)
             .await?;
 
         /* Send messages using SMTP - internet mang */
-        // self.send_message_to_smtp(mail_subject, &html_template, receivers)
+        // self.send_message_to_smtp(&mail_subject, &html_template, receivers)
         //     .await?;
 
         Ok(())
@@ -310,7 +338,7 @@ impl NotificationService for NotificationServiceImpl {
         email_subject: &str,
         html_content: &str,
     ) -> anyhow::Result<()> {
-        let receiver_email_list: &ReceiverEmailList = self.email_list();
+        let receiver_email_list: Arc<ReceiverEmailList> = self.email_list();
 
         /* SMTP version -> for online network use */
         // self.send_message_to_receivers_smtp(email_subject, html_content, receiver_email_list)
@@ -340,6 +368,19 @@ impl NotificationService for NotificationServiceImpl {
             }
         }
 
+        Ok(())
+    }
+    #[doc = "Function that reloads the receiver list file. The running list is kept if the file is invalid."]
+    async fn reload_receiver_infos(&self) -> anyhow::Result<()> {
+        let receiver_email_list: ReceiverEmailList = Self::load_receiver_email_list()?;
+
+        let mut email_list = self
+            .email_list
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        *email_list = Arc::new(receiver_email_list);
+
         Ok(())
     }
 }
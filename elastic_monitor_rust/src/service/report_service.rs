@@ -12,11 +12,16 @@ use crate::enums::{img_file_type::*, report_type::*};
 use crate::env_configuration::env_config::*;
 
 use crate::model::{
+    cluster_dto::{cluster_info::*, disk_forecast_config::*},
     configs::{config::*, report_config::*},
+    reports::disk_forecast_info::*,
     reports::err_agg_history_bucket::*,
     reports::report_range::*,
 };
 
+/* Interval at which the report configuration is checked for changes */
+const REPORT_CONFIG_RECHECK_SEC: u64 = 60;
+
 #[derive(Debug, new)]
 pub struct ReportServiceImpl<
     N: NotificationService,
@@ -26,6 +31,8 @@ pub struct ReportServiceImpl<
     notification_service: Arc<N>,
     chart_service: Arc<C>,
     mon_es_service: Arc<ME>,
+    /* Replaced in place when the cluster list is reloaded */
+    cluster_info: RwLock<ClusterInfo>,
 }
 
 impl<N, C, ME> ReportServiceImpl<N, C, ME>
@@ -34,6 +41,13 @@ where
     C: ChartService,
     ME: MonEsService,
 {
+    #[doc = "Function that replaces the configuration of the cluster without restarting the report tasks"]
+    /// # Arguments
+    /// * `cluster_info` - Reloaded configuration of the cluster
+    pub async fn update_cluster_info(&self, cluster_info: ClusterInfo) {
+        *self.cluster_info.write().await = cluster_info;
+    }
+
     #[doc = ""]
     async fn report_cluster_issues(
         &self,
@@ -107,6 +121,21 @@ where
             )
         })?;
 
+        /* The disk forecast is only part of the weekly/monthly reports */
+        let disk_forecast_html: String = match report_type {
+            ReportType::Week | ReportType::Month => self
+                .generate_disk_forecast_html()
+                .await
+                .unwrap_or_else(|e| {
+                    error!(
+                        "[ReportServiceImpl::report_cluster_issues] disk forecast: {:?}",
+                        e
+                    );
+                    String::from("")
+                }),
+            ReportType::Day | ReportType::Year => String::from(""),
+        };
+
         let local_start_at: DateTime<Local> = start_at.with_timezone(&Local);
         let local_end_at: DateTime<Local> = end_at.with_timezone(&Local);
 
@@ -121,6 +150,7 @@ where
                 &con_err_agg_img_path,
                 &unstable_agg_img_path,
                 &emergency_agg_img_path,
+                &disk_forecast_html,
             )
             .await?;
 
@@ -144,6 +174,80 @@ where
         Ok(())
     }
 
+    #[doc = "Generate the disk-full forecast section of the report -> one row per host"]
+    /// # Returns
+    /// * `Ok(String)` - Html section of the disk forecast
+    async fn generate_disk_forecast_html(&self) -> anyhow::Result<String> {
+        let cluster_info: ClusterInfo = self.cluster_info.read().await.clone();
+
+        let host_ips: Vec<String> = cluster_info
+            .hosts
+            .iter()
+            .map(|host_info| {
+                host_info
+                    .split_once(':')
+                    .map(|(host, _)| host.to_string())
+                    .unwrap_or_else(|| host_info.to_string())
+            })
+            .collect();
+
+        let mut disk_forecast_infos: Vec<DiskForecastInfo> = self
+            .mon_es_service
+            .get_disk_forecast_infos(&host_ips, &cluster_info.disk_forecast)
+            .await
+            .map_err(|e| anyhow!("[ReportServiceImpl::generate_disk_forecast_html] {:?}", e))?;
+
+        disk_forecast_infos.sort_by(|a, b| a.host().cmp(b.host()));
+
+        let to_local_str = |reached_at: &Option<DateTime<Utc>>| -> String {
+            reached_at
+                .map(|at| convert_date_to_str_human(at.with_timezone(&Local), Local))
+                .unwrap_or_else(|| String::from("-"))
+        };
+
+        let rows: String = disk_forecast_infos
+            .iter()
+            .map(|info| {
+                format!(
+                    "<tr>
+                        <td style='border: 1px solid #ddd; padding: 8px;'>{}</td>
+                        <td style='border: 1px solid #ddd; padding: 8px; text-align: right;'>{:.1}%</td>
+                        <td style='border: 1px solid #ddd; padding: 8px; text-align: right;'>{:+.2}%</td>
+                        <td style='border: 1px solid #ddd; padding: 8px;'>{}</td>
+                        <td style='border: 1px solid #ddd; padding: 8px;'>{}</td>
+                    </tr>",
+                    info.host(),
+                    info.disk_usage(),
+                    info.growth_per_day(),
+                    to_local_str(info.high_watermark_at()),
+                    to_local_str(info.flood_stage_at())
+                )
+            })
+            .collect();
+
+        let disk_forecast: &DiskForecastConfig = &cluster_info.disk_forecast;
+
+        Ok(format!(
+            "<h2 style=\"color: #555; margin-top: 30px; margin-bottom: 15px;\">디스크 사용량 예측 (최근 {}일 추세)</h2>
+            <div style=\"background-color: #f8f9fa; padding: 15px; border-radius: 8px; margin-bottom: 20px;\">
+                <table style='border-collapse: collapse; width: 100%; font-size: 13px;'>
+                    <tr>
+                        <th style='border: 1px solid #ddd; padding: 8px;'>Host</th>
+                        <th style='border: 1px solid #ddd; padding: 8px;'>Disk usage</th>
+                        <th style='border: 1px solid #ddd; padding: 8px;'>Growth / day</th>
+                        <th style='border: 1px solid #ddd; padding: 8px;'>High watermark ({}%)</th>
+                        <th style='border: 1px solid #ddd; padding: 8px;'>Flood stage ({}%)</th>
+                    </tr>
+                    {}
+                </table>
+            </div>",
+            disk_forecast.lookback_days(),
+            disk_forecast.high_watermark_percent(),
+            disk_forecast.flood_stage_watermark_percent(),
+            rows
+        ))
+    }
+
     #[doc = "Process error data for a specific error type: count, aggregate, and generate graph"]
     /// # Returns
     /// * `Ok((u64, PathBuf))` - Error count and generated image path
@@ -277,6 +381,7 @@ where
         node_conn_fail_chart_img_path: &PathBuf,
         cluster_unstable_chart_img_path: &PathBuf,
         urgent_indicator_chart_img_path: &PathBuf,
+        disk_forecast_html: &str,
     ) -> anyhow::Result<String> {
         let now_local: DateTime<Local> = Local::now();
 
@@ -347,7 +452,8 @@ where
             .replace(
                 "{{URGENT_INDICATOR_CHART_IMG}}",
                 &urgent_indicator_chart_img.to_string(),
-            );
+            )
+            .replace("{{DISK_FORECAST}}", disk_forecast_html);
 
         Ok(html_content)
     }
@@ -361,16 +467,39 @@ where
     ME: MonEsService + Sync + Send,
 {
     #[doc = "Function that provides a report service"]
-    async fn report_loop(&self, report_type: ReportType, cluster_name: &str) -> anyhow::Result<()> {
-        let report_config: ReportConfig = match report_type {
-            ReportType::Day => get_daily_report_config_info().clone(),
-            ReportType::Week => get_weekly_report_config_info().clone(),
-            ReportType::Month => get_monthly_report_config_info().clone(),
-            ReportType::Year => get_yearly_report_config_info().clone(),
-        };
+    async fn report_loop(
+        &self,
+        report_type: ReportType,
+        cluster_name: &str,
+        cancel_token: CancellationToken,
+    ) -> anyhow::Result<()> {
+        /* The time at which the next report has been scheduled (for logging) */
+        let mut scheduled_at: Option<DateTime<Local>> = None;
 
-        let schedule: cron::Schedule = cron::Schedule::from_str(&report_config.cron_schedule)
-            .map_err(|e| {
+        loop {
+            /* The configuration is read on every iteration so that a reloaded schedule is applied. */
+            let report_config: ReportConfig = match report_type {
+                ReportType::Day => get_daily_report_config_info(),
+                ReportType::Week => get_weekly_report_config_info(),
+                ReportType::Month => get_monthly_report_config_info(),
+                ReportType::Year => get_yearly_report_config_info(),
+            };
+
+            let recheck_interval: Duration = Duration::from_secs(REPORT_CONFIG_RECHECK_SEC);
+
+            if !report_config.enabled {
+                if scheduled_at.take().is_some() {
+                    info!("[ReportServiceImpl->report_loop] Report scheduler disabled.");
+                }
+
+                tokio::select! {
+                    _ = cancel_token.cancelled() => return Ok(()),
+                    _ = sleep(recheck_interval) => continue,
+                }
+            }
+
+            let schedule: cron::Schedule = cron::Schedule::from_str(&report_config.cron_schedule)
+                .map_err(|e| {
                 anyhow!(
                     "[ReportServiceImpl->report_loop] Failed to parse cron schedule '{}': {:?}",
                     report_config.cron_schedule,
@@ -378,12 +507,6 @@ where
                 )
             })?;
 
-        info!(
-            "Starting daily report scheduler with cron schedule: {}",
-            report_config.cron_schedule
-        );
-
-        loop {
             /* The reporting schedule is based on Korean time - GMT+9 */
             let now_local: DateTime<Local> = chrono::Local::now();
 
@@ -403,14 +526,33 @@ where
                 }
             };
 
-            info!(
-                "Next report scheduled at: {}. Sleeping for {:?}",
-                next_run.format("%Y-%m-%dT%H:%M:%S"),
-                duration_until_next_run
-            );
+            if scheduled_at != Some(next_run) {
+                info!(
+                    "Next report scheduled at: {} (cron schedule: {}). Sleeping for {:?}",
+                    next_run.format("%Y-%m-%dT%H:%M:%S"),
+                    report_config.cron_schedule,
+                    duration_until_next_run
+                );
+                scheduled_at = Some(next_run);
+            }
+
+            /* Wakes up at least every `REPORT_CONFIG_RECHECK_SEC` seconds to pick up a changed schedule. */
+            let is_due: bool = duration_until_next_run <= recheck_interval;
+            let wake: Instant = Instant::now() + duration_until_next_run.min(recheck_interval);
+
+            tokio::select! {
+                _ = cancel_token.cancelled() => {
+                    info!("[ReportServiceImpl->report_loop] Report scheduler stopped.");
+                    return Ok(());
+                }
+                _ = sleep_until(wake) => {}
+            }
+
+            if !is_due {
+                continue;
+            }
 
-            let wake: Instant = Instant::now() + duration_until_next_run;
-            sleep_until(wake).await;
+            scheduled_at = None;
 
             /* Get the current time after waking up */
             //let report_time: DateTime<Local> = chrono::Local::now(); // 애 따로 필요없을 것 같긴한데...?!...
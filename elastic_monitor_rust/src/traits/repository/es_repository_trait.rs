@@ -1,15 +1,32 @@
 use crate::common::*;
 
+use crate::model::elastic_dto::es_version_info::*;
+
 #[async_trait]
 pub trait EsRepository {
     async fn get_indices_info(&self) -> Result<String, anyhow::Error>;
+    async fn detect_cluster_version(&mut self) -> anyhow::Result<EsVersionInfo>;
     async fn get_health_info(&self) -> Result<Value, anyhow::Error>;
+    async fn get_cluster_stats(&self) -> anyhow::Result<Value>;
     async fn get_node_conn_check(&self) -> Vec<(String, bool)>;
+    async fn get_disabled_node_conn_check(&self) -> Vec<(String, bool)>;
     async fn get_node_stats(&self, fields: &[&str]) -> Result<Value, anyhow::Error>;
     async fn get_specific_index_info(&self, index_name: &str) -> Result<Value, anyhow::Error>;
     async fn get_cat_shards(&self, fields: &[&str]) -> Result<String, anyhow::Error>;
-    async fn get_cat_thread_pool(&self) -> Result<String, anyhow::Error>;
+    async fn get_allocation_explain(
+        &self,
+        index_name: &str,
+        shard: u32,
+        primary: bool,
+    ) -> anyhow::Result<Value>;
+    async fn get_pending_tasks(&self) -> anyhow::Result<Value>;
+    async fn get_search_tasks(&self) -> anyhow::Result<Value>;
+    async fn cancel_task(&self, task_id: &str) -> anyhow::Result<()>;
+    async fn get_hot_threads(&self, node_name: &str, threads: u64) -> anyhow::Result<String>;
+    async fn get_cat_thread_pool(&self, fields: &[&str]) -> Result<String, anyhow::Error>;
+    async fn get_cat_master(&self) -> Result<String, anyhow::Error>;
     async fn post_doc(&self, index_name: &str, document: Value) -> Result<(), anyhow::Error>;
+    async fn post_bulk_docs(&self, index_name: &str, documents: Vec<Value>) -> anyhow::Result<()>;
     async fn get_search_query<T: for<'de> Deserialize<'de> + Send + 'static>(
         &self,
         es_query: &Value,
@@ -30,5 +47,10 @@ pub trait EsRepository {
     fn get_cluster_index_monitoring_pattern(&self) -> Option<String>;
     fn get_cluster_index_urgent_pattern(&self) -> Option<String>;
     fn get_cluster_index_error_pattern(&self) -> Option<String>;
+    fn get_cluster_index_cycle_pattern(&self) -> Option<String>;
+    fn get_cluster_index_pending_task_pattern(&self) -> Option<String>;
+    fn get_cluster_index_cluster_metric_pattern(&self) -> Option<String>;
+    fn get_cluster_version(&self) -> Option<EsVersionInfo>;
+    fn get_disabled_hosts(&self) -> Vec<String>;
     fn change_es_conn_pool(&mut self, disable_node_list: Vec<String>) -> anyhow::Result<()>;
 }
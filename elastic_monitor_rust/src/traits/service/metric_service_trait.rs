@@ -1,17 +1,38 @@
 use crate::common::*;
 
-use crate::model::{monitoring::metric_info::*, search_indicies::*};
+use crate::model::{
+    cluster_dto::{cluster_health_info::*, thread_pool_config::*, unassigned_shard_info::*},
+    monitoring::{
+        cluster_metric_info::*, index_metric_info::*, metric_info::*, node_membership_info::*,
+        pending_task_info::*, search_task_info::*, shard_balance_info::*,
+    },
+    search_indicies::*,
+};
 
 #[async_trait]
 pub trait MetricService {
     async fn get_cluster_name(&self) -> String;
     async fn get_cluster_all_host_infos(&self) -> Vec<String>;
     async fn get_cluster_node_check(&self) -> Result<Vec<String>, anyhow::Error>;
-    async fn get_cluster_health_check(&self) -> Result<String, anyhow::Error>;
+    async fn get_cluster_health_check(&self) -> Result<ClusterHealthInfo, anyhow::Error>;
     async fn get_cluster_unstable_index_infos(
         &self,
         cluster_name: &str,
     ) -> Result<Vec<SearchIndicies>, anyhow::Error>;
+    async fn get_pending_task_info(&self) -> anyhow::Result<PendingTaskInfo>;
+    async fn get_node_membership_info(&self) -> anyhow::Result<NodeMembershipInfo>;
+    async fn get_cluster_metric_info(&self) -> anyhow::Result<ClusterMetricInfo>;
+    async fn get_unassigned_shard_infos(&self) -> anyhow::Result<Vec<UnassignedShardInfo>>;
+    async fn get_long_running_search_tasks(
+        &self,
+        min_running_time_ms: u64,
+    ) -> anyhow::Result<Vec<SearchTaskInfo>>;
+    async fn cancel_search_task(&self, task_id: &str) -> anyhow::Result<()>;
+    async fn get_shard_balance_infos(
+        &self,
+        top_indices: usize,
+    ) -> anyhow::Result<Vec<ShardBalanceInfo>>;
+    async fn get_node_hot_threads(&self, node_name: &str, threads: u64) -> anyhow::Result<String>;
     async fn get_nodes_stats_handle(
         &self,
         metric_vec: &mut Vec<MetricInfo>,
@@ -24,11 +45,20 @@ pub trait MetricService {
     async fn get_cat_thread_pool_handle(
         &self,
         metric_vec: &mut Vec<MetricInfo>,
+        thread_pool_config: &ThreadPoolConfig,
     ) -> Result<(), anyhow::Error>;
-    async fn get_cluster_nodes_infos(&self) -> anyhow::Result<Vec<MetricInfo>>;
+    async fn get_cluster_nodes_infos(
+        &self,
+        thread_pool_config: &ThreadPoolConfig,
+    ) -> anyhow::Result<Vec<MetricInfo>>;
+    async fn get_index_watch_infos(
+        &self,
+        index_watch_list: &[String],
+    ) -> anyhow::Result<Vec<IndexMetricInfo>>;
     async fn extract_host_ips(&self) -> Vec<String>;
     async fn refresh_es_connection_pool(
         &self,
         disable_node_list: Vec<String>,
     ) -> anyhow::Result<()>; // -> ?
+    async fn recover_es_connection_pool(&self) -> anyhow::Result<Vec<String>>;
 }
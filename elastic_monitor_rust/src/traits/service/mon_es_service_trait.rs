@@ -1,10 +1,20 @@
 use crate::common::*;
 
 use crate::model::{
-    message_formatter_dto::message_formatter_urgent::*, monitoring::metric_info::*,
-    reports::err_agg_history_bucket::*, search_indicies::*,
+    alert_dto::alert_transition::*,
+    cluster_dto::{disk_forecast_config::*, unassigned_shard_info::*},
+    message_formatter_dto::message_formatter_urgent::*,
+    monitoring::{
+        anomaly_baseline::*, cluster_metric_info::*, hot_threads_info::*, index_metric_info::*,
+        metric_info::*, monitor_cycle_info::*, node_membership_info::*, pending_task_info::*,
+    },
+    reports::{disk_forecast_info::*, err_agg_history_bucket::*},
+    search_indicies::*,
+    urgent_dto::urgent_info::*,
 };
 
+use crate::enums::anomaly_source::*;
+
 #[async_trait]
 pub trait MonEsService {
     async fn put_node_conn_err_infos(
@@ -17,16 +27,50 @@ pub trait MonEsService {
         cluster_name: &str,
         danger_indicies: &[SearchIndicies],
     ) -> anyhow::Result<()>;
+    async fn put_unassigned_shard_infos(
+        &self,
+        cluster_name: &str,
+        unassigned_shards: &[UnassignedShardInfo],
+    ) -> anyhow::Result<()>;
+    async fn put_hot_threads_infos(
+        &self,
+        cluster_name: &str,
+        hot_threads_infos: &[HotThreadsInfo],
+    ) -> anyhow::Result<()>;
+    async fn put_node_membership_changes(
+        &self,
+        cluster_name: &str,
+        changes: &[NodeMembershipChange],
+    ) -> anyhow::Result<()>;
     async fn put_urgent_infos(
         &self,
         cluster_name: &str,
         urgent_infos: &[UrgentAlarmInfo],
     ) -> anyhow::Result<()>;
+    async fn put_alert_transition_infos(
+        &self,
+        cluster_name: &str,
+        transitions: &[AlertTransition],
+    ) -> anyhow::Result<()>;
     async fn post_cluster_nodes_infos(&self, metric_infos: Vec<MetricInfo>) -> anyhow::Result<()>;
-    async fn get_alarm_urgent_infos(
+    async fn post_index_metric_infos(
+        &self,
+        index_metric_infos: Vec<IndexMetricInfo>,
+    ) -> anyhow::Result<()>;
+    async fn post_pending_task_info(
+        &self,
+        pending_task_info: PendingTaskInfo,
+    ) -> anyhow::Result<()>;
+    async fn post_cluster_metric_info(
         &self,
-        host_ips: Vec<String>,
-    ) -> anyhow::Result<Vec<UrgentAlarmInfo>>;
+        cluster_metric_info: ClusterMetricInfo,
+    ) -> anyhow::Result<()>;
+    async fn post_monitor_cycle_info(&self, cycle_info: MonitorCycleInfo) -> anyhow::Result<()>;
+    async fn get_urgent_infos(
+        &self,
+        host_ips: &[String],
+        search_range_sec: u64,
+    ) -> anyhow::Result<Vec<UrgentInfo>>;
     async fn get_cluster_err_datas_cnt_from_es(
         &self,
         cluster_name: &str,
@@ -42,4 +86,17 @@ pub trait MonEsService {
         end_at: DateTime<Utc>,
         calendar_interval: &str,
     ) -> anyhow::Result<Vec<ErrorAggHistoryBucket>>;
+    async fn get_disk_forecast_infos(
+        &self,
+        host_ips: &[String],
+        disk_forecast: &DiskForecastConfig,
+    ) -> anyhow::Result<Vec<DiskForecastInfo>>;
+    async fn get_anomaly_baselines(
+        &self,
+        source: AnomalySource,
+        metric_names: &[String],
+        host_ips: &[String],
+        bootstrap_hours: u64,
+        end_at: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<(String, String, AnomalyBaseline)>>;
 }
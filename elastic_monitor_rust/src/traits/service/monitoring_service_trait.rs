@@ -2,6 +2,9 @@ use crate::common::*;
 
 #[async_trait]
 pub trait MonitoringService {
-    async fn monitoring_loop(&self) -> anyhow::Result<()>;
+    async fn monitoring_loop(&self, cancel_token: CancellationToken) -> anyhow::Result<()>;
+    async fn pool_recovery_loop(&self, cancel_token: CancellationToken) -> anyhow::Result<()>;
+    async fn index_watch_loop(&self, cancel_token: CancellationToken) -> anyhow::Result<()>;
+    async fn disk_forecast_loop(&self, cancel_token: CancellationToken) -> anyhow::Result<()>;
     async fn get_cluster_name(&self) -> String;
 }
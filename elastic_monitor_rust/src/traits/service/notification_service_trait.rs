@@ -13,4 +13,5 @@ pub trait NotificationService {
         email_subject: &str,
         html_content: &str,
     ) -> anyhow::Result<()>;
+    async fn reload_receiver_infos(&self) -> anyhow::Result<()>;
 }
@@ -4,5 +4,10 @@ use crate::enums::report_type::*;
 
 #[async_trait]
 pub trait ReportService {
-    async fn report_loop(&self, report_type: ReportType, cluster_name: &str) -> anyhow::Result<()>;
+    async fn report_loop(
+        &self,
+        report_type: ReportType,
+        cluster_name: &str,
+        cancel_token: CancellationToken,
+    ) -> anyhow::Result<()>;
 }
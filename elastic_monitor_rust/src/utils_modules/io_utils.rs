@@ -15,6 +15,59 @@ pub fn read_toml_from_file<T: DeserializeOwned>(file_path: &str) -> Result<T, an
     Ok(toml)
 }
 
+#[doc = "Function that reads a JSON file and converts it into an object"]
+/// # Arguments
+/// * `file_path` - Path of the JSON file
+///
+/// # Returns
+/// * Result<T, anyhow::Error>
+pub fn read_json_from_file<T: DeserializeOwned>(file_path: &str) -> anyhow::Result<T> {
+    let json_content: String = fs::read_to_string(file_path)
+        .map_err(|e| anyhow!("[read_json_from_file] '{}': {:?}", file_path, e))?;
+    let json: T = serde_json::from_str(&json_content)
+        .map_err(|e| anyhow!("[read_json_from_file] '{}': {:?}", file_path, e))?;
+
+    Ok(json)
+}
+
+#[doc = "Function that writes an object into a JSON file (the directory is created if missing).
+         The file is written next to the target and renamed, so that a crash never leaves a truncated file."]
+/// # Arguments
+/// * `file_path`   - Path of the JSON file
+/// * `value`       - Object to be written
+///
+/// # Returns
+/// * Result<(), anyhow::Error>
+pub fn write_json_to_file<T: Serialize>(file_path: &str, value: &T) -> anyhow::Result<()> {
+    let json_content: String = serde_json::to_string(value)?;
+    let tmp_file_path: String = format!("{}.tmp", file_path);
+
+    if let Some(parent_dir) = Path::new(file_path).parent() {
+        fs::create_dir_all(parent_dir)
+            .map_err(|e| anyhow!("[write_json_to_file] '{}': {:?}", file_path, e))?;
+    }
+
+    fs::write(&tmp_file_path, json_content)
+        .and_then(|_| fs::rename(&tmp_file_path, file_path))
+        .map_err(|e| anyhow!("[write_json_to_file] '{}': {:?}", file_path, e))?;
+
+    Ok(())
+}
+
+#[doc = "Function that returns the last modification time of a file (used to detect config changes)"]
+/// # Arguments
+/// * `file_path` - Path of the file
+///
+/// # Returns
+/// * Result<std::time::SystemTime, anyhow::Error>
+pub fn get_file_modified_time(file_path: &str) -> anyhow::Result<std::time::SystemTime> {
+    let modified_time: std::time::SystemTime = fs::metadata(file_path)
+        .and_then(|metadata| metadata.modified())
+        .map_err(|e| anyhow!("[get_file_modified_time] '{}': {:?}", file_path, e))?;
+
+    Ok(modified_time)
+}
+
 #[doc = "Function that removes a specific file."]
 pub fn delete_file(file_path: &PathBuf) -> anyhow::Result<()> {
     fs::remove_file(file_path).map_err(|e| anyhow!("[delete_file] {:?}", e))?;
@@ -34,3 +34,19 @@ pub fn get_value_by_path<T: FromStr>(json_value: &Value, path: &str) -> Result<T
         )),
     }
 }
+
+#[doc = "Function that returns a numeric value by field name or dotted path.
+         The name is looked up as a field first (e.g. `load_avg_1m`), then as a dotted path (e.g. `system.cpu.iowait`)."]
+/// # Arguments
+/// * `json_value` - JSON object
+/// * `field_name` - Field name or dotted path
+///
+/// # Returns
+/// * Option<f64>
+pub fn get_numeric_value(json_value: &Value, field_name: &str) -> Option<f64> {
+    match json_value.get(field_name) {
+        Some(Value::Number(value)) => value.as_f64(),
+        Some(Value::String(value)) => value.parse().ok(),
+        _ => get_value_by_path::<f64>(json_value, field_name).ok(),
+    }
+}